@@ -38,11 +38,14 @@ impl<'a> Agent<'a>
 	#[inline(always)]
 	pub fn prefix(&self, version: &Version) -> &'a Prefix
 	{
-		match self.agent_detail.prefix_exceptions.get(version)
-		{
-			Some(prefix) => prefix,
-			None => &self.agent_detail.prefix,
-		}
+		self.agent_detail.prefix(version)
+	}
+	
+	/// The engine used by `version` of this agent and, if known, the version of that engine; see `AgentName::engine()`.
+	#[inline(always)]
+	pub fn engine(&self, version: &Version) -> Option<(Engine, Option<Version>)>
+	{
+		self.agent_name.engine(version)
 	}
 	
 	/// Is this a desktop or mobile agent?
@@ -54,10 +57,11 @@ impl<'a> Agent<'a>
 	
 	/// Global usage; differs from `VersionDetail.global_usage()` **and** from `RegionalUsage::WorldWide`.
 	/// It is recommended to use the values in `RegionalUsage::WorldWide` for consistency.
+	/// A version within a range, eg "4.4.4", has the usage of that range, eg "4.4.3-4.4.4".
 	#[inline(always)]
 	pub fn global_usage(&self, version: &Version) -> Option<UsagePercentage>
 	{
-		self.agent_detail.usage_global.get(version).map(|value| *value)
+		version.find_in(&self.agent_detail.usage_global).map(|(_, value)| *value)
 	}
 	
 	/// Details of every known version.
@@ -92,10 +96,28 @@ impl<'a> Agent<'a>
 		self.version_details().range((Unbounded, Included(self.current_version())))
 	}
 	
-	/// Details of version
+	/// Details of version; version may be one within a known range, eg "15.2" for iOS Safari's "15.2-15.3".
 	#[inline(always)]
 	pub fn version_detail(&'a self, version: &Version) -> Option<&'a VersionDetail>
 	{
-		self.agent_detail.version_list.get(version)
+		version.find_in(&self.agent_detail.version_list).map(|(_, version_detail)| version_detail)
+	}
+	
+	/// The known version nearest to a version reported by, say, a `User-Agent` string, eg "62" for "62.0.3202.94" and "10.0-10.2" for "10.1".
+	/// A version newer than any known is resolved to the latest known (perhaps future) version; see `Version::resolve()`.
+	/// Safari's TP and Opera Mini's all only ever match exactly.
+	/// Returns None if no version is known or the version is older than every known version.
+	#[inline(always)]
+	pub fn nearest_known_version(&self, reported_version: &Version) -> Option<&'a Version>
+	{
+		reported_version.resolve(&self.agent_detail.version_list).map(|(known_version, _)| known_version)
+	}
+	
+	/// A field of this agent in the caniuse.com database that this crate does not model.
+	/// Such fields are preserved when loading leniently and written back out when serializing.
+	#[inline(always)]
+	pub fn extra_field(&self, name: &str) -> Option<&'a Value>
+	{
+		self.agent_detail.extra_fields.get(name)
 	}
 }
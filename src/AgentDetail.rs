@@ -2,7 +2,7 @@
 // Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
 
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 struct AgentDetail
 {
 	#[serde(rename = "browser")] name: String,
@@ -10,13 +10,57 @@ struct AgentDetail
 	prefix: Prefix,
 	#[serde(rename = "type")] agent_type: AgentType,
 	usage_global: BTreeMap<Version, UsagePercentage>,
-	#[serde(deserialize_with = "AgentDetail::deserialize_version_list")] version_list: BTreeMap<Version, VersionDetail>,
+	#[serde(serialize_with = "AgentDetail::serialize_version_list", deserialize_with = "AgentDetail::deserialize_version_list")] version_list: BTreeMap<Version, VersionDetail>,
 	current_version: Version,
-	#[serde(default)] prefix_exceptions: BTreeMap<Version, Prefix>
+	#[serde(default, skip_serializing_if = "BTreeMap::is_empty")] prefix_exceptions: BTreeMap<Version, Prefix>,
+	#[serde(flatten)] extra_fields: BTreeMap<String, Value>,
 }
 
 impl AgentDetail
 {
+	#[inline(always)]
+	fn prefix(&self, version: &Version) -> &Prefix
+	{
+		match version.find_in(&self.prefix_exceptions)
+		{
+			Some((_, prefix)) => prefix,
+			None => &self.prefix,
+		}
+	}
+	
+	fn serialize_version_list<S: Serializer>(version_list: &BTreeMap<Version, VersionDetail>, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		struct VersionListEntry<'a>(&'a Version, &'a VersionDetail);
+		
+		impl<'a> Serialize for VersionListEntry<'a>
+		{
+			#[inline(always)]
+			fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+			{
+				let version_detail = self.1;
+				
+				// Cast here is deliberate; we deliberately parse expecting a non-negative timestamp
+				let release_date = version_detail.release_date.map(|release_date| release_date.timestamp() as u64);
+				
+				let prefix = match version_detail.prefix_override
+				{
+					None => "",
+					Some(ref prefix) => prefix.can_i_use_identifier(),
+				};
+				
+				let mut state = serializer.serialize_struct("VersionListEntry", 5)?;
+				state.serialize_field("version", self.0)?;
+				state.serialize_field("global_usage", &version_detail.global_usage)?;
+				state.serialize_field("release_date", &release_date)?;
+				state.serialize_field("era", &version_detail.era)?;
+				state.serialize_field("prefix", prefix)?;
+				state.end()
+			}
+		}
+		
+		serializer.collect_seq(version_list.iter().map(|(version, version_detail)| VersionListEntry(version, version_detail)))
+	}
+	
 	fn deserialize_version_list<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BTreeMap<Version, VersionDetail>, D::Error>
 	{
 		use de::Error as SerdeError;
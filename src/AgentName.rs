@@ -127,6 +127,16 @@ impl<'de> Deserialize<'de> for AgentName
 	}
 }
 
+impl Serialize for AgentName
+{
+	/// Serialize using Serde
+	#[inline(always)]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		serializer.serialize_str(self.can_i_use_identifier())
+	}
+}
+
 impl AgentName
 {
 	/// Given an agent name and the CanIUse database, find the associated agent.
@@ -136,4 +146,225 @@ impl AgentName
 	{
 		can_i_use.agent(self)
 	}
+	
+	/// The identifier used for this agent in the caniuse.com database, eg `ios_saf`.
+	#[inline(always)]
+	pub fn can_i_use_identifier(&self) -> &str
+	{
+		use self::AgentName::*;
+		
+		match *self
+		{
+			MicrosoftInternetExplorer => "ie",
+			MicrosoftEdge => "edge",
+			MozillaFirefox => "firefox",
+			GoogleChrome => "chrome",
+			AppleSafari => "safari",
+			Opera => "opera",
+			AppleSafariIOs => "ios_saf",
+			OperaMini => "op_mini",
+			GoogleAndroidBrowserAndWebComponent => "android",
+			Blackberry => "bb",
+			OperaMobile => "op_mob",
+			GoogleChromeAndroid => "and_chr",
+			MozillaFirefoxAndroid => "and_ff",
+			MicrosoftInternetExplorerMobile => "ie_mob",
+			UcBrowserAndroid => "and_uc",
+			SamsungBrowserAndroid => "samsung",
+			QqBrowserAndroid => "and_qq",
+			BaiduBrowserAndroid => "baidu",
+			
+			Unknown(ref identifier) => identifier,
+			__Nonexhaustive => unreachable!(),
+		}
+	}
+	
+	/// The identifier used for this agent in Babel's `@babel/preset-env` and SWC's `env` `targets`, eg `ios`; Chrome for Android, Firefox for Android and Internet Explorer Mobile share those of their desktop agents.
+	/// Returns None for agents these tools do not know.
+	#[inline(always)]
+	pub fn babel_identifier(&self) -> Option<&'static str>
+	{
+		use self::AgentName::*;
+		
+		let identifier = match *self
+		{
+			MicrosoftInternetExplorer | MicrosoftInternetExplorerMobile => "ie",
+			MicrosoftEdge => "edge",
+			MozillaFirefox | MozillaFirefoxAndroid => "firefox",
+			GoogleChrome | GoogleChromeAndroid => "chrome",
+			AppleSafari => "safari",
+			Opera => "opera",
+			AppleSafariIOs => "ios",
+			GoogleAndroidBrowserAndWebComponent => "android",
+			OperaMobile => "opera_mobile",
+			SamsungBrowserAndroid => "samsung",
+			
+			OperaMini | Blackberry | UcBrowserAndroid | QqBrowserAndroid | BaiduBrowserAndroid | Unknown(_) => return None,
+			__Nonexhaustive => unreachable!(),
+		};
+		Some(identifier)
+	}
+	
+	/// The engine used by `version` of this agent and, if known, the version of that engine (see `Engine` for what engine versions are), eg Blink 79 for Microsoft Edge 79 and EdgeHTML 18 for Microsoft Edge 18.
+	/// Returns None for unknown agents and for versions without a major number other than Safari's TP and Opera Mini's all.
+	#[inline(always)]
+	pub fn engine(&self, version: &Version) -> Option<(Engine, Option<Version>)>
+	{
+		use self::AgentName::*;
+		use self::Engine::*;
+		
+		let major = match version.major_number()
+		{
+			Some(major) => major,
+			None => return match *self
+			{
+				AppleSafari | AppleSafariIOs if version.is_safari_technology_preview() => Some((WebKit, None)),
+				OperaMini => Some((Presto, None)),
+				_ => None,
+			},
+		};
+		let lower_bound = version.lower_bound();
+		
+		let engine = match *self
+		{
+			MicrosoftInternetExplorer | MicrosoftInternetExplorerMobile => (Trident, if major >= 8 { Some(Version::major_minor(major - 4, 0)) } else { None }),
+			
+			MicrosoftEdge => (if major <= 18 { EdgeHTML } else { Blink }, Some(Version::major(major))),
+			
+			MozillaFirefox | MozillaFirefoxAndroid => (Gecko, Some(Self::gecko_version(major, lower_bound))),
+			
+			GoogleChrome | GoogleChromeAndroid => if major < 28
+			{
+				(WebKit, None)
+			}
+			else
+			{
+				(Blink, Some(Version::major(major)))
+			},
+			
+			AppleSafari | AppleSafariIOs => (WebKit, Some(lower_bound)),
+			
+			Opera => if major <= 12
+			{
+				(Presto, None)
+			}
+			else
+			{
+				(Blink, Some(Version::major(if major <= 68 { major + 13 } else { major + 14 })))
+			},
+			
+			OperaMobile => if major <= 12
+			{
+				(Presto, None)
+			}
+			else
+			{
+				(Blink, Self::single_listed_version_chromium_version(major, 37, 50))
+			},
+			
+			OperaMini => (Presto, None),
+			
+			GoogleAndroidBrowserAndWebComponent => if lower_bound < Version::major_minor(4, 4)
+			{
+				(WebKit, None)
+			}
+			else if major == 4
+			{
+				(Blink, Some(Version::major(if lower_bound < Version::major_minor_revision(4, 4, 3) { 30 } else { 33 })))
+			}
+			else
+			{
+				(Blink, Some(Version::major(major)))
+			},
+			
+			Blackberry => (WebKit, None),
+			
+			SamsungBrowserAndroid => (Blink, Self::samsung_browser_chromium_version(major)),
+			
+			UcBrowserAndroid => (Blink, Self::single_listed_version_chromium_version(major, 11, 57)),
+			
+			QqBrowserAndroid => (Blink, Self::single_listed_version_chromium_version(major, 1, 37)),
+			
+			BaiduBrowserAndroid => (Blink, Self::single_listed_version_chromium_version(major, 7, 48)),
+			
+			Unknown(_) => return None,
+			__Nonexhaustive => unreachable!(),
+		};
+		Some(engine)
+	}
+	
+	#[inline(always)]
+	fn gecko_version(major: u64, lower_bound: Version) -> Version
+	{
+		if major >= 5
+		{
+			Version::major(major)
+		}
+		else if major == 4
+		{
+			Version::major_minor(2, 0)
+		}
+		else if lower_bound >= Version::major_minor(3, 6)
+		{
+			Version::major_minor_revision(1, 9, 2)
+		}
+		else if lower_bound >= Version::major_minor(3, 5)
+		{
+			Version::major_minor_revision(1, 9, 1)
+		}
+		else if major == 3
+		{
+			Version::major_minor(1, 9)
+		}
+		else
+		{
+			Version::major_minor_revision(1, 8, 1)
+		}
+	}
+	
+	/// caniuse.com lists only one version of these agents (eg UC Browser 11.4), the Chromium version of which is known; other versions are not.
+	#[inline(always)]
+	fn single_listed_version_chromium_version(major: u64, listed_major: u64, chromium_major: u64) -> Option<Version>
+	{
+		if major == listed_major
+		{
+			Some(Version::major(chromium_major))
+		}
+		else
+		{
+			None
+		}
+	}
+	
+	#[inline(always)]
+	fn samsung_browser_chromium_version(major: u64) -> Option<Version>
+	{
+		let chromium_major = match major
+		{
+			4 => 44,
+			5 => 51,
+			6 => 56,
+			7 => 59,
+			8 => 63,
+			9 => 67,
+			10 => 71,
+			11 => 75,
+			12 => 79,
+			13 => 83,
+			14 => 87,
+			15 => 90,
+			16 => 92,
+			17 => 96,
+			18 => 99,
+			19 => 102,
+			20 => 106,
+			21 => 110,
+			22 => 111,
+			23 => 115,
+			24 => 117,
+			25 => 121,
+			_ => return None,
+		};
+		Some(Version::major(chromium_major))
+	}
 }
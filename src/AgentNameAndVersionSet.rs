@@ -49,6 +49,45 @@ impl AgentNameAndVersionSet
 		}
 	}
 	
+	/// The engines used by the agents and versions in this set and, if known, the minimum version of each; see `minimum_engine_version()`.
+	#[inline(always)]
+	pub fn minimum_engine_versions(&self) -> HashMap<Engine, Option<Version>>
+	{
+		let mut minimum_engine_versions = HashMap::new();
+		for (agent_name, version) in self.0.iter()
+		{
+			if let Some((engine, engine_version)) = agent_name.engine(version)
+			{
+				let minimum_engine_version = minimum_engine_versions.entry(engine).or_insert(None);
+				if let Some(engine_version) = engine_version
+				{
+					let is_lower = match *minimum_engine_version
+					{
+						None => true,
+						Some(ref minimum_engine_version) => engine_version < *minimum_engine_version,
+					};
+					if is_lower
+					{
+						*minimum_engine_version = Some(engine_version);
+					}
+				}
+			}
+		}
+		minimum_engine_versions
+	}
+	
+	/// The minimum version of `engine` used by the agents and versions in this set, eg the minimum Blink (Chromium) version, which is what tools such as esbuild need as a target.
+	/// Agents and versions using `engine` without a known engine version (see `AgentName::engine()`) are ignored; returns None if there are none with a known engine version.
+	#[inline(always)]
+	pub fn minimum_engine_version(&self, engine: Engine) -> Option<Version>
+	{
+		self.0.iter().filter_map(|(agent_name, version)| match agent_name.engine(version)
+		{
+			Some((agent_engine, engine_version)) if agent_engine == engine => engine_version,
+			_ => None,
+		}).min()
+	}
+	
 	/// Constructor to use if one of the methods below isn't suitable
 	#[inline(always)]
 	pub fn new(values: HashSet<(AgentName, Version)>) -> Self
@@ -56,6 +95,25 @@ impl AgentNameAndVersionSet
 		AgentNameAndVersionSet(values)
 	}
 	
+	/// Evaluates a browserslist query, eg `> 0.5% in AU, last 2 versions, not dead, Firefox ESR`, with regional usage from `regional_usage_source`, eg `EmbeddedRegionalUsages`.
+	/// Fails if `regional_usage_source` does not have the usage data of a region in the query.
+	#[inline(always)]
+	pub fn browserslist<S: RegionalUsageSource>(can_i_use: &CanIUse, regional_usage_source: &S, query: &str) -> Result<Self, BrowserslistQueryParseError>
+	{
+		let query: BrowserslistQuery = query.parse()?;
+		query.check_regional_usage_is_available(regional_usage_source)?;
+		Ok(query.evaluate(can_i_use, regional_usage_source))
+	}
+	
+	/// Evaluates the browserslist configuration (`.browserslistrc` or `package.json`) for a directory, exactly as browserslist for Node.js would resolve it (see `BrowserslistConfiguration::resolve()`), with regional usage from `regional_usage_source`.
+	#[inline(always)]
+	pub fn browserslist_configuration<S: RegionalUsageSource, P: AsRef<Path>>(can_i_use: &CanIUse, regional_usage_source: &S, directory: P, environment: Option<&str>) -> Result<Self, BrowserslistConfigurationError>
+	{
+		let query = BrowserslistConfiguration::resolve(directory, environment)?;
+		query.check_regional_usage_is_available(regional_usage_source)?;
+		Ok(query.evaluate(can_i_use, regional_usage_source))
+	}
+	
 	/// A sensible set of choices for an international website in multiple languages
 	#[inline(always)]
 	pub fn a_sensible_set_of_choices_for_an_international_website_in_multiple_languages(can_i_use: &CanIUse, maximum_release_age_from_can_i_use_database_last_updated: Duration, minimum_usage_threshold: UsagePercentage, regional_usages: &[&RegionalUsage]) -> Self
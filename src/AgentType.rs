@@ -3,7 +3,7 @@
 
 
 /// Represents whether an agent is used primarily of the desktop or a mobile device
-#[derive(Deserialize, Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Deserialize, Serialize, Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub enum AgentType
 {
 	/// This agent is used on the desktop
@@ -3,7 +3,7 @@
 
 
 /// A Bug is a text note. Not often used by the caniuse.com database.
-#[derive(Deserialize, Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Deserialize, Serialize, Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct Bug
 {
 	description: String,
@@ -4,19 +4,22 @@
 
 /// A database of data relating to caniuse.com
 /// Not used directly, but references should be passed to methods on AgentName, FeatureName, EraName, and, less usefully, Status and ParentCategory.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct CanIUse
 {
-	agents: HashMap<AgentName, AgentDetail>,
+	#[serde(deserialize_with = "LoadErrorContext::deserialize_map")] agents: HashMap<AgentName, AgentDetail>,
 	statuses: HashMap<Status, String>,
 	#[serde(rename = "cats")] child_categories: HashMap<ParentCategory, Vec<Category>>,
-	#[serde(deserialize_with = "CanIUse::updated_deserialize")] updated: DateTime<Utc>,
-	#[serde(rename = "data")] features: HashMap<FeatureName, FeatureDetail>,
+	#[serde(serialize_with = "CanIUse::updated_serialize", deserialize_with = "CanIUse::updated_deserialize")] updated: DateTime<Utc>,
+	#[serde(rename = "data", deserialize_with = "LoadErrorContext::deserialize_map")] features: HashMap<FeatureName, FeatureDetail>,
+	#[serde(flatten)] extra_fields: BTreeMap<String, Value>,
 }
 
+#[cfg(feature = "embedded-database")]
 impl Default for CanIUse
 {
 	/// Defaults to the up-to-date version of the caniuse.com database shipped embedded in this crate.
+	/// Requires the cargo feature `embedded-database`.
 	#[inline(always)]
 	fn default() -> Self
 	{
@@ -30,13 +33,13 @@ impl Default for CanIUse
 
 impl FromStr for CanIUse
 {
-	type Err = ::serde_json::error::Error;
+	type Err = LoadError;
 	
 	/// Deserialize a CanIUse database from a UTF-8 string representing the contents of a `data-2.0.json` file (typically in `fulldata-json/`).
 	#[inline(always)]
 	fn from_str(can_i_use_database_json: &str) -> Result<Self, Self::Err>
 	{
-		::serde_json::from_str(can_i_use_database_json)
+		LoadErrorContext::load(|| ::serde_json::from_str(can_i_use_database_json))
 	}
 }
 
@@ -44,23 +47,141 @@ impl CanIUse
 {
 	/// Deserialize a CanIUse database from a file path to a `data-2.0.json` file (typically in `fulldata-json/`).
 	#[inline(always)]
-	pub fn from_path<P: AsRef<Path>>(can_i_use_database_file_path: P) -> Result<Self, Box<::std::error::Error>>
+	pub fn from_path<P: AsRef<Path>>(can_i_use_database_file_path: P) -> Result<Self, LoadError>
 	{
-		Self::from_reader(File::open(can_i_use_database_file_path)?)
+		Self::from_path_with_options(can_i_use_database_file_path, &LoadOptions::default())
+	}
+	
+	/// Deserialize a CanIUse database from a file path to a `data-2.0.json` file (typically in `fulldata-json/`), eg strictly.
+	#[inline(always)]
+	pub fn from_path_with_options<P: AsRef<Path>>(can_i_use_database_file_path: P, options: &LoadOptions) -> Result<Self, LoadError>
+	{
+		Self::from_reader_with_options(File::open(can_i_use_database_file_path)?, options)
 	}
 	
 	/// Deserialize a CanIUse database from a readable stream of raw JSON bytes.
 	#[inline(always)]
-	pub fn from_reader<R: Read>(reader_of_stream_of_can_i_use_json_bytes: R) -> Result<Self, Box<::std::error::Error>>
+	pub fn from_reader<R: Read>(reader_of_stream_of_can_i_use_json_bytes: R) -> Result<Self, LoadError>
 	{
-		Ok(serde_json::from_reader(reader_of_stream_of_can_i_use_json_bytes)?)
+		Self::from_reader_with_options(reader_of_stream_of_can_i_use_json_bytes, &LoadOptions::default())
+	}
+	
+	/// Deserialize a CanIUse database from a readable stream of raw JSON bytes, eg strictly.
+	/// Strict loading reads the entire stream into memory first.
+	#[inline(always)]
+	pub fn from_reader_with_options<R: Read>(mut reader_of_stream_of_can_i_use_json_bytes: R, options: &LoadOptions) -> Result<Self, LoadError>
+	{
+		if options.strict
+		{
+			let mut raw_can_i_use_json_bytes = Vec::new();
+			reader_of_stream_of_can_i_use_json_bytes.read_to_end(&mut raw_can_i_use_json_bytes)?;
+			Self::from_slice_with_options(&raw_can_i_use_json_bytes, options)
+		}
+		else
+		{
+			LoadErrorContext::load(|| ::serde_json::from_reader(reader_of_stream_of_can_i_use_json_bytes))
+		}
 	}
 	
 	/// Deserialize a CanIUse database from a slice of raw JSON bytes.
 	#[inline(always)]
-	pub fn from_slice(raw_can_i_use_json_bytes: &[u8]) -> Result<Self, ::serde_json::error::Error>
+	pub fn from_slice(raw_can_i_use_json_bytes: &[u8]) -> Result<Self, LoadError>
+	{
+		Self::from_slice_with_options(raw_can_i_use_json_bytes, &LoadOptions::default())
+	}
+	
+	/// Deserialize a CanIUse database from a slice of raw JSON bytes, eg strictly.
+	#[inline(always)]
+	pub fn from_slice_with_options(raw_can_i_use_json_bytes: &[u8], options: &LoadOptions) -> Result<Self, LoadError>
+	{
+		let can_i_use = LoadErrorContext::load(|| ::serde_json::from_slice(raw_can_i_use_json_bytes))?;
+		if options.strict
+		{
+			StrictLoad::can_i_use(raw_can_i_use_json_bytes)?;
+		}
+		Ok(can_i_use)
+	}
+	
+	/// Deserialize a CanIUse database from a UTF-8 string representing the contents of a `data-2.0.json` file (typically in `fulldata-json/`), eg strictly.
+	#[inline(always)]
+	pub fn from_str_with_options(can_i_use_database_json: &str, options: &LoadOptions) -> Result<Self, LoadError>
 	{
-		Ok(serde_json::from_slice(raw_can_i_use_json_bytes)?)
+		Self::from_slice_with_options(can_i_use_database_json.as_bytes(), options)
+	}
+	
+	/// Assembles a CanIUse database from a checkout of the caniuse.com git repository, such as the `lib/caniuse` submodule.
+	/// Features are read from the per-feature files in `features-json/`; agents, statuses, categories and when the database was last updated are read from `fulldata-json/data-2.0.json`.
+	/// This allows pinning to an exact upstream commit.
+	#[inline(always)]
+	pub fn from_caniuse_repository<P: AsRef<Path>>(caniuse_repository_folder_path: P) -> Result<Self, LoadError>
+	{
+		Self::from_caniuse_repository_with_options(caniuse_repository_folder_path, &LoadOptions::default())
+	}
+	
+	/// Assembles a CanIUse database from a checkout of the caniuse.com git repository, eg strictly; see `from_caniuse_repository()`.
+	/// Paths in strict loading errors for features are as if the feature were in `fulldata-json/data-2.0.json`, eg `/data/css-grid/stats/kaios`.
+	#[inline(always)]
+	pub fn from_caniuse_repository_with_options<P: AsRef<Path>>(caniuse_repository_folder_path: P, options: &LoadOptions) -> Result<Self, LoadError>
+	{
+		#[derive(Deserialize)]
+		struct CanIUseWithoutFeatures
+		{
+			#[serde(deserialize_with = "LoadErrorContext::deserialize_map")] agents: HashMap<AgentName, AgentDetail>,
+			statuses: HashMap<Status, String>,
+			#[serde(rename = "cats")] child_categories: HashMap<ParentCategory, Vec<Category>>,
+			#[serde(deserialize_with = "CanIUse::updated_deserialize")] updated: DateTime<Utc>,
+			#[serde(default, rename = "data")] _features: IgnoredAny,
+			#[serde(flatten)] extra_fields: BTreeMap<String, Value>,
+		}
+		
+		let caniuse_repository_folder_path = caniuse_repository_folder_path.as_ref();
+		
+		let mut raw_agents_json_bytes = Vec::new();
+		File::open(caniuse_repository_folder_path.join("fulldata-json").join("data-2.0.json"))?.read_to_end(&mut raw_agents_json_bytes)?;
+		let CanIUseWithoutFeatures { agents, statuses, child_categories, updated, extra_fields, .. } = LoadErrorContext::load(|| ::serde_json::from_slice(&raw_agents_json_bytes))?;
+		if options.strict
+		{
+			StrictLoad::can_i_use_without_features(&raw_agents_json_bytes)?;
+		}
+		
+		let mut features = HashMap::new();
+		for entry in read_dir(caniuse_repository_folder_path.join("features-json"))?
+		{
+			let feature_file_path = entry?.path();
+			if feature_file_path.extension() != Some("json".as_ref())
+			{
+				continue;
+			}
+			
+			let feature_name = match feature_file_path.file_stem().and_then(|file_stem| file_stem.to_str())
+			{
+				None => continue,
+				Some(file_stem) => FeatureName(file_stem.to_owned()),
+			};
+			
+			let mut raw_feature_json_bytes = Vec::new();
+			File::open(&feature_file_path)?.read_to_end(&mut raw_feature_json_bytes)?;
+			let feature_detail = LoadErrorContext::load(||
+			{
+				feature_name.enter();
+				::serde_json::from_slice(&raw_feature_json_bytes)
+			})?;
+			if options.strict
+			{
+				StrictLoad::feature_file(&feature_name, &raw_feature_json_bytes)?;
+			}
+			features.insert(feature_name, feature_detail);
+		}
+		
+		Ok(CanIUse
+		{
+			agents,
+			statuses,
+			child_categories,
+			updated,
+			features,
+			extra_fields,
+		})
 	}
 	
 	/// A timestamp of when this particular database was last updated.
@@ -70,6 +191,14 @@ impl CanIUse
 		self.updated
 	}
 	
+	/// A top-level field of the caniuse.com database that this crate does not model, eg `eras`.
+	/// Such fields are preserved when loading leniently and written back out when serializing.
+	#[inline(always)]
+	pub fn extra_field(&self, name: &str) -> Option<&Value>
+	{
+		self.extra_fields.get(name)
+	}
+	
 	/// An iterator over the AgentNames known in this caniuse.com database
 	#[inline(always)]
 	pub fn known_agent_names(&self) -> AgentNameIterator
@@ -98,6 +227,29 @@ impl CanIUse
 		FeatureNameIterator(self.features.keys())
 	}
 	
+	/// Resolves a HTTP `User-Agent` header to an agent and a version known to this database.
+	#[inline(always)]
+	pub fn resolve_user_agent(&self, user_agent: &str) -> UserAgentResolution
+	{
+		UserAgentResolution::resolve(self, user_agent)
+	}
+	
+	/// Does the agent of a HTTP `User-Agent` header support a feature?
+	/// `policy` decides whether almost supported, supported using a polyfill and disabled by default count as support.
+	#[inline(always)]
+	pub fn supports<'a>(&'a self, user_agent: &str, feature_name: &FeatureName, policy: &SupportPolicy) -> UserAgentSupport<'a>
+	{
+		UserAgentSupport::evaluate(self, user_agent, feature_name, policy)
+	}
+	
+	/// Writes this database as Rust source for a `static_data::StaticCanIUse` named `static_name`; typically used from a build script to write a file in `OUT_DIR` that is then `include!`d.
+	/// The result needs no parsing or heap allocation at start up.
+	#[inline(always)]
+	pub fn write_static_rust_source<W: Write>(&self, writer: &mut W, static_name: &str) -> io::Result<()>
+	{
+		StaticRustSource(writer).can_i_use(static_name, self)
+	}
+	
 	#[inline(always)]
 	fn agent<'a>(&'a self, agent_name: &'a AgentName) -> Option<Agent<'a>>
 	{
@@ -130,6 +282,13 @@ impl CanIUse
 		self.child_categories.get(parentCategory).map(|value| &value[..])
 	}
 	
+	#[inline(always)]
+	fn updated_serialize<S: Serializer>(updated: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		// Cast here is deliberate; we deliberately parse expecting a non-negative timestamp
+		serializer.serialize_u64(updated.timestamp() as u64)
+	}
+	
 	#[inline(always)]
 	fn updated_deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
 	{
@@ -220,8 +379,10 @@ impl CanIUse
 	}
 }
 
+#[cfg(feature = "embedded-database")]
 lazy_static!
 {
 	/// The up-to-date version of the caniuse.com database shipped embedded in this crate.
+	/// Requires the cargo feature `embedded-database`.
 	#[derive(Debug)] pub static ref EmbeddedCanIUseDatabase: CanIUse = CanIUse::default();
 }
@@ -108,3 +108,42 @@ impl<'de> Deserialize<'de> for Category
 		deserializer.deserialize_str(CategoryVisitor)
 	}
 }
+
+impl Serialize for Category
+{
+	/// Serialize using Serde
+	#[inline(always)]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		serializer.serialize_str(self.can_i_use_identifier())
+	}
+}
+
+impl Category
+{
+	/// The identifier used for this category in the caniuse.com database, eg `JS API`.
+	#[inline(always)]
+	pub fn can_i_use_identifier(&self) -> &str
+	{
+		use self::Category::*;
+		
+		match *self
+		{
+			HTML5 => "HTML5",
+			CSS => "CSS",
+			CSS2 => "CSS2",
+			CSS3 => "CSS3",
+			SVG => "SVG",
+			PNG => "PNG",
+			JS_API => "JS API",
+			Canvas => "Canvas",
+			DOM => "DOM",
+			Other => "Other",
+			JS => "JS",
+			Security => "Security",
+			
+			Unknown(ref identifier) => identifier,
+			__Nonexhaustive => unreachable!(),
+		}
+	}
+}
@@ -0,0 +1,77 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// A comma separated list of strings, eg a feature's `keywords`; the strings are trimmed and empty strings are omitted, but the original text is retained so that it can be written back out unchanged.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct CommaSeparatedStrings
+{
+	strings: Vec<String>,
+	original: String,
+}
+
+impl<'de> Deserialize<'de> for CommaSeparatedStrings
+{
+	/// Deserialize using Serde
+	#[inline(always)]
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>
+	{
+		struct CommaSeparatedStringsVisitor;
+		
+		impl<'de> Visitor<'de> for CommaSeparatedStringsVisitor
+		{
+			type Value = CommaSeparatedStrings;
+			
+			fn expecting(&self, formatter: &mut Formatter) -> fmt::Result
+			{
+				formatter.write_str("a string which contains comma separated sub-strings")
+			}
+			
+			fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E>
+			{
+				let mut strings = Vec::with_capacity(16);
+				for string in v.split(',')
+				{
+					let trimmed = string.trim();
+					if !trimmed.is_empty()
+					{
+						strings.push(trimmed.to_owned());
+					}
+				}
+				strings.shrink_to_fit();
+				
+				Ok
+				(
+					CommaSeparatedStrings
+					{
+						strings,
+						original: v.to_owned(),
+					}
+				)
+			}
+		}
+		
+		deserializer.deserialize_str(CommaSeparatedStringsVisitor)
+	}
+}
+
+impl Serialize for CommaSeparatedStrings
+{
+	/// Serialize using Serde; serializes the original text
+	#[inline(always)]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		serializer.serialize_str(&self.original)
+	}
+}
+
+impl Deref for CommaSeparatedStrings
+{
+	type Target = [String];
+	
+	#[inline(always)]
+	fn deref(&self) -> &Self::Target
+	{
+		&self.strings
+	}
+}
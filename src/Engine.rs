@@ -0,0 +1,75 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// A rendering (and JavaScript) engine used by agents.
+/// Many agents share an engine; most Android browsers, for example, are Blink with a known Chromium version.
+#[derive(Deserialize, Serialize, Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Engine
+{
+	/// Blink, Chromium's engine; versions are Chromium versions.
+	Blink,
+	
+	/// Gecko, Firefox's engine; versions are Gecko versions (the same as Firefox's since Firefox 5).
+	Gecko,
+	
+	/// WebKit, Safari's engine; versions are those of the Safari release shipping that WebKit.
+	WebKit,
+	
+	/// Trident, Internet Explorer's engine.
+	Trident,
+	
+	/// EdgeHTML, the engine of Microsoft Edge before it switched to Blink.
+	EdgeHTML,
+	
+	/// Presto, Opera's engine before it switched to Blink.
+	Presto,
+}
+
+impl Display for Engine
+{
+	/// Displays the name of this engine, eg `Blink`.
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.write_str(self.name())
+	}
+}
+
+impl Engine
+{
+	/// The name of this engine, eg `Blink`.
+	#[inline(always)]
+	pub fn name(&self) -> &'static str
+	{
+		use self::Engine::*;
+		
+		match *self
+		{
+			Blink => "Blink",
+			Gecko => "Gecko",
+			WebKit => "WebKit",
+			Trident => "Trident",
+			EdgeHTML => "EdgeHTML",
+			Presto => "Presto",
+		}
+	}
+	
+	/// The identifier used for this engine in an esbuild `--target`, eg `chrome` for Blink; esbuild's `ie` and `firefox` versions are Internet Explorer and Firefox versions rather than Trident and Gecko versions.
+	/// Returns None for Presto, which esbuild does not know.
+	#[inline(always)]
+	pub fn esbuild_identifier(&self) -> Option<&'static str>
+	{
+		use self::Engine::*;
+		
+		match *self
+		{
+			Blink => Some("chrome"),
+			Gecko => Some("firefox"),
+			WebKit => Some("safari"),
+			Trident => Some("ie"),
+			EdgeHTML => Some("edge"),
+			Presto => None,
+		}
+	}
+}
@@ -94,6 +94,7 @@ impl<'a> Feature<'a>
 	}
 	
 	/// implementation; returns None if agent_name has no known usages.
+	/// version need not be known; it is resolved to a known version with `Version::resolve()`, eg "15.2" to iOS Safari's "15.2-15.3", or "118.0.5993" to Chrome's "118".
 	/// returns Some(None) if agent_name exists but not for the version.
 	/// returns Some(Some(support) if agent_name exists and the version has known support
 	#[inline(always)]
@@ -104,19 +105,126 @@ impl<'a> Feature<'a>
 			None => None,
 			Some(entry) =>
 			{
-				match entry.get(version)
+				match version.resolve(entry)
 				{
 					None => Some(None),
-					Some(support_detail) => Some(Some(Support
+					Some((_, support_detail)) => Some(Some(Support
 					{
 						support_detail,
 						feature: self,
+						inferred_from_google_chrome_version: None,
 					}))
 				}
 			}
 		}
 	}
 	
+	/// implementation, but with support for a Chromium-derived agent (other than Google Chrome itself) that caniuse.com does not know, ie `SupportMaturity::SupportUnknown` or no entry for the version, inferred from the equivalent Google Chrome version; see `SupportInference`.
+	/// Inferred support is marked as such; see `Support::is_inferred()`.
+	/// returns None if agent_name has no known usages.
+	#[inline(always)]
+	pub fn inferred_implementation(&'a self, agent_name: &AgentName, version: &Version, support_inference: &SupportInference) -> Option<Option<Support<'a>>>
+	{
+		let implementation = self.implementation(agent_name, version);
+		
+		let is_unknown = match implementation
+		{
+			None => return None,
+			Some(None) => true,
+			Some(Some(ref support)) => support.maturity() == SupportMaturity::SupportUnknown,
+		};
+		if !is_unknown || *agent_name == AgentName::GoogleChrome
+		{
+			return implementation;
+		}
+		
+		let chromium_version = match support_inference.chromium_version(agent_name, version)
+		{
+			None => return implementation,
+			Some(chromium_version) => chromium_version,
+		};
+		
+		let google_chrome_implementations = match self.feature_detail.implementations_by_agents.get(&AgentName::GoogleChrome)
+		{
+			None => return implementation,
+			Some(google_chrome_implementations) => google_chrome_implementations,
+		};
+		
+		match chromium_version.resolve(google_chrome_implementations)
+		{
+			Some((google_chrome_version, support_detail)) if support_detail.maturity() != SupportMaturity::SupportUnknown => Some(Some(Support
+			{
+				support_detail,
+				feature: self,
+				inferred_from_google_chrome_version: Some(google_chrome_version),
+			})),
+			_ => implementation,
+		}
+	}
+	
+	/// The oldest version of each agent with support for this feature according to `policy`, ie from which version of each agent this feature is usable; the version is None if no version of the agent has support.
+	/// Support may have been removed in a later version; see `support_removals()`.
+	/// As for `support_removals()`, versions without a major number, such as Safari's TP, are ignored, so support only in TP is None.
+	#[inline(always)]
+	pub fn first_supported_versions(&self, policy: &SupportPolicy) -> HashMap<AgentName, Option<Version>>
+	{
+		self.feature_detail.implementations_by_agents.iter().map(|(agent_name, implementations)|
+		{
+			let first_supported_version = implementations.iter().find(|&(version, support_detail)| version.major_number().is_some() && support_detail.maturity() != SupportMaturity::SupportUnknown && policy.supports_detail(support_detail)).map(|(version, _)| version.clone());
+			(agent_name.clone(), first_supported_version)
+		}).collect()
+	}
+	
+	/// Agents where support for this feature according to `policy` was removed in a later version, say because a prefixed implementation was dropped, with each removal in version order.
+	/// Versions with unknown support (`SupportMaturity::SupportUnknown`) and versions without a major number, such as Safari's TP, are ignored.
+	/// Agents without any removals are omitted.
+	#[inline(always)]
+	pub fn support_removals(&self, policy: &SupportPolicy) -> HashMap<AgentName, Vec<SupportRemoval>>
+	{
+		let mut support_removals = HashMap::new();
+		
+		for (agent_name, implementations) in self.feature_detail.implementations_by_agents.iter()
+		{
+			let mut removals = Vec::new();
+			let mut last_supported_version = None;
+			let mut unrestored_removal: Option<SupportRemoval> = None;
+			
+			for (version, support_detail) in implementations.iter().filter(|&(version, support_detail)| version.major_number().is_some() && support_detail.maturity() != SupportMaturity::SupportUnknown)
+			{
+				if policy.supports_detail(support_detail)
+				{
+					if let Some(mut removal) = unrestored_removal.take()
+					{
+						removal.restored_in_version = Some(version.clone());
+						removals.push(removal);
+					}
+					last_supported_version = Some(version);
+				}
+				else if let Some(last_supported_version) = last_supported_version.take()
+				{
+					unrestored_removal = Some(SupportRemoval
+					{
+						last_supported_version: last_supported_version.clone(),
+						removed_in_version: version.clone(),
+						restored_in_version: None,
+					});
+				}
+			}
+			
+			if let Some(removal) = unrestored_removal
+			{
+				removals.push(removal);
+			}
+			
+			if !removals.is_empty()
+			{
+				support_removals.insert(agent_name.clone(), removals);
+			}
+		}
+		
+		support_removals
+	}
+	
 	/// The supported usage of this feature; those agents where the feature is SupportMaturity::SupportedByDefault.
 	#[inline(always)]
 	pub fn supported_by_default_usage(&self) -> UsagePercentage
@@ -221,4 +329,12 @@ impl<'a> Feature<'a>
 	{
 		!self.feature_detail.shown
 	}
+	
+	/// A field of this feature in the caniuse.com database that this crate does not model.
+	/// Such fields are preserved when loading leniently and written back out when serializing, so new upstream data can be used before this crate models it.
+	#[inline(always)]
+	pub fn extra_field(&self, name: &str) -> Option<&'a Value>
+	{
+		self.feature_detail.extra_fields.get(name)
+	}
 }
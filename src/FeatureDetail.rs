@@ -2,7 +2,7 @@
 // Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
 
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 struct FeatureDetail
 {
 	#[serde(default)] title: String,
@@ -12,23 +12,34 @@ struct FeatureDetail
 	#[serde(default)] links: Vec<Link>,
 	#[serde(default)] bugs: Vec<Bug>,
 	#[serde(default)] categories: Vec<Category>,
-	#[serde(default, rename = "stats")] implementations_by_agents: HashMap<AgentName, BTreeMap<Version, SupportDetail>>,
+	#[serde(default, rename = "stats", deserialize_with = "LoadErrorContext::deserialize_map_of_maps")] implementations_by_agents: HashMap<AgentName, BTreeMap<Version, SupportDetail>>,
 	#[serde(default)] notes: String,
 	#[serde(default, rename = "notes_by_num")] notes_by_one_based_number: BTreeMap<u8, String>,
-	#[serde(default, deserialize_with = "FeatureDetail::deserialize_parent")] parent: Option<FeatureName>,
+	#[serde(default, serialize_with = "FeatureDetail::serialize_parent", deserialize_with = "FeatureDetail::deserialize_parent")] parent: Option<FeatureName>,
 	#[serde(default, rename="usage_perc_y")] supported_by_default_usage: UsagePercentage,
 	#[serde(default, rename="usage_perc_a")] almost_supported_usage: UsagePercentage,
 	#[serde(default, rename="ucprefix")] upper_case_prefix: bool,
-	#[serde(default, deserialize_with = "FeatureDetail::deserialize_comma_separated_strings")] keywords: Vec<String>,
-	#[serde(default, deserialize_with = "FeatureDetail::deserialize_comma_separated_strings", rename="ie_id")] internet_explorer_feature_identifiers: Vec<String>,
-	#[serde(default, deserialize_with = "FeatureDetail::deserialize_comma_separated_strings", rename="chrome_id")] blink_feature_identifiers: Vec<String>,
-	#[serde(default, deserialize_with = "FeatureDetail::deserialize_comma_separated_strings", rename="firefox_id")] firefox_feature_identifiers: Vec<String>,
-	#[serde(default, deserialize_with = "FeatureDetail::deserialize_comma_separated_strings", rename="webkit_id")] webkit_feature_identifiers: Vec<String>,
+	#[serde(default)] keywords: CommaSeparatedStrings,
+	#[serde(default, rename="ie_id")] internet_explorer_feature_identifiers: CommaSeparatedStrings,
+	#[serde(default, rename="chrome_id")] blink_feature_identifiers: CommaSeparatedStrings,
+	#[serde(default, rename="firefox_id")] firefox_feature_identifiers: CommaSeparatedStrings,
+	#[serde(default, rename="webkit_id")] webkit_feature_identifiers: CommaSeparatedStrings,
 	#[serde(default = "FeatureDetail::shown_default")] shown: bool,
+	#[serde(flatten)] extra_fields: BTreeMap<String, Value>,
 }
 
 impl FeatureDetail
 {
+	#[inline(always)]
+	fn serialize_parent<S: Serializer>(parent: &Option<FeatureName>, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		match *parent
+		{
+			None => serializer.serialize_str(""),
+			Some(ref feature_name) => serializer.serialize_str(feature_name),
+		}
+	}
+	
 	#[inline(always)]
 	fn deserialize_parent<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<FeatureName>, D::Error>
 	{
@@ -71,44 +82,6 @@ impl FeatureDetail
 		deserializer.deserialize_str(ParentVisitor)
 	}
 	
-	#[inline(always)]
-	fn deserialize_comma_separated_strings<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error>
-	{
-		struct CommaSeparatedStringsVisitor;
-		
-		impl<'de> Visitor<'de> for CommaSeparatedStringsVisitor
-		{
-			type Value = Vec<String>;
-			
-			fn expecting(&self, formatter: &mut Formatter) -> fmt::Result
-			{
-				formatter.write_str("a string which contains comma separated sub-strings")
-			}
-			
-			fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E>
-			{
-				if v.is_empty()
-				{
-					return Ok(vec![]);
-				}
-				
-				let mut strings = Vec::with_capacity(16);
-				for string in v.split(',')
-				{
-					let trimmed = string.trim();
-					if !trimmed.is_empty()
-					{
-						strings.push(trimmed.to_owned());
-					}
-				}
-				strings.shrink_to_fit();
-				Ok(strings)
-			}
-		}
-		
-		deserializer.deserialize_str(CommaSeparatedStringsVisitor)
-	}
-	
 	#[inline(always)]
 	fn shown_default() -> bool
 	{
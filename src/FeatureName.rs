@@ -3,7 +3,7 @@
 
 
 /// A feature name is a lower case, possibly hyphenated string representing a particular HTML, CSS or like feature that agents may not have support for.
-#[derive(Deserialize, Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Deserialize, Serialize, Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct FeatureName(String);
 
 impl<I: Into<String>> From<I> for FeatureName
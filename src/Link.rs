@@ -3,7 +3,7 @@
 
 
 /// A Link is an URL and its title, representing a link to a specification document or subsection
-#[derive(Deserialize, Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Deserialize, Serialize, Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct Link
 {
 	#[serde(with = "url_serde")] url: Url,
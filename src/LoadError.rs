@@ -0,0 +1,176 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Represents the errors that can occur when loading a caniuse.com database or regional usage data.
+#[derive(Debug)]
+pub enum LoadError
+{
+	/// The data could not be opened or read.
+	Io(io::Error),
+	
+	/// The data was not valid JSON, or ended unexpectedly.
+	JsonSyntax
+	{
+		/// One-based line.
+		line: usize,
+		
+		/// One-based column.
+		column: usize,
+		
+		/// Underlying error.
+		cause: ::serde_json::error::Error,
+	},
+	
+	/// The data was valid JSON but not a valid caniuse.com database or regional usage data, eg a `stats` string such as `"y q"`.
+	SchemaViolation
+	{
+		/// One-based line.
+		line: usize,
+		
+		/// One-based column.
+		column: usize,
+		
+		/// The feature, agent and version being parsed when the error occurred, if any, and the underlying error.
+		context: Box<SchemaViolationContext>,
+	},
+	
+	/// Strict loading (see `LoadOptions::strict`) found a value this crate does not model, eg a new agent or category.
+	UnrecognisedValue
+	{
+		/// JSON Pointer (RFC 6901) to the value or, for a map key, its entry, eg `/agents/kaios`.
+		path: String,
+		
+		/// Unrecognised value.
+		value: String,
+	},
+	
+	/// Strict loading (see `LoadOptions::strict`) found a field of an agent or feature that this crate does not model.
+	UnrecognisedField
+	{
+		/// JSON Pointer (RFC 6901) to the field, eg `/data/css-grid/new_field`.
+		path: String,
+	},
+}
+
+impl From<io::Error> for LoadError
+{
+	#[inline(always)]
+	fn from(error: io::Error) -> Self
+	{
+		LoadError::Io(error)
+	}
+}
+
+impl From<::serde_json::error::Error> for LoadError
+{
+	/// Uses the feature, agent and version being parsed on this thread when `error` occurred.
+	#[inline(always)]
+	fn from(error: ::serde_json::error::Error) -> Self
+	{
+		use ::serde_json::error::Category;
+		use self::LoadError::*;
+		
+		let line = error.line();
+		let column = error.column();
+		
+		match error.classify()
+		{
+			Category::Io => Io(error.into()),
+			
+			Category::Syntax | Category::Eof => JsonSyntax
+			{
+				line,
+				column,
+				cause: error,
+			},
+			
+			Category::Data =>
+			{
+				let LoadErrorContext { feature, agent, version } = LoadErrorContext::take();
+				SchemaViolation
+				{
+					line,
+					column,
+					context: Box::new(SchemaViolationContext
+					{
+						feature,
+						agent,
+						version,
+						cause: error,
+					}),
+				}
+			}
+		}
+	}
+}
+
+impl Error for LoadError
+{
+	#[inline(always)]
+	fn description(&self) -> &str
+	{
+		use self::LoadError::*;
+		
+		match *self
+		{
+			Io(_) => "could not read data",
+			JsonSyntax { .. } => "invalid JSON",
+			SchemaViolation { .. } => "invalid caniuse.com data",
+			UnrecognisedValue { .. } => "unrecognised value in caniuse.com data",
+			UnrecognisedField { .. } => "unrecognised field in caniuse.com data",
+		}
+	}
+	
+	#[inline(always)]
+	fn cause(&self) -> Option<&dyn Error>
+	{
+		use self::LoadError::*;
+		
+		match *self
+		{
+			Io(ref cause) => Some(cause),
+			JsonSyntax { ref cause, .. } => Some(cause),
+			SchemaViolation { ref context, .. } => Some(&context.cause),
+			UnrecognisedValue { .. } | UnrecognisedField { .. } => None,
+		}
+	}
+}
+
+impl Display for LoadError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::LoadError::*;
+		
+		match *self
+		{
+			Io(ref cause) => write!(f, "could not read data: {}", cause),
+			
+			JsonSyntax { ref cause, .. } => write!(f, "invalid JSON: {}", cause),
+			
+			SchemaViolation { ref context, .. } =>
+			{
+				write!(f, "invalid caniuse.com data")?;
+				if let Some(ref feature) = context.feature
+				{
+					write!(f, " in feature '{}'", feature.0)?;
+				}
+				if let Some(ref agent) = context.agent
+				{
+					write!(f, " for agent '{}'", agent.can_i_use_identifier())?;
+				}
+				if let Some(ref version) = context.version
+				{
+					write!(f, " version '{}'", version)?;
+				}
+				write!(f, ": {}", context.cause)
+			}
+			
+			UnrecognisedValue { ref path, ref value } => write!(f, "unrecognised value in caniuse.com data '{}' at '{}'", value, path),
+			
+			UnrecognisedField { ref path } => write!(f, "unrecognised field in caniuse.com data at '{}'", path),
+		}
+	}
+}
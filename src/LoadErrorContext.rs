@@ -0,0 +1,165 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// The feature, agent and version being parsed on this thread; used to give context to a `LoadError::SchemaViolation`.
+/// Serde errors carry only a message, so this is recorded as map keys are deserialized (see `LoadErrorContextKey`) and taken when an error is converted.
+#[derive(Default, Debug, Clone)]
+struct LoadErrorContext
+{
+	feature: Option<FeatureName>,
+	agent: Option<AgentName>,
+	version: Option<Version>,
+}
+
+thread_local!
+{
+	static CurrentLoadErrorContext: RefCell<LoadErrorContext> = RefCell::new(LoadErrorContext::default());
+}
+
+impl LoadErrorContext
+{
+	/// Wraps a load so that its errors have context; any context left over from a previous load on this thread is discarded.
+	#[inline(always)]
+	fn load<T, F: FnOnce() -> Result<T, ::serde_json::error::Error>>(load: F) -> Result<T, LoadError>
+	{
+		Self::take();
+		let result = load();
+		let result = result.map_err(LoadError::from);
+		Self::take();
+		result
+	}
+	
+	#[inline(always)]
+	fn take() -> Self
+	{
+		CurrentLoadErrorContext.with(|context| ::std::mem::take(&mut *context.borrow_mut()))
+	}
+	
+	#[inline(always)]
+	fn modify<F: FnOnce(&mut LoadErrorContext)>(modify: F)
+	{
+		CurrentLoadErrorContext.with(|context| modify(&mut context.borrow_mut()))
+	}
+	
+	/// Deserializes a map, recording each key as context whilst its value is deserialized.
+	#[inline(always)]
+	fn deserialize_map<'de, D: Deserializer<'de>, K: LoadErrorContextKey + Deserialize<'de>, V: Deserialize<'de>, M: Default + Extend<(K, V)>>(deserializer: D) -> Result<M, D::Error>
+	{
+		struct ContextualMapVisitor<K, V, M>(PhantomData<(K, V, M)>);
+		
+		impl<'de, K: LoadErrorContextKey + Deserialize<'de>, V: Deserialize<'de>, M: Default + Extend<(K, V)>> Visitor<'de> for ContextualMapVisitor<K, V, M>
+		{
+			type Value = M;
+			
+			#[inline(always)]
+			fn expecting(&self, formatter: &mut Formatter) -> fmt::Result
+			{
+				formatter.write_str("a map")
+			}
+			
+			#[inline(always)]
+			fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error>
+			{
+				let mut result = M::default();
+				while let Some(key) = map.next_key::<K>()?
+				{
+					key.enter();
+					let value = map.next_value::<V>()?;
+					result.extend(Some((key, value)));
+				}
+				K::leave();
+				Ok(result)
+			}
+		}
+		
+		deserializer.deserialize_map(ContextualMapVisitor(PhantomData))
+	}
+	
+	/// Deserializes a map of maps, such as a feature's `stats`, recording each outer and inner key as context whilst its value is deserialized.
+	#[inline(always)]
+	fn deserialize_map_of_maps<'de, D: Deserializer<'de>, K: LoadErrorContextKey + Deserialize<'de> + Eq + Hash, L: LoadErrorContextKey + Deserialize<'de> + Ord, V: Deserialize<'de>>(deserializer: D) -> Result<HashMap<K, BTreeMap<L, V>>, D::Error>
+	{
+		struct ContextualMap<L, V>(BTreeMap<L, V>);
+		
+		impl<'de, L: LoadErrorContextKey + Deserialize<'de> + Ord, V: Deserialize<'de>> Deserialize<'de> for ContextualMap<L, V>
+		{
+			#[inline(always)]
+			fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>
+			{
+				LoadErrorContext::deserialize_map(deserializer).map(ContextualMap)
+			}
+		}
+		
+		let map_of_maps: HashMap<K, ContextualMap<L, V>> = Self::deserialize_map(deserializer)?;
+		Ok(map_of_maps.into_iter().map(|(key, ContextualMap(map))| (key, map)).collect())
+	}
+}
+
+/// A map key that is recorded as context.
+trait LoadErrorContextKey: Sized
+{
+	/// Record this key as context, discarding any more specific context.
+	fn enter(&self);
+	
+	/// Discard this kind of context and any more specific context.
+	fn leave();
+}
+
+impl LoadErrorContextKey for FeatureName
+{
+	#[inline(always)]
+	fn enter(&self)
+	{
+		LoadErrorContext::modify(|context| *context = LoadErrorContext
+		{
+			feature: Some(self.clone()),
+			agent: None,
+			version: None,
+		})
+	}
+	
+	#[inline(always)]
+	fn leave()
+	{
+		LoadErrorContext::modify(|context| *context = LoadErrorContext::default())
+	}
+}
+
+impl LoadErrorContextKey for AgentName
+{
+	#[inline(always)]
+	fn enter(&self)
+	{
+		LoadErrorContext::modify(|context|
+		{
+			context.agent = Some(self.clone());
+			context.version = None;
+		})
+	}
+	
+	#[inline(always)]
+	fn leave()
+	{
+		LoadErrorContext::modify(|context|
+		{
+			context.agent = None;
+			context.version = None;
+		})
+	}
+}
+
+impl LoadErrorContextKey for Version
+{
+	#[inline(always)]
+	fn enter(&self)
+	{
+		LoadErrorContext::modify(|context| context.version = Some(self.clone()))
+	}
+	
+	#[inline(always)]
+	fn leave()
+	{
+		LoadErrorContext::modify(|context| context.version = None)
+	}
+}
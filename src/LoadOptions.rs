@@ -0,0 +1,28 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Options for loading a caniuse.com database, eg with `CanIUse::from_path_with_options()`.
+/// The default is lenient.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct LoadOptions
+{
+	/// If true, error with `LoadError::UnrecognisedValue` for agents, statuses, categories, parent categories and prefixes that this crate does not model, and with `LoadError::UnrecognisedField` for fields of agents and features that this crate does not model.
+	/// If false, such values are loaded as `Unknown(_)` discriminants and such fields are preserved (eg see `Feature::extra_field()`), so that newer databases can still be used.
+	pub strict: bool,
+}
+
+impl LoadOptions
+{
+	/// Load values this crate does not model as `Unknown(_)` discriminants and preserve unrecognised fields; appropriate for production.
+	pub const Lenient: Self = LoadOptions
+	{
+		strict: false,
+	};
+	
+	/// Error for values this crate does not model and for unrecognised fields; appropriate for data pipelines that need to know when caniuse.com adds, say, a new agent.
+	pub const Strict: Self = LoadOptions
+	{
+		strict: true,
+	};
+}
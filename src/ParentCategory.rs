@@ -94,6 +94,16 @@ impl<'de> Deserialize<'de> for ParentCategory
 	}
 }
 
+impl Serialize for ParentCategory
+{
+	/// Serialize using Serde
+	#[inline(always)]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		serializer.serialize_str(self.can_i_use_identifier())
+	}
+}
+
 impl ParentCategory
 {
 	/// A list of one or more categories scoped by this parent category. Of limited use outside of the caniuse.com UI.
@@ -103,4 +113,25 @@ impl ParentCategory
 	{
 		canIUse.child_categories(self)
 	}
+	
+	/// The identifier used for this parent category in the caniuse.com database, eg `JS API`.
+	#[inline(always)]
+	pub fn can_i_use_identifier(&self) -> &str
+	{
+		use self::ParentCategory::*;
+		
+		match *self
+		{
+			CSS => "CSS",
+			HTML5 => "HTML5",
+			JS => "JS",
+			JS_API => "JS API",
+			Other => "Other",
+			Security => "Security",
+			SVG => "SVG",
+			
+			Unknown(ref identifier) => identifier,
+			__Nonexhaustive => unreachable!(),
+		}
+	}
 }
@@ -44,3 +44,33 @@ impl<'de> Deserialize<'de> for Prefix
 		}
 	}
 }
+
+impl Serialize for Prefix
+{
+	/// Serialize using Serde
+	#[inline(always)]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		serializer.serialize_str(self.can_i_use_identifier())
+	}
+}
+
+impl Prefix
+{
+	/// The identifier used for this prefix in the caniuse.com database, eg `webkit`; lacks leading and trailing hyphens.
+	#[inline(always)]
+	pub fn can_i_use_identifier(&self) -> &str
+	{
+		use self::Prefix::*;
+		
+		match *self
+		{
+			o => "o",
+			moz => "moz",
+			webkit => "webkit",
+			ms => "ms",
+			
+			Unknown(ref identifier) => identifier,
+		}
+	}
+}
@@ -0,0 +1,20 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// What was being parsed when a `LoadError::SchemaViolation` occurred; boxed so that `LoadError` stays small.
+#[derive(Debug)]
+pub struct SchemaViolationContext
+{
+	/// Feature being parsed, if any.
+	pub feature: Option<FeatureName>,
+	
+	/// Agent being parsed, if any.
+	pub agent: Option<AgentName>,
+	
+	/// Version being parsed, if any.
+	pub version: Option<Version>,
+	
+	/// Underlying error.
+	pub cause: ::serde_json::error::Error,
+}
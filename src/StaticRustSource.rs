@@ -0,0 +1,127 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Writes Rust source for the types in the `static_data` module; used by `CanIUse::write_static_rust_source()` and `RegionalUsage::write_static_rust_source()`.
+/// Types are written with absolute paths (eg `::caniuse_serde::static_data::StaticCanIUse`), so the source can be `include!`d anywhere in a crate that depends on this one.
+/// Maps are written as slices sorted by key so that output is reproducible; those keyed by version are in the order of `Version`, which `static_data` relies upon to resolve versions by walking them.
+#[derive(Debug)]
+struct StaticRustSource<'a, W: 'a + Write>(&'a mut W);
+
+impl<'a, W: 'a + Write> StaticRustSource<'a, W>
+{
+	const StaticData: &'static str = "::caniuse_serde::static_data";
+	
+	#[inline(always)]
+	fn can_i_use(&mut self, static_name: &str, can_i_use: &CanIUse) -> io::Result<()>
+	{
+		write!(self.0, "pub static {}: {}::StaticCanIUse = {}::StaticCanIUse {{ agents: ", static_name, Self::StaticData, Self::StaticData)?;
+		let mut agents: Vec<_> = can_i_use.agents.iter().collect();
+		agents.sort_by_key(|&(agent_name, _)| agent_name.can_i_use_identifier());
+		self.slice(agents, |this, (agent_name, agent_detail)| this.agent_detail(agent_name, agent_detail))?;
+		
+		self.0.write_all(b", statuses: ")?;
+		let mut statuses: Vec<_> = can_i_use.statuses.iter().map(|(status, description)| (status.can_i_use_identifier(), description)).collect();
+		statuses.sort();
+		self.slice(statuses, |this, (status, description)| write!(this.0, "({:?}, {:?})", status, description))?;
+		
+		self.0.write_all(b", child_categories: ")?;
+		let mut child_categories: Vec<_> = can_i_use.child_categories.iter().map(|(parent_category, child_categories)| (parent_category.can_i_use_identifier(), child_categories)).collect();
+		child_categories.sort();
+		self.slice(child_categories, |this, (parent_category, child_categories)|
+		{
+			write!(this.0, "({:?}, ", parent_category)?;
+			this.slice(child_categories.iter(), |this, category| write!(this.0, "{:?}", category.can_i_use_identifier()))?;
+			this.0.write_all(b")")
+		})?;
+		
+		write!(self.0, ", updated: {}, features: ", can_i_use.updated.timestamp())?;
+		let mut features: Vec<_> = can_i_use.features.iter().collect();
+		features.sort_by_key(|&(feature_name, _)| &feature_name.0);
+		self.slice(features, |this, (feature_name, feature_detail)| this.feature_detail(feature_name, feature_detail))?;
+		
+		writeln!(self.0, " }};")
+	}
+	
+	#[inline(always)]
+	fn agent_detail(&mut self, agent_name: &AgentName, agent_detail: &AgentDetail) -> io::Result<()>
+	{
+		write!(self.0, "{}::StaticAgentDetail {{ agent_name: {:?}, name: {:?}, abbreviated_name: {:?}, prefix: {:?}, agent_type: ::caniuse_serde::AgentType::{:?}, usage_global: ", Self::StaticData, agent_name.can_i_use_identifier(), agent_detail.name, agent_detail.abbreviated_name, agent_detail.prefix.can_i_use_identifier(), agent_detail.agent_type)?;
+		self.slice(agent_detail.usage_global.iter(), |this, (version, usage)| write!(this.0, "({:?}, {:?})", version.to_string(), usage.0))?;
+		
+		self.0.write_all(b", version_list: ")?;
+		self.slice(agent_detail.version_list.iter(), |this, (version, version_detail)|
+		{
+			write!(this.0, "{}::StaticVersionDetail {{ version: {:?}, global_usage: {:?}, release_date: ", Self::StaticData, version.to_string(), version_detail.global_usage.0)?;
+			match version_detail.release_date
+			{
+				None => this.0.write_all(b"None")?,
+				Some(release_date) => write!(this.0, "Some({})", release_date.timestamp())?,
+			}
+			write!(this.0, ", era: {}, prefix_override: ", version_detail.era)?;
+			match version_detail.prefix_override
+			{
+				None => this.0.write_all(b"None")?,
+				Some(ref prefix) => write!(this.0, "Some({:?})", prefix.can_i_use_identifier())?,
+			}
+			this.0.write_all(b" }")
+		})?;
+		
+		write!(self.0, ", current_version: {:?}, prefix_exceptions: ", agent_detail.current_version.to_string())?;
+		self.slice(agent_detail.prefix_exceptions.iter(), |this, (version, prefix)| write!(this.0, "({:?}, {:?})", version.to_string(), prefix.can_i_use_identifier()))?;
+		self.0.write_all(b" }")
+	}
+	
+	#[inline(always)]
+	fn feature_detail(&mut self, feature_name: &FeatureName, feature_detail: &FeatureDetail) -> io::Result<()>
+	{
+		write!(self.0, "{}::StaticFeatureDetail {{ feature_name: {:?}, title: {:?}, description: {:?}, specification_url: {:?}, status: {:?}, links: ", Self::StaticData, feature_name.0, feature_detail.title, feature_detail.description, feature_detail.specification_url.as_str(), feature_detail.status.can_i_use_identifier())?;
+		self.slice(feature_detail.links.iter(), |this, link| write!(this.0, "({:?}, {:?})", link.url.as_str(), link.title))?;
+		
+		self.0.write_all(b", bugs: ")?;
+		self.slice(feature_detail.bugs.iter(), |this, bug| write!(this.0, "{:?}", bug.description))?;
+		
+		self.0.write_all(b", categories: ")?;
+		self.slice(feature_detail.categories.iter(), |this, category| write!(this.0, "{:?}", category.can_i_use_identifier()))?;
+		
+		self.0.write_all(b", implementations_by_agents: ")?;
+		let mut implementations_by_agents: Vec<_> = feature_detail.implementations_by_agents.iter().collect();
+		implementations_by_agents.sort_by_key(|&(agent_name, _)| agent_name.can_i_use_identifier());
+		self.slice(implementations_by_agents, |this, (agent_name, implementations)|
+		{
+			write!(this.0, "({:?}, ", agent_name.can_i_use_identifier())?;
+			this.slice(implementations.iter(), |this, (version, support_detail)|
+			{
+				write!(this.0, "({:?}, {}::StaticSupportDetail {{ maturity: ::caniuse_serde::SupportMaturity::{:?}, requires_prefix: {}, disabled_by_default: {}, notes_by_one_based_number: &{:?} }})", version.to_string(), Self::StaticData, support_detail.maturity, support_detail.requires_prefix, support_detail.disabled_by_default, support_detail.notes_by_one_based_number)
+			})?;
+			this.0.write_all(b")")
+		})?;
+		
+		write!(self.0, ", notes: {:?}, notes_by_one_based_number: ", feature_detail.notes)?;
+		self.slice(feature_detail.notes_by_one_based_number.iter(), |this, (note_number, note)| write!(this.0, "({}, {:?})", note_number, note))?;
+		
+		self.0.write_all(b", parent: ")?;
+		match feature_detail.parent
+		{
+			None => self.0.write_all(b"None")?,
+			Some(ref parent) => write!(self.0, "Some({:?})", parent.0)?,
+		}
+		
+		write!(self.0, ", supported_by_default_usage: {:?}, almost_supported_usage: {:?}, upper_case_prefix: {}, keywords: &{:?}", feature_detail.supported_by_default_usage.0, feature_detail.almost_supported_usage.0, feature_detail.upper_case_prefix, &feature_detail.keywords[..])?;
+		write!(self.0, ", internet_explorer_feature_identifiers: &{:?}, blink_feature_identifiers: &{:?}, firefox_feature_identifiers: &{:?}, webkit_feature_identifiers: &{:?}", &feature_detail.internet_explorer_feature_identifiers[..], &feature_detail.blink_feature_identifiers[..], &feature_detail.firefox_feature_identifiers[..], &feature_detail.webkit_feature_identifiers[..])?;
+		write!(self.0, ", shown: {} }}", feature_detail.shown)
+	}
+	
+	/// Writes a slice expression, eg `&[a, b, ]`.
+	#[inline(always)]
+	fn slice<I: IntoIterator, F: FnMut(&mut Self, I::Item) -> io::Result<()>>(&mut self, items: I, mut write_item: F) -> io::Result<()>
+	{
+		self.0.write_all(b"&[")?;
+		for item in items
+		{
+			write_item(self, item)?;
+			self.0.write_all(b", ")?;
+		}
+		self.0.write_all(b"]")
+	}
+}
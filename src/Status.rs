@@ -90,6 +90,16 @@ impl<'de> Deserialize<'de> for Status
 	}
 }
 
+impl Serialize for Status
+{
+	/// Serialize using Serde
+	#[inline(always)]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		serializer.serialize_str(self.can_i_use_identifier())
+	}
+}
+
 impl Status
 {
 	/// A short piece of text describing this status.
@@ -99,4 +109,25 @@ impl Status
 	{
 		canIUse.status_description(self)
 	}
+	
+	/// The identifier used for this status in the caniuse.com database, eg `rec`.
+	#[inline(always)]
+	pub fn can_i_use_identifier(&self) -> &str
+	{
+		use self::Status::*;
+		
+		match *self
+		{
+			W3CRecommendation => "rec",
+			W3CProposedRecommendation => "pr",
+			W3CCandidateRecommendation => "cr",
+			W3CWorkingDraft => "wd",
+			WhatwgLivingStandard => "ls",
+			Other => "other",
+			UnofficialOrNote => "unoff",
+			
+			Unknown(ref identifier) => identifier,
+			__Nonexhaustive => unreachable!(),
+		}
+	}
 }
@@ -0,0 +1,221 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Checks the raw JSON of a caniuse.com database for values and fields that a lenient load would map to `Unknown(_)` discriminants or preserve as extra fields.
+/// Done after a successful load, so that syntax and schema errors are still reported with a line and column.
+/// Paths are JSON Pointers (RFC 6901), eg `/data/css-grid/stats/kaios`.
+#[derive(Debug, Copy, Clone)]
+struct StrictLoad;
+
+impl StrictLoad
+{
+	const AgentDetailFieldNames: &'static [&'static str] = &["browser", "abbr", "prefix", "type", "usage_global", "version_list", "current_version", "prefix_exceptions"];
+	
+	const FeatureDetailFieldNames: &'static [&'static str] = &["title", "description", "spec", "status", "links", "bugs", "categories", "stats", "notes", "notes_by_num", "parent", "usage_perc_y", "usage_perc_a", "ucprefix", "keywords", "ie_id", "chrome_id", "firefox_id", "webkit_id", "shown"];
+	
+	/// Checks a complete database, such as `fulldata-json/data-2.0.json`.
+	#[inline(always)]
+	fn can_i_use(raw_can_i_use_json_bytes: &[u8]) -> Result<(), LoadError>
+	{
+		let json = Self::parse(raw_can_i_use_json_bytes)?;
+		Self::agents_statuses_and_categories(&json)?;
+		
+		if let Some(features) = json.get("data").and_then(Value::as_object)
+		{
+			for (feature_identifier, feature) in features.iter()
+			{
+				Self::feature(&Self::path("/data", feature_identifier), feature)?;
+			}
+		}
+		Ok(())
+	}
+	
+	/// Checks a database without considering its features, as is done when its features are read from `features-json/`.
+	#[inline(always)]
+	fn can_i_use_without_features(raw_can_i_use_json_bytes: &[u8]) -> Result<(), LoadError>
+	{
+		Self::agents_statuses_and_categories(&Self::parse(raw_can_i_use_json_bytes)?)
+	}
+	
+	/// Checks a feature file in `features-json/`; paths are as if the feature were in a complete database.
+	#[inline(always)]
+	fn feature_file(feature_name: &FeatureName, raw_feature_json_bytes: &[u8]) -> Result<(), LoadError>
+	{
+		Self::feature(&Self::path("/data", feature_name), &Self::parse(raw_feature_json_bytes)?)
+	}
+	
+	#[inline(always)]
+	fn parse(raw_json_bytes: &[u8]) -> Result<Value, LoadError>
+	{
+		LoadErrorContext::load(|| ::serde_json::from_slice(raw_json_bytes))
+	}
+	
+	#[inline(always)]
+	fn agents_statuses_and_categories(json: &Value) -> Result<(), LoadError>
+	{
+		if let Some(agents) = json.get("agents").and_then(Value::as_object)
+		{
+			for (agent_identifier, agent) in agents.iter()
+			{
+				Self::agent(&Self::path("/agents", agent_identifier), agent_identifier, agent)?;
+			}
+		}
+		
+		if let Some(statuses) = json.get("statuses").and_then(Value::as_object)
+		{
+			for status_identifier in statuses.keys()
+			{
+				Self::value(Self::path("/statuses", status_identifier), status_identifier, |status: &Status| matches!(*status, Status::Unknown(_)))?;
+			}
+		}
+		
+		if let Some(child_categories) = json.get("cats").and_then(Value::as_object)
+		{
+			for (parent_category_identifier, categories) in child_categories.iter()
+			{
+				let path = Self::path("/cats", parent_category_identifier);
+				Self::value(path.clone(), parent_category_identifier, |parent_category: &ParentCategory| matches!(*parent_category, ParentCategory::Unknown(_)))?;
+				Self::categories(&path, categories)?;
+			}
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn agent(path: &str, agent_identifier: &str, agent: &Value) -> Result<(), LoadError>
+	{
+		Self::agent_name(path.to_owned(), agent_identifier)?;
+		Self::fields(path, agent, Self::AgentDetailFieldNames)?;
+		
+		if let Some(prefix_identifier) = agent.get("prefix").and_then(Value::as_str)
+		{
+			Self::prefix(Self::path(path, "prefix"), prefix_identifier)?;
+		}
+		
+		if let Some(prefix_exceptions) = agent.get("prefix_exceptions").and_then(Value::as_object)
+		{
+			let path = Self::path(path, "prefix_exceptions");
+			for (version, prefix) in prefix_exceptions.iter()
+			{
+				if let Some(prefix_identifier) = prefix.as_str()
+				{
+					Self::prefix(Self::path(&path, version), prefix_identifier)?;
+				}
+			}
+		}
+		
+		// Unrecognised fields of version list entries are already rejected by `AgentDetail::deserialize_version_list()`; an empty prefix is no prefix override.
+		if let Some(version_list) = agent.get("version_list").and_then(Value::as_array)
+		{
+			let path = Self::path(path, "version_list");
+			for (index, version_list_entry) in version_list.iter().enumerate()
+			{
+				match version_list_entry.get("prefix").and_then(Value::as_str)
+				{
+					None | Some("") => (),
+					Some(prefix_identifier) => Self::prefix(Self::path(&Self::path(&path, &index.to_string()), "prefix"), prefix_identifier)?,
+				}
+			}
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn feature(path: &str, feature: &Value) -> Result<(), LoadError>
+	{
+		Self::fields(path, feature, Self::FeatureDetailFieldNames)?;
+		
+		if let Some(status_identifier) = feature.get("status").and_then(Value::as_str)
+		{
+			Self::value(Self::path(path, "status"), status_identifier, |status: &Status| matches!(*status, Status::Unknown(_)))?;
+		}
+		
+		if let Some(categories) = feature.get("categories")
+		{
+			Self::categories(&Self::path(path, "categories"), categories)?;
+		}
+		
+		if let Some(implementations_by_agents) = feature.get("stats").and_then(Value::as_object)
+		{
+			let path = Self::path(path, "stats");
+			for agent_identifier in implementations_by_agents.keys()
+			{
+				Self::agent_name(Self::path(&path, agent_identifier), agent_identifier)?;
+			}
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn categories(path: &str, categories: &Value) -> Result<(), LoadError>
+	{
+		if let Value::Array(ref categories) = *categories
+		{
+			for (index, category) in categories.iter().enumerate()
+			{
+				if let Some(category_identifier) = category.as_str()
+				{
+					Self::value(Self::path(path, &index.to_string()), category_identifier, |category: &Category| matches!(*category, Category::Unknown(_)))?;
+				}
+			}
+		}
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn agent_name(path: String, agent_identifier: &str) -> Result<(), LoadError>
+	{
+		Self::value(path, agent_identifier, |agent_name: &AgentName| matches!(*agent_name, AgentName::Unknown(_)))
+	}
+	
+	#[inline(always)]
+	fn prefix(path: String, prefix_identifier: &str) -> Result<(), LoadError>
+	{
+		Self::value(path, prefix_identifier, |prefix: &Prefix| matches!(*prefix, Prefix::Unknown(_)))
+	}
+	
+	#[inline(always)]
+	fn fields(path: &str, object: &Value, field_names: &[&str]) -> Result<(), LoadError>
+	{
+		if let Value::Object(ref object) = *object
+		{
+			for field_name in object.keys()
+			{
+				if !field_names.contains(&&field_name[..])
+				{
+					return Err(LoadError::UnrecognisedField
+					{
+						path: Self::path(path, field_name),
+					});
+				}
+			}
+		}
+		Ok(())
+	}
+	
+	/// Deserializes `identifier` exactly as a lenient load would.
+	#[inline(always)]
+	fn value<T: DeserializeOwned>(path: String, identifier: &str, is_unknown: fn(&T) -> bool) -> Result<(), LoadError>
+	{
+		match ::serde_json::from_value(Value::String(identifier.to_owned()))
+		{
+			Ok(ref value) if !is_unknown(value) => Ok(()),
+			_ => Err(LoadError::UnrecognisedValue
+			{
+				path,
+				value: identifier.to_owned(),
+			}),
+		}
+	}
+	
+	/// Appends a reference token to a JSON Pointer, escaping `~` and `/`.
+	#[inline(always)]
+	fn path(path: &str, reference_token: &str) -> String
+	{
+		format!("{}/{}", path, reference_token.replace('~', "~0").replace('/', "~1"))
+	}
+}
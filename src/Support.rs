@@ -8,6 +8,7 @@ pub struct Support<'a>
 {
 	support_detail: &'a SupportDetail,
 	feature: &'a Feature<'a>,
+	inferred_from_google_chrome_version: Option<&'a Version>,
 }
 
 impl<'a> Support<'a>
@@ -33,11 +34,32 @@ impl<'a> Support<'a>
 		self.support_detail.disabled_by_default()
 	}
 	
+	/// Is this support inferred from that of the equivalent Google Chrome version rather than known by caniuse.com? See `Feature::inferred_implementation()`.
+	#[inline(always)]
+	pub fn is_inferred(&self) -> bool
+	{
+		self.inferred_from_google_chrome_version.is_some()
+	}
+	
+	/// The Google Chrome version this support was inferred from, if inferred; see `Feature::inferred_implementation()`.
+	#[inline(always)]
+	pub fn inferred_from_google_chrome_version(&self) -> Option<&'a Version>
+	{
+		self.inferred_from_google_chrome_version
+	}
+	
+	/// Does this support count as supported according to `policy`?
+	#[inline(always)]
+	pub fn is_supported(&self, policy: &SupportPolicy) -> bool
+	{
+		policy.supports(self)
+	}
+	
 	/// Returns a list of pairs of one-based note numbers (the list itself is zero-based) and note text
-	/// Panics if the feature does not contain the note number; this is only possible if the caniuse.com database is invalid or the wrong Feature is passed
+	/// Note numbers the feature does not contain are skipped; this is only possible if the caniuse.com database is invalid (see `CanIUse::validate()`)
 	#[inline(always)]
 	pub fn notes(&'a self) -> Vec<(u8, &'a str)>
 	{
-		self.support_detail.notes(self.feature)
+		self.support_detail.notes(self.feature.feature_detail)
 	}
 }
@@ -137,6 +137,35 @@ impl<'de> Deserialize<'de> for SupportDetail
 	}
 }
 
+impl Serialize for SupportDetail
+{
+	/// Serialize using Serde
+	#[inline(always)]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		let mut stats = String::with_capacity(16);
+		
+		stats.push_str(self.maturity.can_i_use_identifier());
+		
+		if self.requires_prefix
+		{
+			stats.push_str(" x");
+		}
+		
+		if self.disabled_by_default
+		{
+			stats.push_str(" d");
+		}
+		
+		for note_number in self.notes_by_one_based_number.iter()
+		{
+			stats.push_str(&format!(" #{}", note_number));
+		}
+		
+		serializer.serialize_str(&stats)
+	}
+}
+
 impl SupportDetail
 {
 	#[inline(always)]
@@ -158,14 +187,16 @@ impl SupportDetail
 	}
 	
 	#[inline(always)]
-	fn notes<'a>(&'a self, feature: &'a Feature) -> Vec<(u8, &'a str)>
+	fn notes<'a>(&'a self, feature_detail: &'a FeatureDetail) -> Vec<(u8, &'a str)>
 	{
 		let mut result = Vec::with_capacity(self.notes_by_one_based_number.len());
 		
 		for note_number in self.notes_by_one_based_number.iter()
 		{
-			let noteText = feature.feature_detail.notes_by_one_based_number.get(note_number).expect("Invalid caniuse.com database").as_str();
-			result.push((*note_number, noteText));
+			if let Some(noteText) = feature_detail.notes_by_one_based_number.get(note_number)
+			{
+				result.push((*note_number, noteText.as_str()));
+			}
 		}
 		
 		result
@@ -0,0 +1,43 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Infers support for Chromium-derived agents, such as Samsung Internet, QQ Browser, Baidu Browser and UC Browser, from the equivalent Google Chrome version where caniuse.com does not know it; see `Feature::inferred_implementation()`.
+/// The Chromium versions of agents are those given by `AgentName::engine()`, which knows those of the versions of UC Browser, QQ Browser and Baidu Browser listed by caniuse.com, plus any added with `add_chromium_version()` for agents or versions it does not know.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SupportInference
+{
+	chromium_versions: HashMap<AgentName, BTreeMap<Version, Version>>,
+}
+
+impl SupportInference
+{
+	/// Adds (or replaces) the Chromium version of a version (or range of versions) of a Chromium-derived agent, eg Chromium 57 for UC Browser for Android 11.8.
+	#[inline(always)]
+	pub fn add_chromium_version(&mut self, agent_name: AgentName, version: Version, chromium_version: Version) -> &mut Self
+	{
+		self.chromium_versions.entry(agent_name).or_default().insert(version, chromium_version);
+		self
+	}
+	
+	/// The Chromium version of a version of an agent; those added with `add_chromium_version()` take precedence over `AgentName::engine()`.
+	/// A version within an added range of versions has the Chromium version of that range; see `Version::contains()`.
+	/// Returns None if not known or if the agent is not Chromium-derived.
+	#[inline(always)]
+	pub fn chromium_version(&self, agent_name: &AgentName, version: &Version) -> Option<Version>
+	{
+		if let Some(chromium_versions) = self.chromium_versions.get(agent_name)
+		{
+			if let Some((_, chromium_version)) = version.find_in(chromium_versions)
+			{
+				return Some(chromium_version.clone());
+			}
+		}
+		
+		match agent_name.engine(version)
+		{
+			Some((Engine::Blink, chromium_version)) => chromium_version,
+			_ => None,
+		}
+	}
+}
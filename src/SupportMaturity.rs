@@ -23,3 +23,22 @@ impl Default for SupportMaturity
 		SupportMaturity::SupportUnknown
 	}
 }
+
+impl SupportMaturity
+{
+	/// The identifier used for this maturity in the `stats` of the caniuse.com database, eg `y`.
+	#[inline(always)]
+	pub fn can_i_use_identifier(&self) -> &'static str
+	{
+		use self::SupportMaturity::*;
+		
+		match *self
+		{
+			SupportedByDefault => "y",
+			AlmostSupported => "a",
+			NotSupportedOrDisabledByDefault => "n",
+			SupportedUsingAPolyfill => "p",
+			SupportUnknown => "u",
+		}
+	}
+}
@@ -0,0 +1,88 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Decides what counts as support.
+/// `SupportMaturity::SupportedByDefault` always counts as support, unless disabled by default.
+/// Support disabled by default counts only if both its maturity and `disabled_by_default` do; caniuse.com's `n d`, for support behind a flag, counts as `SupportMaturity::SupportedByDefault` would.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SupportPolicy
+{
+	/// Does `SupportMaturity::AlmostSupported` (partial support) count as support?
+	pub almost_supported: bool,
+	
+	/// Does `SupportMaturity::SupportedUsingAPolyfill` count as support?
+	pub supported_using_a_polyfill: bool,
+	
+	/// Does support that requires a prefix count as support?
+	pub accept_prefixed: bool,
+	
+	/// Does support behind a flag or some other mechanism that isn't normally enabled in a default install count as support?
+	pub disabled_by_default: bool,
+}
+
+impl Default for SupportPolicy
+{
+	/// Defaults to SupportPolicy::Strict
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self::Strict
+	}
+}
+
+impl SupportPolicy
+{
+	/// Only `SupportMaturity::SupportedByDefault` that is not disabled by default counts as support, prefixed or not.
+	pub const Strict: SupportPolicy = SupportPolicy
+	{
+		almost_supported: false,
+		supported_using_a_polyfill: false,
+		accept_prefixed: true,
+		disabled_by_default: false,
+	};
+	
+	/// `SupportMaturity::SupportedByDefault` and `SupportMaturity::AlmostSupported` that are not disabled by default count as support, prefixed or not.
+	pub const Lenient: SupportPolicy = SupportPolicy
+	{
+		almost_supported: true,
+		supported_using_a_polyfill: false,
+		accept_prefixed: true,
+		disabled_by_default: false,
+	};
+	
+	/// Does this support count as supported?
+	#[inline(always)]
+	pub fn supports(&self, support: &Support) -> bool
+	{
+		self.supports_detail(support.support_detail)
+	}
+	
+	#[inline(always)]
+	fn supports_detail(&self, support_detail: &SupportDetail) -> bool
+	{
+		self.supports_maturity(support_detail.maturity(), support_detail.requires_prefix(), support_detail.disabled_by_default())
+	}
+	
+	#[inline(always)]
+	fn supports_maturity(&self, maturity: SupportMaturity, requires_prefix: bool, disabled_by_default: bool) -> bool
+	{
+		use self::SupportMaturity::*;
+		
+		if requires_prefix && !self.accept_prefixed
+		{
+			return false;
+		}
+		
+		let supported = match maturity
+		{
+			SupportedByDefault => true,
+			AlmostSupported => self.almost_supported,
+			SupportedUsingAPolyfill => self.supported_using_a_polyfill,
+			NotSupportedOrDisabledByDefault => disabled_by_default,
+			SupportUnknown => false,
+		};
+		
+		supported && (!disabled_by_default || self.disabled_by_default)
+	}
+}
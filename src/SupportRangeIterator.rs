@@ -49,6 +49,7 @@ impl<'a> SupportRangeIterator<'a>
 					{
 						support_detail,
 						feature: self.feature,
+						inferred_from_google_chrome_version: None,
 					}
 				)
 			}
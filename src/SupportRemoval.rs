@@ -0,0 +1,17 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// The removal of support for a feature from a version of an agent that followed a version with support.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SupportRemoval
+{
+	/// The last version with support before it was removed.
+	pub last_supported_version: Version,
+	
+	/// The first version without support.
+	pub removed_in_version: Version,
+	
+	/// The first later version in which support was restored, if any.
+	pub restored_in_version: Option<Version>,
+}
@@ -19,6 +19,23 @@ impl<I: Into<f64>> From<I> for UsagePercentage
 	}
 }
 
+impl Serialize for UsagePercentage
+{
+	/// Serialize using Serde; whole percentages are serialized as integers, as JavaScript (and so the caniuse.com database) does, eg `0` rather than `0.0`.
+	#[inline(always)]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		if self.0.fract() == 0.0
+		{
+			serializer.serialize_u64(self.0 as u64)
+		}
+		else
+		{
+			serializer.serialize_f64(self.0)
+		}
+	}
+}
+
 impl PartialEq for UsagePercentage
 {
 	/// Partial Equality; total equality is also supported
@@ -3,10 +3,12 @@
 
 
 /// Version "3" and "3.0" are not considered equal; "3.0" is greater than "3".
-/// Opera and iOS Safari have hyphenation ranges of versions, eg "4.0-4.2". These are converted to a version matching the lower of the range, eg "4.0".
+/// Opera, iOS Safari and the Android Browser have inclusive ranges of versions, eg "4.0-4.2" and "4.4.3-4.4.4"; see `lower_bound()`, `upper_bound()` and `contains()`.
+/// These sort by the lower of the range, eg "4.0", and then by the upper, with a version that is not a range sorting before a range with the same lower bound; they retain the upper of the range so that they can be written back out unchanged.
+/// A range is only equal to the same range, so use `resolve()` or `contains()` to find, say, "4.4.3" in a map keyed by "4.4.3-4.4.4".
 /// Safari also has "TP" for its latest version, which is not stable across time and is converted to the VersionPart::TechnologyPreview, and Opera Mini just has "all"; it is effectively unversioned.
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
-pub struct Version(VersionPart, Vec<VersionPart>);
+pub struct Version(VersionPart, Vec<VersionPart>, Option<Box<Version>>);
 
 impl<'de> Deserialize<'de> for Version
 {
@@ -37,6 +39,35 @@ impl<'de> Deserialize<'de> for Version
 	}
 }
 
+impl Serialize for Version
+{
+	/// Serialize using Serde
+	#[inline(always)]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		serializer.serialize_str(&self.to_string())
+	}
+}
+
+impl Display for Version
+{
+	/// Displays as the caniuse.com database would, eg "4.0-4.2", "TP" or "all".
+	#[inline(always)]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result
+	{
+		write!(fmt, "{}", self.0)?;
+		for version_part in self.1.iter()
+		{
+			write!(fmt, ".{}", version_part)?;
+		}
+		if let Some(ref upper) = self.2
+		{
+			write!(fmt, "-{}", upper)?;
+		}
+		Ok(())
+	}
+}
+
 impl<'a, I: Into<&'a str>> From<I> for Version
 {
 	/// Converts into a Version anything that can be converted into '&str'.
@@ -67,35 +98,35 @@ impl Version
 	#[inline(always)]
 	pub fn opera_mini_all() -> Self
 	{
-		Version(VersionPart::All, vec![])
+		Version(VersionPart::All, vec![], None)
 	}
 	
 	/// Special method to construct a version representing the Safari TP version
 	#[inline(always)]
 	pub fn safari_technology_preview() -> Self
 	{
-		Version(VersionPart::TechnologyPreview, vec![])
+		Version(VersionPart::TechnologyPreview, vec![], None)
 	}
 	
 	/// Special method to construct a Version that only represents a major version, eg 10
 	#[inline(always)]
 	pub fn major(major_version: u64) -> Self
 	{
-		Version(VersionPart::Number(major_version), vec![])
+		Version(VersionPart::Number(major_version), vec![], None)
 	}
 	
 	/// Special method to construct a Version that represents a major-minor version, eg 12.1
 	#[inline(always)]
 	pub fn major_minor(major_version: u64, minor_version: u64) -> Self
 	{
-		Version(VersionPart::Number(major_version), vec![VersionPart::Number(minor_version)])
+		Version(VersionPart::Number(major_version), vec![VersionPart::Number(minor_version)], None)
 	}
 	
 	/// Special method to construct a Version that represents a major-minor-revision version, eg 4.5.6
 	#[inline(always)]
 	pub fn major_minor_revision(major_version: u64, minor_version: u64, revision_version: u64) -> Self
 	{
-		Version(VersionPart::Number(major_version), vec![VersionPart::Number(minor_version), VersionPart::Number(revision_version)])
+		Version(VersionPart::Number(major_version), vec![VersionPart::Number(minor_version), VersionPart::Number(revision_version)], None)
 	}
 	
 	/// Is this version the Safari Technology Preview?
@@ -109,6 +140,17 @@ impl Version
 		}
 	}
 	
+	/// The major version number, eg 4 for "4.4.3-4.4.4"; None for Safari's TP, Opera Mini's all and unknown versions.
+	#[inline(always)]
+	pub fn major_number(&self) -> Option<u64>
+	{
+		match self.0
+		{
+			VersionPart::Number(major_number) => Some(major_number),
+			_ => None,
+		}
+	}
+	
 	/// Is this version "0" (sometimes found in caniuse.com's Regional data) or Unknown
 	#[inline(always)]
 	pub fn is_invalid_or_unknown(&self) -> bool
@@ -121,6 +163,86 @@ impl Version
 		}
 	}
 	
+	/// Is this version a range, eg "4.4.3-4.4.4"?
+	#[inline(always)]
+	pub fn is_range(&self) -> bool
+	{
+		self.2.is_some()
+	}
+	
+	/// The lower of a range, eg "4.0" for "4.0-4.2"; otherwise a clone of this version.
+	#[inline(always)]
+	pub fn lower_bound(&self) -> Self
+	{
+		Version(self.0.clone(), self.1.clone(), None)
+	}
+	
+	/// The upper of a range, eg "4.2" for "4.0-4.2"; otherwise a clone of this version.
+	#[inline(always)]
+	pub fn upper_bound(&self) -> Self
+	{
+		match self.2
+		{
+			None => self.clone(),
+			Some(ref upper) => upper.lower_bound(),
+		}
+	}
+	
+	/// Does this version, perhaps a range, include `version`?
+	/// Ranges are inclusive, and a version includes its more precise versions, so "15.2-15.3" contains "15.2", "15.2.1" and "15.3.1", and "62" contains "62.0.3202.94".
+	/// If `version` is itself a range, both its lower and upper bounds must be contained.
+	#[inline(always)]
+	pub fn contains(&self, version: &Version) -> bool
+	{
+		let lower_bound = self.lower_bound();
+		let upper_bound = self.upper_bound();
+		
+		let contains = |point: Version| lower_bound <= point && (point <= upper_bound || upper_bound.is_prefix_of(&point));
+		contains(version.lower_bound()) && contains(version.upper_bound())
+	}
+	
+	/// Resolves this version, say one reported by a browser such as "118.0.5993" or "16.4.1", onto the version used as a key in `known_versions`, such as `Agent::version_details()`; this is the basis of `Agent::nearest_known_version()`, `Feature::implementation()` and `RegionalUsage::usage_of_version()`.
+	///
+	/// In order of preference, this is:-
+	///
+	/// * the exact version;
+	/// * the known version (perhaps a range) containing this version, eg "118" for "118.0.5993" or "16.4" for "16.4.1" (see `contains()`);
+	/// * the greatest known version not after this version (comparing ranges by their lower bound), so that a version newer than any known is treated as the latest known (or future) version.
+	///
+	/// Safari's TP, Opera Mini's all and unknown versions only ever match exactly, and known versions that are invalid or unknown (see `is_invalid_or_unknown()`) only ever match exactly or by containment.
+	/// Returns None if there is no match, including for a version older than every known version, eg Chrome "1".
+	#[inline(always)]
+	pub fn resolve<'a, V>(&self, known_versions: &'a BTreeMap<Version, V>) -> Option<(&'a Version, &'a V)>
+	{
+		if let Some(entry) = self.find_in(known_versions)
+		{
+			return Some(entry);
+		}
+		
+		self.major_number()?;
+		let lower_bound = self.lower_bound();
+		
+		known_versions.iter().rev().filter(|&(known_version, _)| known_version.major_number().is_some() && !known_version.is_invalid_or_unknown()).find(|&(known_version, _)| known_version.lower_bound() <= lower_bound)
+	}
+	
+	/// Finds the entry for `self` in, say, a feature's `stats` for an agent: the entry for this exact version, otherwise that of the known version (perhaps a range) containing it, eg "15.2-15.3" for "15.2".
+	#[inline(always)]
+	fn find_in<'a, V>(&self, known_versions: &'a BTreeMap<Version, V>) -> Option<(&'a Version, &'a V)>
+	{
+		match known_versions.range((Bound::Included(self), Bound::Included(self))).next()
+		{
+			Some(entry) => Some(entry),
+			None => known_versions.iter().rev().find(|&(known_version, _)| known_version.contains(self)),
+		}
+	}
+	
+	/// Are the parts of this version, ignoring any range, the leading parts of `other`, eg "62" of "62.0.3202.94"?
+	#[inline(always)]
+	fn is_prefix_of(&self, other: &Version) -> bool
+	{
+		self.0 == other.0 && self.1.len() <= other.1.len() && self.1[..] == other.1[..self.1.len()]
+	}
+	
 	#[inline(always)]
 	fn parse(v: &str) -> Self
 	{
@@ -129,7 +251,9 @@ impl Version
 		// Handle version ranges used in Opera and iOS Safari
 		if let Some(index) = v.find('-')
 		{
-			return Self::parse(&v[..index]);
+			let Version(first, subsequent, _) = Self::parse(&v[..index]);
+			let upper = Self::parse(&v[index + 1..]);
+			return Version(first, subsequent, Some(Box::new(upper)));
 		}
 		
 		// Specialized logic to handle legacy Opera Presto ranges, Safari Technology Preview, Opera Mini and iOS Safari
@@ -176,6 +300,6 @@ impl Version
 		}
 		
 		subsequent.shrink_to_fit();
-		Version(first.unwrap(), subsequent)
+		Version(first.unwrap(), subsequent, None)
 	}
 }
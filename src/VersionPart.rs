@@ -19,3 +19,21 @@ enum VersionPart
 	/// Represents an unknown version part, perhaps a hyphenated release candidate, beta, etc; sorts after a number, which may be inappropriate
 	Unknown(String),
 }
+
+impl Display for VersionPart
+{
+	/// Displays as the caniuse.com database would.
+	#[inline(always)]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result
+	{
+		use self::VersionPart::*;
+		
+		match *self
+		{
+			Number(value) => write!(fmt, "{}", value),
+			TechnologyPreview => write!(fmt, "TP"),
+			All => write!(fmt, "all"),
+			Unknown(ref value) => write!(fmt, "{}", value),
+		}
+	}
+}
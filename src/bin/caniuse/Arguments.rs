@@ -0,0 +1,75 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Parsed command line arguments; options may appear anywhere, up to a `--`.
+#[derive(Debug, Default, Clone)]
+struct Arguments
+{
+	database: Option<PathBuf>,
+	json: bool,
+	targets: Option<String>,
+	policy: Option<String>,
+	command: Option<String>,
+	operands: Vec<String>,
+}
+
+impl Arguments
+{
+	fn parse<I: Iterator<Item=String>>(mut arguments: I) -> Result<Self, String>
+	{
+		let mut parsed = Arguments::default();
+		let mut options_ended = false;
+		
+		while let Some(argument) = arguments.next()
+		{
+			if options_ended || !argument.starts_with('-') || argument == "-"
+			{
+				if parsed.command.is_none()
+				{
+					parsed.command = Some(argument);
+				}
+				else
+				{
+					parsed.operands.push(argument);
+				}
+				continue;
+			}
+			
+			match &argument[..]
+			{
+				"--" => options_ended = true,
+				
+				"--json" => parsed.json = true,
+				
+				"--database" => match arguments.next()
+				{
+					None => return Err("'--database' requires a PATH".to_owned()),
+					Some(path) => parsed.database = Some(PathBuf::from(path)),
+				},
+				
+				"--targets" => match arguments.next()
+				{
+					None => return Err("'--targets' requires a QUERY".to_owned()),
+					Some(query) => parsed.targets = Some(query),
+				},
+				
+				"--policy" => match arguments.next()
+				{
+					None => return Err("'--policy' requires a POLICY".to_owned()),
+					Some(policy) => parsed.policy = Some(policy),
+				},
+				
+				"--help" | "-h" => parsed.command = Some("help".to_owned()),
+				
+				_ => match argument.strip_prefix("--database=")
+				{
+					Some(database) => parsed.database = Some(PathBuf::from(database)),
+					None => return Err(format!("unknown option '{}'; try 'caniuse help'", argument)),
+				},
+			}
+		}
+		
+		Ok(parsed)
+	}
+}
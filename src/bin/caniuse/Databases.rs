@@ -0,0 +1,75 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// The caniuse.com database and regional usage to query; embedded unless `--database` is given.
+#[derive(Debug)]
+struct Databases
+{
+	can_i_use: CanIUse,
+	regional_usage_registry: Option<RegionalUsageRegistry>,
+}
+
+impl Databases
+{
+	/// A folder is treated as a checkout of the caniuse.com git repository, providing regional usage too; a file as `data-2.0.json`, with embedded regional usage.
+	fn load(arguments: &Arguments) -> Result<Self, String>
+	{
+		match arguments.database
+		{
+			None => Self::embedded(),
+			
+			Some(ref path) => if path.is_dir()
+			{
+				Ok(Databases
+				{
+					can_i_use: CanIUse::from_caniuse_repository(path).map_err(|error| format!("could not load database from '{}': {}", path.display(), error))?,
+					regional_usage_registry: Some(RegionalUsageRegistry::from_caniuse_repository(path).map_err(|error| format!("could not load regional usage from '{}': {}", path.display(), error))?),
+				})
+			}
+			else
+			{
+				Ok(Databases
+				{
+					can_i_use: CanIUse::from_path(path).map_err(|error| format!("could not load database from '{}': {}", path.display(), error))?,
+					regional_usage_registry: None,
+				})
+			},
+		}
+	}
+	
+	#[cfg(feature = "embedded-database")]
+	fn embedded() -> Result<Self, String>
+	{
+		Ok(Databases
+		{
+			can_i_use: CanIUse::default(),
+			regional_usage_registry: None,
+		})
+	}
+	
+	#[cfg(not(feature = "embedded-database"))]
+	fn embedded() -> Result<Self, String>
+	{
+		Err("no database is embedded (the cargo feature 'embedded-database' is disabled); use '--database PATH'".to_owned())
+	}
+	
+	fn regional_usage(&self, regional_usages: RegionalUsages) -> Result<&RegionalUsage, String>
+	{
+		match self.regional_usage_registry
+		{
+			None => regional_usages.regional_usage().map_err(|error| error.to_string()),
+			Some(ref regional_usage_registry) => regional_usage_registry.regional_usage(regional_usages).ok_or_else(|| format!("regional usage for '{}' is not in the database", regional_usages.can_i_use_iso_like_code())),
+		}
+	}
+}
+
+impl RegionalUsageSource for Databases
+{
+	/// Regional usage from the database given by `--database`, if a folder, or that embedded.
+	#[inline(always)]
+	fn regional_usage(&self, regional_usages: RegionalUsages) -> Option<&RegionalUsage>
+	{
+		Databases::regional_usage(self, regional_usages).ok()
+	}
+}
@@ -0,0 +1,189 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// A HTTP/1.1 request to `caniuse serve`; only `GET` is supported, and connections are not kept alive.
+#[derive(Debug)]
+struct HttpRequest
+{
+	method: String,
+	url: Url,
+	user_agent: String,
+}
+
+impl HttpRequest
+{
+	const MaximumHeaderLength: u64 = 64 * 1024;
+	
+	fn respond(databases: &Databases, mut stream: TcpStream) -> io::Result<()>
+	{
+		stream.set_read_timeout(Some(::std::time::Duration::from_secs(10)))?;
+		
+		let (status, body) = match Self::read(&stream)?
+		{
+			None => (400, json!({ "error": "malformed request" })),
+			Some(request) => request.route(databases),
+		};
+		
+		let reason = match status
+		{
+			200 => "OK",
+			400 => "Bad Request",
+			404 => "Not Found",
+			_ => "Method Not Allowed",
+		};
+		let body = ::serde_json::to_string_pretty(&body).unwrap();
+		write!(stream, "HTTP/1.1 {} {}\r\nContent-Type: application/json; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, reason, body.len(), body)?;
+		stream.flush()
+	}
+	
+	fn read(stream: &TcpStream) -> io::Result<Option<Self>>
+	{
+		let mut reader = BufReader::new(stream.take(Self::MaximumHeaderLength));
+		
+		let mut request_line = String::new();
+		reader.read_line(&mut request_line)?;
+		let mut parts = request_line.split_whitespace();
+		let (method, target) = match (parts.next(), parts.next(), parts.next())
+		{
+			(Some(method), Some(target), Some(_http_version)) if target.starts_with('/') => (method.to_owned(), target),
+			_ => return Ok(None),
+		};
+		
+		let url = match Url::parse(&format!("http://localhost{}", target))
+		{
+			Ok(url) => url,
+			Err(_) => return Ok(None),
+		};
+		
+		let mut user_agent = String::new();
+		loop
+		{
+			let mut header = String::new();
+			if reader.read_line(&mut header)? == 0
+			{
+				return Ok(None);
+			}
+			let header = header.trim_end();
+			if header.is_empty()
+			{
+				break;
+			}
+			if let Some(colon) = header.find(':')
+			{
+				if header[..colon].eq_ignore_ascii_case("user-agent")
+				{
+					user_agent = header[colon + 1..].trim().to_owned();
+				}
+			}
+		}
+		
+		Ok(Some(HttpRequest
+		{
+			method,
+			url,
+			user_agent,
+		}))
+	}
+	
+	/// Routes to the same queries as the commands of `caniuse`, with the same JSON output.
+	fn route(&self, databases: &Databases) -> (u16, Value)
+	{
+		if self.method != "GET"
+		{
+			return (405, json!({ "error": "only GET is supported" }));
+		}
+		
+		let segments: Vec<String> = match self.url.path_segments()
+		{
+			None => vec![],
+			Some(segments) => segments.filter(|segment| !segment.is_empty()).map(|segment| percent_decode(segment.as_bytes()).decode_utf8_lossy().into_owned()).collect(),
+		};
+		let segment = |index: usize| segments.get(index).map(|segment| &segment[..]);
+		
+		match (segment(0), segment(1), segment(2), segment(3))
+		{
+			(None, _, _, _) => (200, json!
+			({
+				"endpoints":
+				[
+					"/features?query=QUERY",
+					"/features/FEATURE",
+					"/features/FEATURE/support",
+					"/features/FEATURE/supports?user_agent=USER_AGENT&policy=strict",
+					"/agents",
+					"/regions",
+					"/regions/REGION",
+					"/targets?query=BROWSERSLIST_QUERY",
+				]
+			})),
+			
+			(Some("features"), None, _, _) => match self.parameter("query")
+			{
+				None => Self::error(400, "'query' is required".to_owned()),
+				Some(query) => Self::output(400, search(databases, &[query])),
+			},
+			
+			(Some("features"), Some(feature_name), None, _) => Self::output(404, show(databases, &[feature_name.to_owned()])),
+			
+			(Some("features"), Some(feature_name), Some("support"), None) => match show(databases, &[feature_name.to_owned()])
+			{
+				Err(message) => Self::error(404, message),
+				Ok(output) => (200, json!
+				({
+					"feature": output.json["feature"],
+					"agents": output.json["agents"],
+					"notes": output.json["notes"],
+				})),
+			},
+			
+			(Some("features"), Some(feature_name), Some("supports"), None) =>
+			{
+				if FeatureName::from(feature_name).feature(&databases.can_i_use).is_none()
+				{
+					return Self::error(404, format!("unknown feature '{}'", feature_name));
+				}
+				let user_agent = self.parameter("user_agent").unwrap_or_else(|| self.user_agent.clone());
+				let policy = self.parameter("policy").unwrap_or_else(|| "strict".to_owned());
+				match supports(databases, feature_name, &user_agent, &policy)
+				{
+					Err(message) => Self::error(400, message),
+					Ok(json) => (200, json),
+				}
+			}
+			
+			(Some("agents"), None, _, _) => Self::output(400, agents(databases, &[])),
+			
+			(Some("regions"), None, _, _) => Self::output(400, regions(databases, &[])),
+			
+			(Some("regions"), Some(region), None, _) => Self::output(404, usage(databases, &[region.to_owned()])),
+			
+			(Some("targets"), None, _, _) =>
+			{
+				let operands: Vec<String> = self.parameter("query").into_iter().collect();
+				Self::output(400, targets(databases, &operands))
+			}
+			
+			_ => Self::error(404, format!("no such endpoint '{}'", self.url.path())),
+		}
+	}
+	
+	fn parameter(&self, name: &str) -> Option<String>
+	{
+		self.url.query_pairs().find(|(key, _)| key == name).map(|(_, value)| value.into_owned())
+	}
+	
+	fn output(error_status: u16, output: Result<Output, String>) -> (u16, Value)
+	{
+		match output
+		{
+			Ok(output) => (200, output.json),
+			Err(message) => Self::error(error_status, message),
+		}
+	}
+	
+	fn error(status: u16, message: String) -> (u16, Value)
+	{
+		(status, json!({ "error": message }))
+	}
+}
@@ -0,0 +1,29 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// The output of a command, as both human-readable text and JSON.
+#[derive(Debug)]
+struct Output
+{
+	text: String,
+	json: Value,
+}
+
+impl Output
+{
+	/// Errors writing, eg because stdout was closed by `head`, are ignored.
+	fn write(&self, json: bool)
+	{
+		let stdout = stdout();
+		let mut stdout = stdout.lock();
+		let _ = if json
+		{
+			::serde_json::to_writer_pretty(&mut stdout, &self.json).map_err(|_| ()).and_then(|_| writeln!(stdout).map_err(|_| ()))
+		}
+		else
+		{
+			stdout.write_all(self.text.as_bytes()).map_err(|_| ())
+		};
+	}
+}
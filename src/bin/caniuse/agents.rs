@@ -0,0 +1,31 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+fn agents(databases: &Databases, operands: &[String]) -> Result<Output, String>
+{
+	no_operands("agents", operands)?;
+	
+	let can_i_use = &databases.can_i_use;
+	let mut text = String::new();
+	let mut json = Vec::new();
+	for agent_name in sorted_agent_names(can_i_use)
+	{
+		let agent = agent_name.agent(can_i_use).unwrap();
+		text.push_str(&format!("{:<10} {} ({:?}, current version {})\n", agent_name.can_i_use_identifier(), agent.browser_name(), agent.agent_type(), agent.current_version()));
+		json.push(json!
+		({
+			"agent": agent_name.can_i_use_identifier(),
+			"name": agent.browser_name(),
+			"abbreviated_name": agent.abbreviated_name(),
+			"type": format!("{:?}", agent.agent_type()),
+			"current_version": agent.current_version().to_string(),
+		}));
+	}
+	
+	Ok(Output
+	{
+		text,
+		json: Value::Array(json),
+	})
+}
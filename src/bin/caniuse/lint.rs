@@ -0,0 +1,66 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Returns the output and whether there were any issues.
+fn lint(databases: &Databases, arguments: &Arguments) -> Result<(Output, bool), String>
+{
+	if arguments.operands.is_empty()
+	{
+		return Err("'lint' requires at least one FILE".to_owned());
+	}
+	
+	let policy = match arguments.policy
+	{
+		None => SupportPolicy::default(),
+		Some(ref policy) => parse_policy(policy)?,
+	};
+	let targets = target_choices(databases, arguments.targets.as_ref().map(|query| &query[..]))?;
+	let linter = CssCompatibilityLinter::new(&databases.can_i_use, &targets, policy);
+	
+	let mut text = String::new();
+	let mut json = Vec::new();
+	for file in arguments.operands.iter()
+	{
+		let issues = linter.lint_file(file).map_err(|error| format!("could not read '{}': {}", file, error))?;
+		for issue in issues.iter()
+		{
+			text.push_str(&format!("{}:{}\n", file, issue));
+			
+			let unsupported: Vec<Value> = issue.unsupported.iter().map(|(agent_name, version)| json!({ "agent": agent_name.can_i_use_identifier(), "version": version.to_string() })).collect();
+			json.push(json!
+			({
+				"file": file,
+				"line": issue.location.line,
+				"column": issue.location.column,
+				"syntax": syntax(&issue.syntax),
+				"feature": issue.feature_name,
+				"unsupported": unsupported,
+			}));
+		}
+	}
+	
+	let has_issues = !json.is_empty();
+	Ok
+	((
+		Output
+		{
+			text,
+			json: Value::Array(json),
+		},
+		has_issues,
+	))
+}
+
+fn syntax(syntax: &CssSyntax) -> Value
+{
+	use ::caniuse_serde::css_compatibility::CssSyntax::*;
+	
+	match *syntax
+	{
+		Property(ref property) => json!({ "kind": "property", "property": property }),
+		Value { ref property, ref value } => json!({ "kind": "value", "property": property, "value": value }),
+		AtRule(ref at_rule) => json!({ "kind": "at-rule", "at_rule": at_rule }),
+		Selector(ref selector) => json!({ "kind": "selector", "selector": selector }),
+	}
+}
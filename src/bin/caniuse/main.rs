@@ -0,0 +1,175 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+//! # caniuse
+//!
+//! A command line tool for querying the caniuse.com database embedded in the caniuse-serde crate, or another copy of it, offline.
+//! Run `caniuse help` for usage.
+
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![deny(missing_debug_implementations)]
+#![deny(unstable_features)]
+#![deny(unused_extern_crates)]
+#![deny(unused_import_braces)]
+#![deny(unused_qualifications)]
+
+
+extern crate caniuse_serde;
+#[macro_use] extern crate serde_json;
+#[cfg(feature = "server")] extern crate url;
+
+
+use ::caniuse_serde::*;
+use ::caniuse_serde::css_compatibility::*;
+use ::caniuse_serde::regional_usage::*;
+use ::serde_json::Map;
+use ::serde_json::Value;
+use ::std::cmp::Reverse;
+use ::std::collections::Bound::Unbounded;
+use ::std::env::args;
+#[cfg(feature = "server")] use ::std::io;
+#[cfg(feature = "server")] use ::std::io::BufRead;
+#[cfg(feature = "server")] use ::std::io::BufReader;
+#[cfg(feature = "server")] use ::std::io::Read;
+use ::std::io::stdout;
+use ::std::io::Write;
+#[cfg(feature = "server")] use ::std::net::TcpListener;
+#[cfg(feature = "server")] use ::std::net::TcpStream;
+#[cfg(feature = "server")] use ::std::os::raw::c_int;
+use ::std::path::PathBuf;
+use ::std::process::exit;
+#[cfg(feature = "server")] use ::std::sync::Arc;
+#[cfg(feature = "server")] use ::std::sync::RwLock;
+#[cfg(feature = "server")] use ::std::sync::atomic::AtomicBool;
+#[cfg(feature = "server")] use ::std::sync::atomic::Ordering::SeqCst;
+#[cfg(feature = "server")] use ::std::thread::sleep;
+#[cfg(feature = "server")] use ::std::thread::spawn;
+#[cfg(feature = "server")] use ::url::Url;
+#[cfg(feature = "server")] use ::url::percent_encoding::percent_decode;
+
+
+include!("agents.rs");
+include!("Arguments.rs");
+include!("Databases.rs");
+include!("lint.rs");
+#[cfg(feature = "server")] include!("HttpRequest.rs");
+include!("Output.rs");
+include!("regions.rs");
+include!("search.rs");
+#[cfg(feature = "server")] include!("serve.rs");
+include!("show.rs");
+include!("targets.rs");
+include!("usage.rs");
+
+
+#[cfg(all(test, feature = "embedded-database"))] mod systemTests;
+
+
+const Help: &str = "Usage: caniuse [--database PATH] [--json] COMMAND [ARGUMENTS]
+
+Queries the caniuse.com database embedded in this tool, offline.
+
+Commands:
+  search QUERY        Search for features by name, title, keyword or browser-specific identifier; tolerant of typos
+  show FEATURE        Show a feature's support by agent and version, with prefixes and notes
+  agents              List agents (browsers)
+  regions             List the codes of regions with usage data, eg 'alt-ww' (world-wide), 'alt-eu' (Europe) or 'AU'
+  usage REGION        Show the usage of agents and versions in a region
+  targets [QUERY]     List the agents and versions selected by a browserslist query, or a sensible choice for an international website if omitted
+  lint FILE...        Report CSS properties, values, at-rules and selectors in stylesheets that the agents and versions of '--targets' do not support; exits with status 2 if there are any
+  serve [ADDRESS]     Serve the above as a read-only JSON API on ADDRESS (default 127.0.0.1:8080), reloading '--database' on SIGHUP; requires the cargo feature 'server'
+  help                Show this help
+
+Options:
+  --database PATH     Use a 'data-2.0.json' file, or a checkout of the caniuse.com git repository (which also provides regional usage), rather than the embedded database
+  --json              Output JSON rather than human-readable text
+  --targets QUERY     For 'lint', a browserslist query of the agents and versions to support, rather than the sensible choice of 'targets'
+  --policy POLICY     For 'lint', 'strict' (the default) or 'lenient' (partial support counts as support)
+";
+
+fn main()
+{
+	if let Err(message) = run()
+	{
+		eprintln!("caniuse: {}", message);
+		exit(1);
+	}
+}
+
+fn run() -> Result<(), String>
+{
+	let arguments = Arguments::parse(args().skip(1))?;
+	
+	let command = match arguments.command
+	{
+		None => "help",
+		Some(ref command) => &command[..],
+	};
+	
+	let output = match command
+	{
+		"help" =>
+		{
+			print!("{}", Help);
+			return Ok(());
+		}
+		
+		"search" => search(&Databases::load(&arguments)?, &arguments.operands)?,
+		"show" => show(&Databases::load(&arguments)?, &arguments.operands)?,
+		"agents" => agents(&Databases::load(&arguments)?, &arguments.operands)?,
+		"regions" => regions(&Databases::load(&arguments)?, &arguments.operands)?,
+		"usage" => usage(&Databases::load(&arguments)?, &arguments.operands)?,
+		"targets" => targets(&Databases::load(&arguments)?, &arguments.operands)?,
+		"lint" =>
+		{
+			let (output, has_issues) = lint(&Databases::load(&arguments)?, &arguments)?;
+			output.write(arguments.json);
+			if has_issues
+			{
+				exit(2);
+			}
+			return Ok(());
+		}
+		
+		#[cfg(feature = "server")] "serve" => return serve(&arguments),
+		#[cfg(not(feature = "server"))] "serve" => return Err("'serve' requires the cargo feature 'server'".to_owned()),
+		
+		_ => return Err(format!("unknown command '{}'; try 'caniuse help'", command)),
+	};
+	
+	output.write(arguments.json);
+	Ok(())
+}
+
+/// Agents in order of their caniuse.com identifier, eg `and_chr` before `chrome`.
+fn sorted_agent_names(can_i_use: &CanIUse) -> Vec<&AgentName>
+{
+	let mut agent_names: Vec<&AgentName> = can_i_use.known_agent_names().collect();
+	agent_names.sort_by_key(|agent_name| agent_name.can_i_use_identifier());
+	agent_names
+}
+
+fn parse_policy(policy: &str) -> Result<SupportPolicy, String>
+{
+	match policy
+	{
+		"strict" => Ok(SupportPolicy::Strict),
+		"lenient" => Ok(SupportPolicy::Lenient),
+		_ => Err(format!("unknown policy '{}'; use 'strict' or 'lenient'", policy)),
+	}
+}
+
+fn no_operands(command: &str, operands: &[String]) -> Result<(), String>
+{
+	if operands.is_empty()
+	{
+		Ok(())
+	}
+	else
+	{
+		Err(format!("'{}' takes no arguments", command))
+	}
+}
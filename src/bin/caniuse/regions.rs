@@ -0,0 +1,35 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+fn regions(databases: &Databases, operands: &[String]) -> Result<Output, String>
+{
+	no_operands("regions", operands)?;
+	
+	let mut text = String::new();
+	let mut json = Vec::new();
+	for regional_usages in RegionalUsages::All.iter()
+	{
+		let code = regional_usages.can_i_use_iso_like_code();
+		match databases.regional_usage(*regional_usages)
+		{
+			Ok(regional_usage) =>
+			{
+				text.push_str(&format!("{:<8} {}\n", code, regional_usage.country_or_region_name()));
+				json.push(json!({ "region": code, "name": regional_usage.country_or_region_name() }));
+			}
+			
+			Err(_) =>
+			{
+				text.push_str(&format!("{:<8} (no usage data)\n", code));
+				json.push(json!({ "region": code, "name": null }));
+			}
+		}
+	}
+	
+	Ok(Output
+	{
+		text,
+		json: Value::Array(json),
+	})
+}
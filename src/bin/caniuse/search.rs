@@ -0,0 +1,27 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+fn search(databases: &Databases, operands: &[String]) -> Result<Output, String>
+{
+	if operands.is_empty()
+	{
+		return Err("'search' requires a QUERY".to_owned());
+	}
+	
+	let can_i_use = &databases.can_i_use;
+	let mut text = String::new();
+	let mut json = Vec::new();
+	for (feature_name, score) in can_i_use.search(&operands.join(" "))
+	{
+		let title = feature_name.feature(can_i_use).unwrap().title();
+		text.push_str(&format!("{:<40} {}\n", &feature_name[..], title));
+		json.push(json!({ "feature": &feature_name[..], "title": title, "score": score }));
+	}
+	
+	Ok(Output
+	{
+		text,
+		json: Value::Array(json),
+	})
+}
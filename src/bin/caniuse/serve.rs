@@ -0,0 +1,116 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Set by the `SIGHUP` handler; polled by the thread started by `reload_when_requested()`.
+static ReloadRequested: AtomicBool = AtomicBool::new(false);
+
+/// Serves a read-only JSON API until killed; each connection is handled on its own thread.
+fn serve(arguments: &Arguments) -> Result<(), String>
+{
+	let address = match arguments.operands.len()
+	{
+		0 => "127.0.0.1:8080",
+		1 => &arguments.operands[0][..],
+		_ => return Err("'serve' takes at most one ADDRESS".to_owned()),
+	};
+	
+	let databases = Arc::new(RwLock::new(Arc::new(Databases::load(arguments)?)));
+	let listener = TcpListener::bind(address).map_err(|error| format!("could not listen on '{}': {}", address, error))?;
+	reload_on_sighup();
+	reload_when_requested(arguments.clone(), databases.clone());
+	eprintln!("caniuse: serving on http://{}/", address);
+	
+	for stream in listener.incoming().flatten()
+	{
+		let databases = databases.read().unwrap().clone();
+		spawn(move ||
+		{
+			if let Err(error) = HttpRequest::respond(&databases, stream)
+			{
+				eprintln!("caniuse: could not respond: {}", error);
+			}
+		});
+	}
+	
+	Ok(())
+}
+
+/// Reloads the databases on a thread of its own as soon as a `SIGHUP` is received, rather than when the next request arrives; connections already being handled keep the databases they started with.
+fn reload_when_requested(arguments: Arguments, databases: Arc<RwLock<Arc<Databases>>>)
+{
+	spawn(move || loop
+	{
+		sleep(::std::time::Duration::from_millis(250));
+		
+		if ReloadRequested.swap(false, SeqCst)
+		{
+			match Databases::load(&arguments)
+			{
+				Ok(reloaded) =>
+				{
+					*databases.write().unwrap() = Arc::new(reloaded);
+					eprintln!("caniuse: reloaded");
+				}
+				
+				Err(message) => eprintln!("caniuse: could not reload, so still serving the previous data: {}", message),
+			}
+		}
+	});
+}
+
+#[cfg(unix)]
+fn reload_on_sighup()
+{
+	const SIGHUP: c_int = 1;
+	
+	extern "C"
+	{
+		fn signal(signum: c_int, handler: extern "C" fn(c_int)) -> usize;
+	}
+	
+	extern "C" fn sighup(_signum: c_int)
+	{
+		ReloadRequested.store(true, SeqCst);
+	}
+	
+	unsafe
+	{
+		signal(SIGHUP, sighup);
+	}
+}
+
+#[cfg(not(unix))]
+fn reload_on_sighup()
+{
+}
+
+/// `GET /features/FEATURE/supports?user_agent=USER_AGENT&policy=strict|lenient`; the user agent defaults to that of the request.
+fn supports(databases: &Databases, feature_name: &str, user_agent: &str, policy: &str) -> Result<Value, String>
+{
+	use ::caniuse_serde::user_agent::UserAgentSupport::*;
+	
+	let policy = parse_policy(policy)?;
+	
+	let can_i_use = &databases.can_i_use;
+	let feature_name = FeatureName::from(feature_name);
+	
+	let support = can_i_use.supports(user_agent, &feature_name, &policy);
+	let agent_name_and_version = match support
+	{
+		Supported { ref agent_name, ref version, .. } | SupportedWithPrefix { ref agent_name, ref version, .. } => Some((agent_name, version)),
+		Unsupported { ref resolution, .. } => resolution.agent_name_and_version(),
+	};
+	let notes: Vec<Value> = support.notes().iter().map(|&(note_number, note)| json!({ "number": note_number, "note": note })).collect();
+	
+	Ok(json!
+	({
+		"feature": &feature_name[..],
+		"user_agent": user_agent,
+		"agent": agent_name_and_version.map(|(agent_name, _)| agent_name.can_i_use_identifier()),
+		"version": agent_name_and_version.map(|(_, version)| version.to_string()),
+		"supported": support.is_supported(),
+		"prefix": support.prefix().map(|prefix| prefix.can_i_use_identifier()),
+		"notes": notes,
+	}))
+}
@@ -0,0 +1,160 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+fn show(databases: &Databases, operands: &[String]) -> Result<Output, String>
+{
+	if operands.len() != 1
+	{
+		return Err("'show' requires exactly one FEATURE".to_owned());
+	}
+	
+	let can_i_use = &databases.can_i_use;
+	let feature_name = FeatureName::from(&operands[0][..]);
+	let feature = match feature_name.feature(can_i_use)
+	{
+		Some(feature) => feature,
+		None => return Err(match can_i_use.search(&feature_name).first()
+		{
+			None => format!("unknown feature '{}'", &feature_name[..]),
+			Some((suggestion, _)) => format!("unknown feature '{}'; did you mean '{}'?", &feature_name[..], &suggestion[..]),
+		}),
+	};
+	
+	let status = feature.status();
+	let mut text = format!("{} [{}]\nStatus: {}\nSpecification: {}\nUsage: {} supported, {} partially supported\n", feature.title(), &feature_name[..], status.description(can_i_use).unwrap_or(status.can_i_use_identifier()), feature.specification_url(), feature.supported_by_default_usage(), feature.almost_supported_usage());
+	
+	let mut notes = Map::new();
+	let mut agents = Map::new();
+	for agent_name in sorted_agent_names(can_i_use)
+	{
+		let agent = agent_name.agent(can_i_use).unwrap();
+		let implementations = match feature.implementations_by_agents(agent_name, Unbounded, Unbounded)
+		{
+			None => continue,
+			Some(implementations) => implementations,
+		};
+		
+		text.push_str(&format!("\n{}\n", agent.browser_name()));
+		
+		let mut versions = Vec::new();
+		let mut runs: Vec<(&Version, &Version, String)> = Vec::new();
+		for (version, support) in implementations
+		{
+			let prefix = if support.requires_prefix()
+			{
+				Some(agent.prefix(version).can_i_use_identifier())
+			}
+			else
+			{
+				None
+			};
+			let note_numbers: Vec<u8> = support.notes().iter().map(|&(note_number, note)|
+			{
+				notes.insert(note_number.to_string(), Value::String(note.to_owned()));
+				note_number
+			}).collect();
+			
+			let description = describe(support.maturity(), prefix, support.disabled_by_default(), &note_numbers);
+			let extends_run = match runs.last()
+			{
+				Some((_, _, run_description)) => *run_description == description,
+				None => false,
+			};
+			if extends_run
+			{
+				runs.last_mut().unwrap().1 = version;
+			}
+			else
+			{
+				runs.push((version, version, description));
+			}
+			
+			versions.push(json!
+			({
+				"version": version.to_string(),
+				"support": support.maturity().can_i_use_identifier(),
+				"prefix": prefix,
+				"disabled_by_default": support.disabled_by_default(),
+				"notes": note_numbers,
+			}));
+		}
+		
+		for (first, last, description) in runs
+		{
+			if first == last
+			{
+				text.push_str(&format!("  {}: {}\n", first, description));
+			}
+			else
+			{
+				text.push_str(&format!("  {} to {}: {}\n", first, last, description));
+			}
+		}
+		
+		agents.insert(agent_name.can_i_use_identifier().to_owned(), Value::Array(versions));
+	}
+	
+	if !notes.is_empty()
+	{
+		text.push_str("\nNotes\n");
+		let mut note_numbers: Vec<u8> = notes.keys().filter_map(|note_number| note_number.parse().ok()).collect();
+		note_numbers.sort();
+		for note_number in note_numbers
+		{
+			text.push_str(&format!("  {}. {}\n", note_number, notes[&note_number.to_string()].as_str().unwrap()));
+		}
+	}
+	
+	let general_notes = feature.general_notes();
+	if !general_notes.is_empty()
+	{
+		text.push_str(&format!("\n{}\n", general_notes));
+	}
+	
+	Ok(Output
+	{
+		text,
+		json: json!
+		({
+			"feature": &feature_name[..],
+			"title": feature.title(),
+			"status": status.can_i_use_identifier(),
+			"specification_url": feature.specification_url().as_str(),
+			"supported_by_default_usage": feature.supported_by_default_usage().to_scalar(),
+			"almost_supported_usage": feature.almost_supported_usage().to_scalar(),
+			"agents": agents,
+			"notes": notes,
+			"general_notes": general_notes,
+		}),
+	})
+}
+
+fn describe(maturity: SupportMaturity, prefix: Option<&str>, disabled_by_default: bool, note_numbers: &[u8]) -> String
+{
+	use ::caniuse_serde::SupportMaturity::*;
+	
+	let mut description = match maturity
+	{
+		SupportedByDefault => "supported",
+		AlmostSupported => "partially supported",
+		NotSupportedOrDisabledByDefault => "not supported",
+		SupportedUsingAPolyfill => "supported using a polyfill",
+		SupportUnknown => "unknown",
+	}.to_owned();
+	
+	if let Some(prefix) = prefix
+	{
+		description.push_str(&format!(", with prefix -{}-", prefix));
+	}
+	if disabled_by_default
+	{
+		description.push_str(", disabled by default");
+	}
+	if !note_numbers.is_empty()
+	{
+		let note_numbers: Vec<String> = note_numbers.iter().map(|note_number| note_number.to_string()).collect();
+		description.push_str(&format!(" (see notes {})", note_numbers.join(", ")));
+	}
+	description
+}
@@ -0,0 +1,163 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn operands(operands: &[&str]) -> Vec<String>
+{
+	operands.iter().map(|operand| operand.to_string()).collect()
+}
+
+#[test]
+fn search_finds_features_despite_typos()
+{
+	let databases = Databases::embedded().unwrap();
+	
+	let output = search(&databases, &operands(&["flexbx"])).unwrap();
+	assert!(output.json.as_array().unwrap().iter().any(|feature| feature["feature"] == "flexbox"), "{}", output.json);
+	assert!(output.text.contains("flexbox"));
+	
+	assert!(search(&databases, &[]).is_err());
+}
+
+#[test]
+fn show_describes_support_by_agent_and_version()
+{
+	let databases = Databases::embedded().unwrap();
+	
+	let output = show(&databases, &operands(&["flexbox"])).unwrap();
+	assert_eq!(output.json["feature"], "flexbox");
+	assert!(!output.json["agents"]["chrome"].as_array().unwrap().is_empty());
+	assert!(output.text.contains("\nChrome\n"));
+	
+	assert!(show(&databases, &operands(&["no-such-feature"])).is_err());
+	assert!(show(&databases, &operands(&["flexbox", "css-grid"])).is_err());
+}
+
+#[test]
+fn agents_lists_agents_in_order_of_identifier()
+{
+	let databases = Databases::embedded().unwrap();
+	
+	let output = agents(&databases, &[]).unwrap();
+	let identifiers: Vec<&str> = output.json.as_array().unwrap().iter().map(|agent| agent["agent"].as_str().unwrap()).collect();
+	let mut sorted_identifiers = identifiers.clone();
+	sorted_identifiers.sort();
+	assert_eq!(identifiers, sorted_identifiers);
+	assert!(identifiers.contains(&"chrome"));
+	
+	assert!(agents(&databases, &operands(&["chrome"])).is_err());
+}
+
+#[test]
+fn regions_lists_every_region()
+{
+	let databases = Databases::embedded().unwrap();
+	
+	let output = regions(&databases, &[]).unwrap();
+	assert_eq!(output.json.as_array().unwrap().len(), RegionalUsages::All.len());
+	if cfg!(feature = "embedded-world-wide")
+	{
+		assert!(output.json.as_array().unwrap().iter().any(|region| region["region"] == "alt-ww" && region["name"].is_string()));
+	}
+	
+	assert!(regions(&databases, &operands(&["alt-ww"])).is_err());
+}
+
+#[cfg(feature = "embedded-world-wide")]
+#[test]
+fn usage_lists_usage_in_descending_order()
+{
+	let databases = Databases::embedded().unwrap();
+	
+	let output = usage(&databases, &operands(&["alt-ww"])).unwrap();
+	assert_eq!(output.json["region"], "alt-ww");
+	let usages: Vec<f64> = output.json["usage"].as_array().unwrap().iter().map(|usage| usage["usage"].as_f64().unwrap()).collect();
+	assert!(!usages.is_empty());
+	assert!(usages.windows(2).all(|pair| pair[0] >= pair[1]));
+	
+	assert!(usage(&databases, &operands(&["nowhere"])).is_err());
+	assert!(usage(&databases, &[]).is_err());
+}
+
+#[test]
+fn targets_evaluates_browserslist_queries()
+{
+	let databases = Databases::embedded().unwrap();
+	
+	let output = targets(&databases, &operands(&["last", "1", "chrome", "version"])).unwrap();
+	let current_version = AgentName::GoogleChrome.agent(&databases.can_i_use).unwrap().current_version().to_string();
+	assert_eq!(output.json, json!([{ "agent": "chrome", "version": current_version }]));
+	
+	assert!(targets(&databases, &operands(&["nonsense"])).is_err());
+}
+
+#[cfg(all(feature = "embedded-continents", feature = "embedded-country-au", feature = "embedded-country-nz"))]
+#[test]
+fn targets_defaults_to_sensible_choices()
+{
+	let databases = Databases::embedded().unwrap();
+	
+	let output = targets(&databases, &[]).unwrap();
+	let (_, choices) = sensible_choices_default();
+	assert_eq!(output.json.as_array().unwrap().len(), choices.len());
+}
+
+#[cfg(feature = "server")]
+fn get(path_and_query: &str) -> (u16, Value)
+{
+	let request = HttpRequest
+	{
+		method: "GET".to_owned(),
+		url: Url::parse(&format!("http://localhost{}", path_and_query)).unwrap(),
+		user_agent: "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/61.0.3163.100 Safari/537.36".to_owned(),
+	};
+	request.route(&Databases::embedded().unwrap())
+}
+
+#[cfg(feature = "server")]
+#[test]
+fn serve_routes_requests()
+{
+	let (status, json) = get("/");
+	assert_eq!(status, 200);
+	assert!(!json["endpoints"].as_array().unwrap().is_empty());
+	
+	let (status, json) = get("/features?query=flexbox");
+	assert_eq!(status, 200);
+	assert!(json.as_array().unwrap().iter().any(|feature| feature["feature"] == "flexbox"), "{}", json);
+	assert_eq!(get("/features").0, 400);
+	
+	let (status, json) = get("/features/flexbox");
+	assert_eq!(status, 200);
+	assert_eq!(json["feature"], "flexbox");
+	assert_eq!(get("/features/no-such-feature").0, 404);
+	
+	let (status, json) = get("/features/flexbox/support");
+	assert_eq!(status, 200);
+	assert_eq!(json["feature"], "flexbox");
+	assert!(json.get("title").is_none());
+	
+	let (status, json) = get("/features/flexbox/supports");
+	assert_eq!(status, 200);
+	assert_eq!(json["agent"], "chrome");
+	assert_eq!(json["version"], "61");
+	assert_eq!(get("/features/flexbox/supports?policy=sloppy").0, 400);
+	assert_eq!(get("/features/no-such-feature/supports").0, 404);
+	
+	assert_eq!(get("/agents").0, 200);
+	assert_eq!(get("/regions").0, 200);
+	assert_eq!(get("/targets?query=last%201%20chrome%20version").0, 200);
+	assert_eq!(get("/targets?query=nonsense").0, 400);
+	assert_eq!(get("/no/such/endpoint").0, 404);
+	
+	let request = HttpRequest
+	{
+		method: "POST".to_owned(),
+		url: Url::parse("http://localhost/agents").unwrap(),
+		user_agent: String::new(),
+	};
+	assert_eq!(request.route(&Databases::embedded().unwrap()).0, 405);
+}
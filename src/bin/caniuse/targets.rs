@@ -0,0 +1,49 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+fn targets(databases: &Databases, operands: &[String]) -> Result<Output, String>
+{
+	let choices = if operands.is_empty()
+	{
+		target_choices(databases, None)?
+	}
+	else
+	{
+		target_choices(databases, Some(&operands.join(" ")))?
+	};
+	
+	let mut choices: Vec<&(AgentName, Version)> = choices.iter().collect();
+	choices.sort_by(|(left_agent_name, left_version), (right_agent_name, right_version)| left_agent_name.can_i_use_identifier().cmp(right_agent_name.can_i_use_identifier()).then_with(|| right_version.cmp(left_version)));
+	
+	let mut text = String::new();
+	let mut json = Vec::new();
+	for (agent_name, version) in choices.iter()
+	{
+		text.push_str(&format!("{} {}\n", agent_name.can_i_use_identifier(), version));
+		json.push(json!({ "agent": agent_name.can_i_use_identifier(), "version": version.to_string() }));
+	}
+	
+	Ok(Output
+	{
+		text,
+		json: Value::Array(json),
+	})
+}
+
+/// The agents and versions selected by a browserslist `query`, or a sensible choice for an international website if None.
+fn target_choices(databases: &Databases, query: Option<&str>) -> Result<AgentNameAndVersionSet, String>
+{
+	let can_i_use = &databases.can_i_use;
+	
+	match query
+	{
+		None =>
+		{
+			let regional_usages = SensibleChoicesDefaultRegionalUsages.iter().map(|regional_usages| databases.regional_usage(*regional_usages)).collect::<Result<Vec<_>, _>>()?;
+			Ok(sensible_choices_default_for(can_i_use, &regional_usages))
+		}
+		
+		Some(query) => AgentNameAndVersionSet::browserslist(can_i_use, databases, query).map_err(|error| error.to_string()),
+	}
+}
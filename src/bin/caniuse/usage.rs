@@ -0,0 +1,53 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+fn usage(databases: &Databases, operands: &[String]) -> Result<Output, String>
+{
+	if operands.len() != 1
+	{
+		return Err("'usage' requires exactly one REGION; try 'caniuse regions'".to_owned());
+	}
+	
+	let regional_usages: RegionalUsages = operands[0].parse().map_err(|error: RegionalUsagesFromStrError| format!("{}; try 'caniuse regions'", error))?;
+	let regional_usage = databases.regional_usage(regional_usages)?;
+	
+	let mut usages = Vec::new();
+	for agent_name in sorted_agent_names(&databases.can_i_use)
+	{
+		if let Some(versions) = regional_usage.usage(agent_name, Unbounded, Unbounded)
+		{
+			for (version, usage) in versions
+			{
+				if let Some(usage) = *usage
+				{
+					if usage > UsagePercentage::default()
+					{
+						usages.push((agent_name, version, usage));
+					}
+				}
+			}
+		}
+	}
+	usages.sort_by_key(|&(_, _, usage)| Reverse(usage));
+	
+	let mut text = format!("Usage in {} ({}), totalling {}\n\n", regional_usage.country_or_region_name(), regional_usages.can_i_use_iso_like_code(), regional_usage.total());
+	let mut json = Vec::new();
+	for (agent_name, version, usage) in usages
+	{
+		text.push_str(&format!("{:<10} {:<12} {}\n", agent_name.can_i_use_identifier(), version.to_string(), usage));
+		json.push(json!({ "agent": agent_name.can_i_use_identifier(), "version": version.to_string(), "usage": usage.to_scalar() }));
+	}
+	
+	Ok(Output
+	{
+		text,
+		json: json!
+		({
+			"region": regional_usages.can_i_use_iso_like_code(),
+			"name": regional_usage.country_or_region_name(),
+			"total": regional_usage.total().to_scalar(),
+			"usage": json,
+		}),
+	})
+}
@@ -0,0 +1,245 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+impl CanIUse
+{
+	/// Adds features imported from a checkout of MDN's browser-compat-data (<https://github.com/mdn/browser-compat-data>), which covers JavaScript APIs and HTML elements far more thoroughly than caniuse.com.
+	/// Features are named `mdn-` followed by their path in lower case joined with `__`, eg `mdn-api__abortcontroller__abort` for `api.AbortController.abort`.
+	/// Only agents and versions known to this database are imported; prefixed support is imported as requiring a prefix, and versions before a ranged version such as "≤37" as unknown support.
+	#[inline(always)]
+	pub fn with_browser_compat_data<P: AsRef<Path>>(mut self, browser_compat_data_folder_path: P) -> Result<Self, LoadError>
+	{
+		let features = BrowserCompatDataImporter::import(&self.agents, browser_compat_data_folder_path)?;
+		self.features.extend(features);
+		Ok(self)
+	}
+}
+
+/// Converts the `__compat` entries of a checkout of MDN's browser-compat-data into features.
+/// Support is evaluated for every version of every agent known to the caniuse.com database, so that imported features can be used exactly like caniuse.com ones, eg with `AgentNameAndVersionSet::support_for_a_feature()`.
+#[derive(Debug)]
+struct BrowserCompatDataImporter<'a>
+{
+	agents: &'a HashMap<AgentName, AgentDetail>,
+	features: HashMap<FeatureName, FeatureDetail>,
+}
+
+impl<'a> BrowserCompatDataImporter<'a>
+{
+	/// Top-level folders of a browser-compat-data checkout containing features of web pages.
+	const Folders: &'static [&'static str] = &["api", "css", "html", "http", "javascript", "mathml", "svg", "webassembly"];
+	
+	#[inline(always)]
+	fn import<P: AsRef<Path>>(agents: &'a HashMap<AgentName, AgentDetail>, browser_compat_data_folder_path: P) -> Result<HashMap<FeatureName, FeatureDetail>, LoadError>
+	{
+		let mut importer = BrowserCompatDataImporter
+		{
+			agents,
+			features: HashMap::new(),
+		};
+		
+		let browser_compat_data_folder_path = browser_compat_data_folder_path.as_ref();
+		for folder in Self::Folders.iter()
+		{
+			let folder_path = browser_compat_data_folder_path.join(folder);
+			if folder_path.is_dir()
+			{
+				importer.import_folder(folder_path)?;
+			}
+		}
+		
+		Ok(importer.features)
+	}
+	
+	#[inline(always)]
+	fn import_folder(&mut self, folder_path: PathBuf) -> Result<(), LoadError>
+	{
+		for entry in read_dir(folder_path)?
+		{
+			let path = entry?.path();
+			if path.is_dir()
+			{
+				self.import_folder(path)?;
+			}
+			else if path.extension() == Some("json".as_ref())
+			{
+				let file = File::open(path)?;
+				let json: Value = LoadErrorContext::load(|| ::serde_json::from_reader(file))?;
+				if let Value::Object(ref json) = json
+				{
+					self.import_object(&mut Vec::new(), None, json);
+				}
+			}
+		}
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn import_object(&mut self, path: &mut Vec<String>, parent: Option<FeatureName>, object: &Map<String, Value>)
+	{
+		for (key, value) in object.iter()
+		{
+			if key == "__compat"
+			{
+				continue;
+			}
+			
+			if let Value::Object(ref child) = *value
+			{
+				path.push(key.to_owned());
+				
+				let feature_name = child.get("__compat").and_then(Value::as_object).map(|compat| self.import_compat(path, parent.clone(), compat));
+				self.import_object(path, feature_name.or_else(|| parent.clone()), child);
+				
+				path.pop();
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn import_compat(&mut self, path: &[String], parent: Option<FeatureName>, compat: &Map<String, Value>) -> FeatureName
+	{
+		let feature_name = FeatureName(format!("mdn-{}", path.join("__").to_lowercase()));
+		
+		let category = match &path[0][..]
+		{
+			"api" => Category::JS_API,
+			"css" => Category::CSS,
+			"html" => Category::HTML5,
+			"javascript" => Category::JS,
+			"svg" => Category::SVG,
+			_ => Category::Other,
+		};
+		
+		let mut links = Vec::new();
+		if let Some(url) = compat.get("mdn_url").and_then(|url| url.as_str()).and_then(|url| Url::parse(url).ok())
+		{
+			links.push(Link
+			{
+				url,
+				title: "MDN Web Docs".to_owned(),
+			});
+		}
+		let specification_urls: Vec<Url> = match compat.get("spec_url")
+		{
+			Some(Value::String(url)) => Url::parse(url).into_iter().collect(),
+			Some(Value::Array(urls)) => urls.iter().filter_map(|url| url.as_str()).filter_map(|url| Url::parse(url).ok()).collect(),
+			_ => vec![],
+		};
+		let specification_url = match specification_urls.first()
+		{
+			Some(url) => url.clone(),
+			None => match links.first()
+			{
+				Some(link) => link.url.clone(),
+				None => Url::parse("https://developer.mozilla.org/").unwrap(),
+			},
+		};
+		
+		// MDN only records whether a feature is on a standards track and whether it is experimental, so stable standards are taken to be candidate recommendations unless specified by the WHATWG.
+		let status_flag = |flag: &str| compat.get("status").and_then(|status| status.get(flag)).and_then(|value| value.as_bool());
+		let status = match (status_flag("standard_track"), status_flag("experimental"))
+		{
+			(Some(false), _) => Status::UnofficialOrNote,
+			(Some(true), _) if specification_urls.iter().any(|url| url.host_str().map(|host| host.ends_with("whatwg.org")).unwrap_or(false)) => Status::WhatwgLivingStandard,
+			(Some(true), Some(true)) => Status::W3CWorkingDraft,
+			(Some(true), _) => Status::W3CCandidateRecommendation,
+			(None, _) => Status::Other,
+		};
+		
+		let mut notes_by_one_based_number = BTreeMap::new();
+		let mut implementations_by_agents = HashMap::new();
+		let mut supported_by_default_usage = UsagePercentage::default();
+		let mut almost_supported_usage = UsagePercentage::default();
+		if let Some(support) = compat.get("support").and_then(Value::as_object)
+		{
+			for (browser_identifier, statements) in support.iter()
+			{
+				let agent_name = match browser_compat_data_agent_name(browser_identifier)
+				{
+					None => continue,
+					Some(agent_name) => agent_name,
+				};
+				
+				let agent_detail = match self.agents.get(&agent_name)
+				{
+					None => continue,
+					Some(agent_detail) => agent_detail,
+				};
+				
+				let statements = BrowserCompatDataSupportStatement::from_value(statements);
+				let mut implementations = BTreeMap::new();
+				for version in agent_detail.version_list.keys()
+				{
+					let (mut support_detail, notes) = BrowserCompatDataSupportStatement::support_detail(&statements, version);
+					
+					for note in notes.iter()
+					{
+						let existing = notes_by_one_based_number.iter().find(|&(_, existing_note)| existing_note == note).map(|(note_number, _)| *note_number);
+						let note_number = match existing
+						{
+							Some(note_number) => note_number,
+							None =>
+							{
+								let note_number = notes_by_one_based_number.len() + 1;
+								if note_number > u8::MAX as usize
+								{
+									continue;
+								}
+								let note_number = note_number as u8;
+								notes_by_one_based_number.insert(note_number, note.to_owned());
+								note_number
+							}
+						};
+						support_detail.notes_by_one_based_number.push(note_number);
+					}
+					
+					if let Some(global_usage) = agent_detail.usage_global.get(version)
+					{
+						if !support_detail.disabled_by_default
+						{
+							match support_detail.maturity
+							{
+								SupportMaturity::SupportedByDefault => supported_by_default_usage += *global_usage,
+								SupportMaturity::AlmostSupported => almost_supported_usage += *global_usage,
+								_ => (),
+							}
+						}
+					}
+					
+					implementations.insert(version.clone(), support_detail);
+				}
+				implementations_by_agents.insert(agent_name, implementations);
+			}
+		}
+		
+		let feature_detail = FeatureDetail
+		{
+			title: path[1..].join("."),
+			description: compat.get("description").and_then(|description| description.as_str()).unwrap_or("").to_owned(),
+			specification_url,
+			status,
+			links,
+			bugs: vec![],
+			categories: vec![category],
+			implementations_by_agents,
+			notes: String::new(),
+			notes_by_one_based_number,
+			parent,
+			supported_by_default_usage,
+			almost_supported_usage,
+			upper_case_prefix: false,
+			keywords: CommaSeparatedStrings::default(),
+			internet_explorer_feature_identifiers: CommaSeparatedStrings::default(),
+			blink_feature_identifiers: CommaSeparatedStrings::default(),
+			firefox_feature_identifiers: CommaSeparatedStrings::default(),
+			webkit_feature_identifiers: CommaSeparatedStrings::default(),
+			shown: true,
+			extra_fields: BTreeMap::new(),
+		};
+		
+		self.features.insert(feature_name.clone(), feature_detail);
+		feature_name
+	}
+}
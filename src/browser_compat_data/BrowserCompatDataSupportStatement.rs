@@ -0,0 +1,130 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// One of the support statements for a browser in a `__compat` entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BrowserCompatDataSupportStatement
+{
+	version_added: BrowserCompatDataVersion,
+	version_removed: BrowserCompatDataVersion,
+	prefix: bool,
+	alternative_name: bool,
+	flags: bool,
+	partial_implementation: bool,
+	notes: Vec<String>,
+}
+
+impl BrowserCompatDataSupportStatement
+{
+	/// A browser's support is either a single statement or an array of them.
+	#[inline(always)]
+	fn from_value(value: &Value) -> Vec<Self>
+	{
+		match *value
+		{
+			Value::Array(ref statements) => statements.iter().filter_map(|statement| statement.as_object()).map(Self::from_object).collect(),
+			Value::Object(ref statement) => vec![Self::from_object(statement)],
+			_ => vec![],
+		}
+	}
+	
+	#[inline(always)]
+	fn from_object(statement: &Map<String, Value>) -> Self
+	{
+		let notes = match statement.get("notes")
+		{
+			Some(Value::String(note)) => vec![note.to_owned()],
+			Some(Value::Array(notes)) => notes.iter().filter_map(|note| note.as_str()).map(|note| note.to_owned()).collect(),
+			_ => vec![],
+		};
+		
+		BrowserCompatDataSupportStatement
+		{
+			version_added: BrowserCompatDataVersion::from_value(statement.get("version_added")),
+			version_removed: BrowserCompatDataVersion::from_value(statement.get("version_removed")),
+			prefix: statement.get("prefix").and_then(|prefix| prefix.as_str()).is_some(),
+			alternative_name: statement.get("alternative_name").and_then(|alternative_name| alternative_name.as_str()).is_some(),
+			flags: statement.get("flags").and_then(|flags| flags.as_array()).map(|flags| !flags.is_empty()).unwrap_or(false),
+			partial_implementation: statement.get("partial_implementation").and_then(|partial_implementation| partial_implementation.as_bool()).unwrap_or(false),
+			notes,
+		}
+	}
+	
+	/// Does this statement apply to `version`?
+	#[inline(always)]
+	fn applies_to(&self, version: &Version) -> bool
+	{
+		self.version_added.by(version) && !self.version_removed.by(version) && !self.version_removed.perhaps_by(version)
+	}
+	
+	/// Might this statement apply to `version`, without it being known to, eg for version 30 when added in "≤37"?
+	#[inline(always)]
+	fn perhaps_applies_to(&self, version: &Version) -> bool
+	{
+		(self.version_added.by(version) || self.version_added.perhaps_by(version)) && !self.version_removed.by(version) && !self.applies_to(version)
+	}
+	
+	/// Statements with fewer caveats are preferred; a browser's statements are not reliably ordered.
+	#[inline(always)]
+	fn caveats(&self) -> (bool, bool, bool)
+	{
+		(self.flags, self.prefix || self.alternative_name, self.partial_implementation)
+	}
+	
+	/// Support for `version`, together with the notes of the statement that applies, if any.
+	/// An alternative name is treated as almost supported, as a different name is not something a prefix can fix.
+	/// Support is unknown if no statement applies but one might, eg before the version of "≤37".
+	#[inline(always)]
+	fn support_detail<'a>(statements: &'a [Self], version: &Version) -> (SupportDetail, &'a [String])
+	{
+		use self::SupportMaturity::*;
+		
+		match statements.iter().filter(|statement| statement.applies_to(version)).min_by_key(|statement| statement.caveats())
+		{
+			None =>
+			{
+				let maturity = if statements.iter().all(|statement| statement.version_added == BrowserCompatDataVersion::Unknown) || statements.iter().any(|statement| statement.perhaps_applies_to(version))
+				{
+					SupportUnknown
+				}
+				else
+				{
+					NotSupportedOrDisabledByDefault
+				};
+				
+				(SupportDetail
+				{
+					maturity,
+					requires_prefix: false,
+					disabled_by_default: false,
+					notes_by_one_based_number: vec![],
+				}, &[])
+			}
+			
+			Some(statement) =>
+			{
+				let maturity = if statement.flags
+				{
+					NotSupportedOrDisabledByDefault
+				}
+				else if statement.partial_implementation || statement.alternative_name
+				{
+					AlmostSupported
+				}
+				else
+				{
+					SupportedByDefault
+				};
+				
+				(SupportDetail
+				{
+					maturity,
+					requires_prefix: statement.prefix,
+					disabled_by_default: statement.flags,
+					notes_by_one_based_number: vec![],
+				}, &statement.notes[..])
+			}
+		}
+	}
+}
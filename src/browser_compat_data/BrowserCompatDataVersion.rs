@@ -0,0 +1,85 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// A `version_added` or `version_removed` value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BrowserCompatDataVersion
+{
+	/// eg "12".
+	Version(Version),
+	
+	/// eg "≤37"; at that version or an unknown earlier one.
+	AtOrBefore(Version),
+	
+	/// "preview"; only in a browser's preview or nightly release.
+	Preview,
+	
+	/// `true`; at an unknown version.
+	Yes,
+	
+	/// `false`.
+	No,
+	
+	/// `null`, or anything unrecognised.
+	Unknown,
+}
+
+impl BrowserCompatDataVersion
+{
+	#[inline(always)]
+	fn from_value(value: Option<&Value>) -> Self
+	{
+		use self::BrowserCompatDataVersion::*;
+		
+		match value
+		{
+			Some(Value::Bool(true)) => Yes,
+			Some(Value::Bool(false)) => No,
+			Some(Value::String(version)) =>
+			{
+				let at_or_before = version.trim_start_matches('≤').trim_start_matches("<=");
+				match at_or_before
+				{
+					"preview" => Preview,
+					"" => Unknown,
+					_ if at_or_before.len() != version.len() => AtOrBefore(::Version::parse(at_or_before)),
+					_ => Version(::Version::parse(version)),
+				}
+			}
+			_ => Unknown,
+		}
+	}
+	
+	/// Has this happened by `version`?
+	/// Versions of agents in the caniuse.com database that aren't numbers (Safari's TP, Opera Mini's all) are considered later than every numbered version.
+	#[inline(always)]
+	fn by(&self, version: &Version) -> bool
+	{
+		use self::BrowserCompatDataVersion::*;
+		
+		match *self
+		{
+			Version(ref happened) | AtOrBefore(ref happened) => match version.major_number()
+			{
+				None => version.is_safari_technology_preview(),
+				Some(_) => *happened <= version.lower_bound(),
+			},
+			Preview => version.is_safari_technology_preview(),
+			Yes => true,
+			No | Unknown => false,
+		}
+	}
+	
+	/// Might this have happened by `version`, without it being known to have?
+	/// Only true for versions before an `AtOrBefore` version.
+	#[inline(always)]
+	fn perhaps_by(&self, version: &Version) -> bool
+	{
+		match *self
+		{
+			BrowserCompatDataVersion::AtOrBefore(ref happened) => version.major_number().is_some() && version.lower_bound() < *happened,
+			_ => false,
+		}
+	}
+}
@@ -0,0 +1,32 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Maps a MDN browser-compat-data browser identifier, eg `safari_ios`, to an agent.
+/// Returns None for browsers that caniuse.com does not have, eg `nodejs`.
+#[inline(always)]
+pub fn browser_compat_data_agent_name(browser_compat_data_browser_identifier: &str) -> Option<AgentName>
+{
+	use self::AgentName::*;
+	
+	let agent_name = match browser_compat_data_browser_identifier
+	{
+		"ie" => MicrosoftInternetExplorer,
+		"edge" => MicrosoftEdge,
+		"firefox" => MozillaFirefox,
+		"chrome" => GoogleChrome,
+		"safari" => AppleSafari,
+		"opera" => Opera,
+		"safari_ios" => AppleSafariIOs,
+		"webview_android" => GoogleAndroidBrowserAndWebComponent,
+		"opera_android" => OperaMobile,
+		"chrome_android" => GoogleChromeAndroid,
+		"firefox_android" => MozillaFirefoxAndroid,
+		"uc_android" => UcBrowserAndroid,
+		"samsunginternet_android" => SamsungBrowserAndroid,
+		"qq_android" => QqBrowserAndroid,
+		
+		_ => return None,
+	};
+	Some(agent_name)
+}
@@ -0,0 +1,14 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+use super::*;
+use ::serde_json::Map;
+use ::serde_json::Value;
+use ::std::path::PathBuf;
+
+
+include!("browser_compat_data_agent_name.rs");
+include!("BrowserCompatDataImporter.rs");
+include!("BrowserCompatDataSupportStatement.rs");
+include!("BrowserCompatDataVersion.rs");
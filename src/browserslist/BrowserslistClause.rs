@@ -0,0 +1,62 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// A single clause of a browserslist query, eg `not dead` or `and last 2 versions`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrowserslistClause
+{
+	combinator: BrowserslistCombinator,
+	negated: bool,
+	selector: BrowserslistSelector,
+}
+
+impl BrowserslistClause
+{
+	/// Constructor.
+	#[inline(always)]
+	pub fn new(combinator: BrowserslistCombinator, negated: bool, selector: BrowserslistSelector) -> Self
+	{
+		Self
+		{
+			combinator,
+			negated,
+			selector,
+		}
+	}
+	
+	/// How this clause combines with the result of the clauses before it; ignored if negated.
+	#[inline(always)]
+	pub fn combinator(&self) -> BrowserslistCombinator
+	{
+		self.combinator
+	}
+	
+	/// Is this a `not` clause, ie one which removes its selection from the result of the clauses before it?
+	#[inline(always)]
+	pub fn negated(&self) -> bool
+	{
+		self.negated
+	}
+	
+	/// What this clause selects.
+	#[inline(always)]
+	pub fn selector(&self) -> &BrowserslistSelector
+	{
+		&self.selector
+	}
+	
+	#[inline(always)]
+	fn parse(combinator: BrowserslistCombinator, clause: &str) -> Result<Self, BrowserslistQueryParseError>
+	{
+		let clause = clause.trim();
+		
+		let (negated, selector) = match clause.get(..4)
+		{
+			Some(not) if not.eq_ignore_ascii_case("not ") => (true, &clause[4..]),
+			_ => (false, clause),
+		};
+		
+		Ok(Self::new(combinator, negated, selector.parse()?))
+	}
+}
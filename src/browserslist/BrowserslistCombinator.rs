@@ -0,0 +1,25 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// How a clause combines with the result of the clauses before it.
+/// As for browserslist, clauses are combined strictly left-to-right.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum BrowserslistCombinator
+{
+	/// `,` or `or`; a union.
+	Or,
+	
+	/// `and`; an intersection.
+	And,
+}
+
+impl Default for BrowserslistCombinator
+{
+	/// Defaults to BrowserslistCombinator::Or
+	#[inline(always)]
+	fn default() -> Self
+	{
+		BrowserslistCombinator::Or
+	}
+}
@@ -0,0 +1,59 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// A comparison operator, as used in `> 0.5%` or `Chrome >= 50`.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum BrowserslistComparison
+{
+	/// `>`
+	GreaterThan,
+	
+	/// `>=`
+	GreaterThanOrEqualTo,
+	
+	/// `<`
+	LessThan,
+	
+	/// `<=`
+	LessThanOrEqualTo,
+}
+
+impl FromStr for BrowserslistComparison
+{
+	type Err = ();
+	
+	/// Parses `>`, `>=`, `<` and `<=`.
+	#[inline(always)]
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		use self::BrowserslistComparison::*;
+		
+		match s
+		{
+			">" => Ok(GreaterThan),
+			">=" => Ok(GreaterThanOrEqualTo),
+			"<" => Ok(LessThan),
+			"<=" => Ok(LessThanOrEqualTo),
+			_ => Err(()),
+		}
+	}
+}
+
+impl BrowserslistComparison
+{
+	/// Does `left` compare to `right` using this comparison?
+	#[inline(always)]
+	pub fn compare<T: Ord>(&self, left: &T, right: &T) -> bool
+	{
+		use self::BrowserslistComparison::*;
+		
+		match *self
+		{
+			GreaterThan => left > right,
+			GreaterThanOrEqualTo => left >= right,
+			LessThan => left < right,
+			LessThanOrEqualTo => left <= right,
+		}
+	}
+}
@@ -0,0 +1,278 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Browserslist configuration, as found in a `.browserslistrc` (or `browserslist`) file or under the `browserslist` key of a `package.json`.
+/// Queries outside of an environment section (eg `[production]`) are the defaults, used when there is no section for an environment.
+/// Resolution of configuration mirrors that of browserslist for Node.js, so that Rust and Node.js tooling agree; see `BrowserslistConfiguration::resolve()`.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct BrowserslistConfiguration
+{
+	defaults: Vec<String>,
+	environments: HashMap<String, Vec<String>>,
+}
+
+impl FromStr for BrowserslistConfiguration
+{
+	type Err = BrowserslistConfigurationError;
+	
+	/// Parses the contents of a `.browserslistrc` file.
+	fn from_str(browserslistrc: &str) -> Result<Self, Self::Err>
+	{
+		let mut configuration = Self::default();
+		let mut current_environments: Vec<String> = Vec::new();
+		
+		for line in browserslistrc.lines()
+		{
+			let line = match line.find('#')
+			{
+				None => line,
+				Some(index) => &line[..index],
+			}.trim();
+			
+			if line.is_empty()
+			{
+				continue;
+			}
+			
+			if line.starts_with('[') && line.ends_with(']')
+			{
+				current_environments = line[1 .. line.len() - 1].split_whitespace().map(|environment| environment.to_owned()).collect();
+				for environment in current_environments.iter()
+				{
+					if configuration.environments.insert(environment.clone(), Vec::new()).is_some()
+					{
+						return Err(BrowserslistConfigurationError::DuplicateEnvironment(environment.clone()));
+					}
+				}
+				continue;
+			}
+			
+			Self::check_is_not_extends(line)?;
+			
+			if current_environments.is_empty()
+			{
+				configuration.defaults.push(line.to_owned());
+			}
+			else
+			{
+				for environment in current_environments.iter()
+				{
+					configuration.environments.get_mut(environment).unwrap().push(line.to_owned());
+				}
+			}
+		}
+		
+		Ok(configuration)
+	}
+}
+
+impl BrowserslistConfiguration
+{
+	/// The environment used when none is specified by the `BROWSERSLIST_ENV` or `NODE_ENV` environment variables.
+	pub const DefaultEnvironment: &'static str = "production";
+	
+	/// Resolves the query to use exactly as browserslist for Node.js would, given a directory (typically that of the file being processed) and an optional environment (eg `development`):-
+	/// - the `BROWSERSLIST` environment variable, if set, is the query;
+	/// - otherwise the file in the `BROWSERSLIST_CONFIG` environment variable, if set, is the configuration;
+	/// - otherwise the configuration is found by `BrowserslistConfiguration::find()`;
+	/// - the environment, if not specified, is that in the `BROWSERSLIST_ENV` or `NODE_ENV` environment variables, or `production`;
+	/// - if there is no configuration, or it has no queries for the environment, the query is `defaults`.
+	pub fn resolve<P: AsRef<Path>>(directory: P, environment: Option<&str>) -> Result<BrowserslistQuery, BrowserslistConfigurationError>
+	{
+		if let Some(query) = ::std::env::var_os("BROWSERSLIST")
+		{
+			return Ok(query.to_string_lossy().parse()?);
+		}
+		
+		let configuration = match ::std::env::var_os("BROWSERSLIST_CONFIG")
+		{
+			Some(configuration_file_path) => Some(Self::from_path(configuration_file_path)?),
+			None => Self::find(directory)?.map(|(_configuration_file_path, configuration)| configuration),
+		};
+		
+		let environment = match environment
+		{
+			Some(environment) => environment.to_owned(),
+			None => ::std::env::var("BROWSERSLIST_ENV").or_else(|_| ::std::env::var("NODE_ENV")).unwrap_or_else(|_| Self::DefaultEnvironment.to_owned()),
+		};
+		
+		match configuration
+		{
+			None => Ok(BrowserslistQuery::Defaults.parse()?),
+			Some(configuration) => configuration.query(&environment),
+		}
+	}
+	
+	/// Finds configuration by walking up from a directory (inclusive) to the root, as browserslist does.
+	/// In each directory, looks for a `browserslist` file, a `.browserslistrc` file and a `package.json` with a `browserslist` key; it is an error for a directory to have more than one of these.
+	/// Returns the path of the file found and its configuration, or None if there is no configuration.
+	pub fn find<P: AsRef<Path>>(directory: P) -> Result<Option<(PathBuf, Self)>, BrowserslistConfigurationError>
+	{
+		let mut directory = Some(directory.as_ref());
+		
+		while let Some(current) = directory
+		{
+			let browserslist_file_path = current.join("browserslist");
+			let browserslistrc_file_path = current.join(".browserslistrc");
+			let package_json_file_path = current.join("package.json");
+			
+			let mut found = Vec::with_capacity(3);
+			
+			for file_path in [browserslist_file_path, browserslistrc_file_path].iter()
+			{
+				if file_path.is_file()
+				{
+					let configuration = Self::from_path(file_path)?;
+					found.push((file_path.clone(), configuration));
+				}
+			}
+			
+			if package_json_file_path.is_file()
+			{
+				if let Some(configuration) = Self::from_package_json(&Self::read_package_json(&package_json_file_path)?, &package_json_file_path)?
+				{
+					found.push((package_json_file_path, configuration));
+				}
+			}
+			
+			match found.len()
+			{
+				0 => directory = current.parent(),
+				1 => return Ok(found.pop()),
+				_ => return Err(BrowserslistConfigurationError::MoreThanOneConfiguration(current.to_path_buf())),
+			}
+		}
+		
+		Ok(None)
+	}
+	
+	/// Loads configuration from a file path; if the file is called `package.json` then uses the `browserslist` key (which must be present), otherwise parses it as a `.browserslistrc` file.
+	pub fn from_path<P: AsRef<Path>>(configuration_file_path: P) -> Result<Self, BrowserslistConfigurationError>
+	{
+		let configuration_file_path = configuration_file_path.as_ref();
+		
+		if configuration_file_path.file_name().map(|file_name| file_name == "package.json").unwrap_or(false)
+		{
+			match Self::from_package_json(&Self::read_package_json(configuration_file_path)?, configuration_file_path)?
+			{
+				None => Err(BrowserslistConfigurationError::InvalidPackageJson(configuration_file_path.to_path_buf())),
+				Some(configuration) => Ok(configuration),
+			}
+		}
+		else
+		{
+			let mut browserslistrc = String::new();
+			File::open(configuration_file_path).and_then(|mut file| file.read_to_string(&mut browserslistrc)).map_err(|error| BrowserslistConfigurationError::Io(configuration_file_path.to_path_buf(), error))?;
+			browserslistrc.parse()
+		}
+	}
+	
+	/// Obtains configuration from the `browserslist` key of a parsed `package.json`; returns None if there is no such key.
+	/// The `package_json_file_path` is only used for error reporting.
+	pub fn from_package_json(package_json: &::serde_json::Value, package_json_file_path: &Path) -> Result<Option<Self>, BrowserslistConfigurationError>
+	{
+		use ::serde_json::Value;
+		
+		let invalid = || BrowserslistConfigurationError::InvalidPackageJson(package_json_file_path.to_path_buf());
+		
+		fn queries<E, F: Fn() -> E>(value: &Value, invalid: F) -> Result<Vec<String>, E>
+		{
+			match *value
+			{
+				Value::String(ref query) => Ok(vec![query.clone()]),
+				Value::Array(ref queries) => queries.iter().map(|query| query.as_str().map(|query| query.to_owned()).ok_or_else(&invalid)).collect(),
+				_ => Err(invalid()),
+			}
+		}
+		
+		let browserslist = match package_json.get("browserslist")
+		{
+			None => return Ok(None),
+			Some(browserslist) => browserslist,
+		};
+		
+		let mut configuration = Self::default();
+		
+		match *browserslist
+		{
+			Value::Object(ref environments) => for (environment, value) in environments.iter()
+			{
+				let queries = queries(value, invalid)?;
+				if environment == "defaults"
+				{
+					configuration.defaults = queries;
+				}
+				else
+				{
+					configuration.environments.insert(environment.clone(), queries);
+				}
+			},
+			
+			_ => configuration.defaults = queries(browserslist, invalid)?,
+		}
+		
+		for query in configuration.defaults.iter().chain(configuration.environments.values().flat_map(|queries| queries.iter()))
+		{
+			Self::check_is_not_extends(query)?;
+		}
+		
+		Ok(Some(configuration))
+	}
+	
+	/// Queries used when there is no section for an environment.
+	#[inline(always)]
+	pub fn defaults(&self) -> &[String]
+	{
+		&self.defaults[..]
+	}
+	
+	/// Queries for an environment, falling back to the defaults if there is no section for it.
+	#[inline(always)]
+	pub fn queries(&self, environment: &str) -> &[String]
+	{
+		match self.environments.get(environment)
+		{
+			None => self.defaults(),
+			Some(queries) => &queries[..],
+		}
+	}
+	
+	/// The query for an environment; if there are no queries for the environment, this is `defaults`.
+	#[inline(always)]
+	pub fn query(&self, environment: &str) -> Result<BrowserslistQuery, BrowserslistConfigurationError>
+	{
+		let queries = self.queries(environment);
+		
+		let query = if queries.is_empty()
+		{
+			BrowserslistQuery::Defaults.to_owned()
+		}
+		else
+		{
+			queries.join(", ")
+		};
+		
+		Ok(query.parse()?)
+	}
+	
+	#[inline(always)]
+	fn read_package_json(package_json_file_path: &Path) -> Result<::serde_json::Value, BrowserslistConfigurationError>
+	{
+		let file = File::open(package_json_file_path).map_err(|error| BrowserslistConfigurationError::Io(package_json_file_path.to_path_buf(), error))?;
+		::serde_json::from_reader(file).map_err(|error| BrowserslistConfigurationError::Json(package_json_file_path.to_path_buf(), error))
+	}
+	
+	#[inline(always)]
+	fn check_is_not_extends(query: &str) -> Result<(), BrowserslistConfigurationError>
+	{
+		if query.trim_start().starts_with("extends ")
+		{
+			Err(BrowserslistConfigurationError::ExtendsIsNotSupported(query.to_owned()))
+		}
+		else
+		{
+			Ok(())
+		}
+	}
+}
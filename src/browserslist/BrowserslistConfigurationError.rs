@@ -0,0 +1,92 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Represents the errors that can occur when finding, loading or using browserslist configuration.
+#[derive(Debug)]
+pub enum BrowserslistConfigurationError
+{
+	/// A configuration file could not be read.
+	Io(PathBuf, io::Error),
+	
+	/// A `package.json` file was not valid JSON.
+	Json(PathBuf, ::serde_json::error::Error),
+	
+	/// The `browserslist` key in a `package.json` was not a string, an array of strings or an object of environment names to strings or arrays of strings.
+	InvalidPackageJson(PathBuf),
+	
+	/// A directory contains more than one configuration, eg both a `.browserslistrc` and a `package.json` with a `browserslist` key.
+	MoreThanOneConfiguration(PathBuf),
+	
+	/// An environment section, eg `[production]`, was defined more than once.
+	DuplicateEnvironment(String),
+	
+	/// An `extends` query was used; shareable configurations are not supported.
+	ExtendsIsNotSupported(String),
+	
+	/// A query was invalid.
+	Query(BrowserslistQueryParseError),
+}
+
+impl From<BrowserslistQueryParseError> for BrowserslistConfigurationError
+{
+	#[inline(always)]
+	fn from(error: BrowserslistQueryParseError) -> Self
+	{
+		BrowserslistConfigurationError::Query(error)
+	}
+}
+
+impl Error for BrowserslistConfigurationError
+{
+	#[inline(always)]
+	fn description(&self) -> &str
+	{
+		use self::BrowserslistConfigurationError::*;
+		
+		match *self
+		{
+			Io(..) => "could not read browserslist configuration",
+			Json(..) => "invalid JSON in package.json",
+			InvalidPackageJson(_) => "invalid browserslist key in package.json",
+			MoreThanOneConfiguration(_) => "more than one browserslist configuration in directory",
+			DuplicateEnvironment(_) => "duplicate environment in browserslist configuration",
+			ExtendsIsNotSupported(_) => "extends is not supported in browserslist configuration",
+			Query(_) => "invalid browserslist query",
+		}
+	}
+	
+	#[inline(always)]
+	fn cause(&self) -> Option<&dyn Error>
+	{
+		use self::BrowserslistConfigurationError::*;
+		
+		match *self
+		{
+			Io(_, ref error) => Some(error),
+			Json(_, ref error) => Some(error),
+			Query(ref error) => Some(error),
+			_ => None,
+		}
+	}
+}
+
+impl Display for BrowserslistConfigurationError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::BrowserslistConfigurationError::*;
+		
+		match *self
+		{
+			Io(ref path, ref error) => write!(f, "could not read browserslist configuration '{}': {}", path.display(), error),
+			Json(ref path, ref error) => write!(f, "invalid JSON in package.json '{}': {}", path.display(), error),
+			InvalidPackageJson(ref path) => write!(f, "invalid browserslist key in package.json '{}'", path.display()),
+			MoreThanOneConfiguration(ref path) => write!(f, "more than one browserslist configuration in directory '{}'", path.display()),
+			DuplicateEnvironment(ref value) => write!(f, "duplicate environment in browserslist configuration '{}'", value),
+			ExtendsIsNotSupported(ref value) => write!(f, "extends is not supported in browserslist configuration '{}'", value),
+			Query(ref error) => write!(f, "{}", error),
+		}
+	}
+}
@@ -0,0 +1,147 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// A parsed browserslist query, eg `> 0.5% in AU, last 2 versions, not dead, Firefox ESR`.
+/// Clauses are evaluated strictly left-to-right, as they are by browserslist; `,` and `or` are unions, `and` is an intersection and `not` removes from the result so far.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrowserslistQuery(Vec<BrowserslistClause>);
+
+impl FromStr for BrowserslistQuery
+{
+	type Err = BrowserslistQueryParseError;
+	
+	/// Parses a browserslist query.
+	#[inline(always)]
+	fn from_str(query: &str) -> Result<Self, Self::Err>
+	{
+		use self::BrowserslistCombinator::*;
+		use self::BrowserslistQueryParseError::*;
+		
+		let mut clauses = Vec::new();
+		
+		for comma_separated in query.split(',')
+		{
+			if comma_separated.trim().is_empty()
+			{
+				continue;
+			}
+			
+			for or_separated in Self::split_ignoring_ascii_case(comma_separated, " or ")
+			{
+				let mut combinator = Or;
+				for and_separated in Self::split_ignoring_ascii_case(or_separated, " and ")
+				{
+					if and_separated.trim().is_empty()
+					{
+						return Err(Empty);
+					}
+					
+					clauses.push(BrowserslistClause::parse(combinator, and_separated)?);
+					combinator = And;
+				}
+			}
+		}
+		
+		match clauses.first()
+		{
+			None => Err(Empty),
+			Some(clause) => if clause.negated()
+			{
+				Err(NotCanNotBeFirst)
+			}
+			else
+			{
+				Ok(BrowserslistQuery(clauses))
+			}
+		}
+	}
+}
+
+impl Deref for BrowserslistQuery
+{
+	type Target = [BrowserslistClause];
+	
+	/// Dereferences to the clauses of this query.
+	#[inline(always)]
+	fn deref(&self) -> &Self::Target
+	{
+		&self.0[..]
+	}
+}
+
+impl BrowserslistQuery
+{
+	/// The query used by browserslist when none is configured.
+	pub const Defaults: &'static str = "> 0.5%, last 2 versions, Firefox ESR, not dead";
+	
+	/// The agents and versions browserslist considers 'dead', ie without official support or updates for 24 months.
+	pub const Dead: &'static str = "Baidu >= 0, ie <= 11, ie_mob <= 11, bb <= 10, op_mob <= 12.1, samsung 4";
+	
+	/// Constructor to use if parsing isn't suitable.
+	#[inline(always)]
+	pub fn new(clauses: Vec<BrowserslistClause>) -> Self
+	{
+		BrowserslistQuery(clauses)
+	}
+	
+	/// Checks that `regional_usage_source` has the usage data of every region in this query, eg `AU` for `> 5% in AU`; clauses for regions it does not have select nothing.
+	#[inline(always)]
+	pub fn check_regional_usage_is_available<S: RegionalUsageSource>(&self, regional_usage_source: &S) -> Result<(), BrowserslistQueryParseError>
+	{
+		for regional_usages in self.0.iter().filter_map(|clause| clause.selector().regional_usages())
+		{
+			if regional_usage_source.regional_usage(regional_usages).is_none()
+			{
+				return Err(BrowserslistQueryParseError::RegionUnavailable(regional_usages));
+			}
+		}
+		Ok(())
+	}
+	
+	/// Evaluates this query against a caniuse.com database (and, for queries such as `> 5% in AU`, a source of regional usage data, eg `EmbeddedRegionalUsages`).
+	#[inline(always)]
+	pub fn evaluate<S: RegionalUsageSource>(&self, can_i_use: &CanIUse, regional_usage_source: &S) -> AgentNameAndVersionSet
+	{
+		use self::BrowserslistCombinator::*;
+		
+		let mut result = HashSet::new();
+		
+		for clause in self.0.iter()
+		{
+			let selected = clause.selector().select(can_i_use, regional_usage_source);
+			
+			if clause.negated()
+			{
+				result.retain(|agent_name_and_version| !selected.contains(agent_name_and_version));
+			}
+			else
+			{
+				match clause.combinator()
+				{
+					Or => result.extend(selected),
+					And => result.retain(|agent_name_and_version| selected.contains(agent_name_and_version)),
+				}
+			}
+		}
+		
+		AgentNameAndVersionSet::new(result)
+	}
+	
+	#[inline(always)]
+	fn split_ignoring_ascii_case<'a>(value: &'a str, separator: &str) -> Vec<&'a str>
+	{
+		// ASCII lower-casing does not change byte offsets
+		let lower_case = value.to_ascii_lowercase();
+		
+		let mut result = Vec::new();
+		let mut start = 0;
+		while let Some(index) = lower_case[start..].find(separator)
+		{
+			result.push(&value[start .. start + index]);
+			start += index + separator.len();
+		}
+		result.push(&value[start..]);
+		result
+	}
+}
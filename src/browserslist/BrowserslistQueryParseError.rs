@@ -0,0 +1,85 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Represents the errors that can occur when parsing a browserslist query.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum BrowserslistQueryParseError
+{
+	/// The query, or a part of it between `,`, `or` or `and`, was empty.
+	Empty,
+	
+	/// A `not` query was the first query; browserslist requires something to be selected before it can be excluded.
+	NotCanNotBeFirst,
+	
+	/// The query was not recognised.
+	UnknownQuery(String),
+	
+	/// The browser name was not recognised.
+	UnknownBrowser(String),
+	
+	/// The region was not recognised; contains the caniuse ISO-like code.
+	UnknownRegion(String),
+	
+	/// The region's usage data is not available from the `RegionalUsageSource` used, eg because its cargo feature is not enabled for `EmbeddedRegionalUsages`.
+	RegionUnavailable(RegionalUsages),
+	
+	/// A number (eg the count in `last 2 versions`) was invalid.
+	InvalidNumber(String),
+	
+	/// A percentage (eg in `> 0.5%`) was invalid.
+	InvalidPercentage(String),
+	
+	/// A date (eg in `since 2015-03`) was invalid.
+	InvalidDate(String),
+}
+
+impl Error for BrowserslistQueryParseError
+{
+	#[inline(always)]
+	fn description(&self) -> &str
+	{
+		use self::BrowserslistQueryParseError::*;
+		
+		match *self
+		{
+			Empty => "empty browserslist query",
+			NotCanNotBeFirst => "browserslist query can not start with 'not'",
+			UnknownQuery(_) => "unknown browserslist query",
+			UnknownBrowser(_) => "unknown browser in browserslist query",
+			UnknownRegion(_) => "unknown region in browserslist query",
+			RegionUnavailable(_) => "regional usage for region in browserslist query not available",
+			InvalidNumber(_) => "invalid number in browserslist query",
+			InvalidPercentage(_) => "invalid percentage in browserslist query",
+			InvalidDate(_) => "invalid date in browserslist query",
+		}
+	}
+	
+	#[inline(always)]
+	fn cause(&self) -> Option<&dyn Error>
+	{
+		None
+	}
+}
+
+impl Display for BrowserslistQueryParseError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::BrowserslistQueryParseError::*;
+		
+		match *self
+		{
+			Empty => write!(f, "empty browserslist query"),
+			NotCanNotBeFirst => write!(f, "browserslist query can not start with 'not'"),
+			UnknownQuery(ref value) => write!(f, "unknown browserslist query '{}'", value),
+			UnknownBrowser(ref value) => write!(f, "unknown browser in browserslist query '{}'", value),
+			UnknownRegion(ref value) => write!(f, "unknown region in browserslist query '{}'", value),
+			RegionUnavailable(regional_usages) => write!(f, "regional usage for region in browserslist query not available '{}'; enable the cargo feature '{}' or use a RegionalUsageRegistry", regional_usages.can_i_use_iso_like_code(), RegionalUsageNotEmbeddedError(regional_usages).cargo_feature()),
+			InvalidNumber(ref value) => write!(f, "invalid number in browserslist query '{}'", value),
+			InvalidPercentage(ref value) => write!(f, "invalid percentage in browserslist query '{}'", value),
+			InvalidDate(ref value) => write!(f, "invalid date in browserslist query '{}'", value),
+		}
+	}
+}
@@ -0,0 +1,604 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// What a browserslist clause selects.
+/// Global usage is that of the caniuse.com database (as it is for browserslist); regional usage is that of the `RegionalUsageSource` passed to `select()`.
+/// A selector for a region whose usage data the source does not have selects nothing; see `BrowserslistQuery::check_regional_usage_is_available()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BrowserslistSelector
+{
+	/// `defaults`; see `BrowserslistQuery::Defaults`.
+	Defaults,
+	
+	/// `dead`; see `BrowserslistQuery::Dead`.
+	Dead,
+	
+	/// `Firefox ESR`; the latest Firefox Extended Support Release, and its predecessor if the latest was released less than 12 weeks (2 Firefox release cycles) before the caniuse.com database was last updated.
+	FirefoxExtendedSupportRelease,
+	
+	/// `last 2 versions`, `last 2 major versions`, `last 2 Chrome versions` or `last 2 Chrome major versions`.
+	LastVersions
+	{
+		/// How many versions.
+		count: usize,
+		
+		/// None for every agent.
+		agent_name: Option<AgentName>,
+		
+		/// Count major versions (and select all released versions of them) rather than versions.
+		major: bool,
+	},
+	
+	/// `> 0.5%`, `>= 5% in AU` and the like.
+	Usage
+	{
+		/// Comparison.
+		comparison: BrowserslistComparison,
+		
+		/// Percentage.
+		usage_percentage: UsagePercentage,
+		
+		/// None for global usage.
+		regional_usages: Option<RegionalUsages>,
+	},
+	
+	/// `cover 99.5%` or `cover 99.5% in AU`; the most used versions which together cover at least the usage percentage.
+	Cover
+	{
+		/// Percentage.
+		usage_percentage: UsagePercentage,
+		
+		/// None for global usage.
+		regional_usages: Option<RegionalUsages>,
+	},
+	
+	/// `since 2015`, `since 2015-03` or `since 2015-03-10`; released versions released on or after a date.
+	Since(DateTime<Utc>),
+	
+	/// `unreleased versions` or `unreleased Chrome versions`.
+	UnreleasedVersions
+	{
+		/// None for every agent.
+		agent_name: Option<AgentName>,
+	},
+	
+	/// `Chrome > 50` and the like; versions which aren't numeric, such as Safari's TP, are never selected.
+	AgentVersionComparison
+	{
+		/// Agent.
+		agent_name: AgentName,
+		
+		/// Comparison.
+		comparison: BrowserslistComparison,
+		
+		/// Version to compare to.
+		version: Version,
+	},
+	
+	/// `ie 11`, `Safari TP` or `op_mini all`; a version within a known range, eg `iOS 15.2`, selects the known range, eg `15.2-15.3`.
+	AgentVersion
+	{
+		/// Agent.
+		agent_name: AgentName,
+		
+		/// Version.
+		version: Version,
+	},
+	
+	/// `ie 6-8`; an inclusive range of numeric versions.
+	AgentVersionRange
+	{
+		/// Agent.
+		agent_name: AgentName,
+		
+		/// First version in range.
+		first: Version,
+		
+		/// Last version in range.
+		last: Version,
+	},
+}
+
+impl FromStr for BrowserslistSelector
+{
+	type Err = BrowserslistQueryParseError;
+	
+	/// Parses a single browserslist clause, without any leading `not`.
+	fn from_str(selector: &str) -> Result<Self, Self::Err>
+	{
+		use self::BrowserslistQueryParseError::*;
+		use self::BrowserslistSelector::*;
+		
+		let tokens = Self::tokenize(selector);
+		let lower_case_tokens: Vec<String> = tokens.iter().map(|token| token.to_ascii_lowercase()).collect();
+		let lower_case: Vec<&str> = lower_case_tokens.iter().map(|token| token.as_str()).collect();
+		
+		let selector = match &lower_case[..]
+		{
+			[] => return Err(Empty),
+			
+			["defaults"] => Defaults,
+			
+			["dead"] => Dead,
+			
+			["firefox", "esr"] | ["ff", "esr"] | ["fx", "esr"] => FirefoxExtendedSupportRelease,
+			
+			["last", count, "versions"] | ["last", count, "version"] => LastVersions
+			{
+				count: Self::parse_count(count)?,
+				agent_name: None,
+				major: false,
+			},
+			
+			["last", count, "major", "versions"] | ["last", count, "major", "version"] => LastVersions
+			{
+				count: Self::parse_count(count)?,
+				agent_name: None,
+				major: true,
+			},
+			
+			["last", count, browser, "versions"] | ["last", count, browser, "version"] => LastVersions
+			{
+				count: Self::parse_count(count)?,
+				agent_name: Some(Self::parse_browser(browser)?),
+				major: false,
+			},
+			
+			["last", count, browser, "major", "versions"] | ["last", count, browser, "major", "version"] => LastVersions
+			{
+				count: Self::parse_count(count)?,
+				agent_name: Some(Self::parse_browser(browser)?),
+				major: true,
+			},
+			
+			["cover", usage_percentage] => Cover
+			{
+				usage_percentage: Self::parse_usage_percentage(usage_percentage)?,
+				regional_usages: None,
+			},
+			
+			["cover", usage_percentage, "in", _] => Cover
+			{
+				usage_percentage: Self::parse_usage_percentage(usage_percentage)?,
+				regional_usages: Some(Self::parse_regional_usages(&tokens[3])?),
+			},
+			
+			["since", date] => Since(Self::parse_date(date)?),
+			
+			["unreleased", "versions"] => UnreleasedVersions
+			{
+				agent_name: None,
+			},
+			
+			["unreleased", browser, "versions"] => UnreleasedVersions
+			{
+				agent_name: Some(Self::parse_browser(browser)?),
+			},
+			
+			[comparison, usage_percentage] if usage_percentage.ends_with('%') => Usage
+			{
+				comparison: Self::parse_comparison(comparison, selector)?,
+				usage_percentage: Self::parse_usage_percentage(usage_percentage)?,
+				regional_usages: None,
+			},
+			
+			[comparison, usage_percentage, "in", _] if usage_percentage.ends_with('%') => Usage
+			{
+				comparison: Self::parse_comparison(comparison, selector)?,
+				usage_percentage: Self::parse_usage_percentage(usage_percentage)?,
+				regional_usages: Some(Self::parse_regional_usages(&tokens[3])?),
+			},
+			
+			[browser, comparison, _] => AgentVersionComparison
+			{
+				agent_name: Self::parse_browser(browser)?,
+				comparison: Self::parse_comparison(comparison, selector)?,
+				version: Version::parse(&tokens[2]),
+			},
+			
+			[browser, _] => match tokens[1].find('-')
+			{
+				None => AgentVersion
+				{
+					agent_name: Self::parse_browser(browser)?,
+					version: Version::parse(&tokens[1]),
+				},
+				
+				Some(index) => AgentVersionRange
+				{
+					agent_name: Self::parse_browser(browser)?,
+					first: Version::parse(&tokens[1][..index]),
+					last: Version::parse(&tokens[1][index + 1..]),
+				},
+			},
+			
+			_ => return Err(UnknownQuery(selector.to_owned())),
+		};
+		
+		Ok(selector)
+	}
+}
+
+impl BrowserslistSelector
+{
+	/// Major versions of Firefox which were Extended Support Releases.
+	pub const FirefoxExtendedSupportReleaseMajorVersions: &'static [u64] = &[10, 17, 24, 31, 38, 45, 52, 60, 68, 78, 91, 102, 115, 128, 140];
+	
+	/// The region of a regional usage selector, eg `AU` for `> 5% in AU`.
+	#[inline(always)]
+	pub fn regional_usages(&self) -> Option<RegionalUsages>
+	{
+		use self::BrowserslistSelector::*;
+		
+		match *self
+		{
+			Usage { regional_usages, .. } | Cover { regional_usages, .. } => regional_usages,
+			_ => None,
+		}
+	}
+	
+	/// Selects agent and versions from a caniuse.com database and, for selectors such as `> 5% in AU`, a source of regional usage data.
+	pub fn select<S: RegionalUsageSource>(&self, can_i_use: &CanIUse, regional_usage_source: &S) -> HashSet<(AgentName, Version)>
+	{
+		use self::BrowserslistSelector::*;
+		
+		let mut selected = HashSet::new();
+		
+		match *self
+		{
+			Defaults => Self::select_query(can_i_use, regional_usage_source, BrowserslistQuery::Defaults, &mut selected),
+			
+			Dead => Self::select_query(can_i_use, regional_usage_source, BrowserslistQuery::Dead, &mut selected),
+			
+			FirefoxExtendedSupportRelease => Self::select_firefox_extended_support_release(can_i_use, &mut selected),
+			
+			LastVersions { count, ref agent_name, major } => Self::for_each_agent(can_i_use, agent_name, |agent|
+			{
+				let released: Vec<&Version> = agent.version_details_for_current_and_older_versions().map(|(version, _version_detail)| version).collect();
+				
+				if major
+				{
+					let mut major_numbers: Vec<u64> = released.iter().filter_map(|version| version.major_number()).collect();
+					major_numbers.dedup();
+					let last_major_numbers = &major_numbers[major_numbers.len().saturating_sub(count)..];
+					
+					for version in released.iter()
+					{
+						if let Some(major_number) = version.major_number()
+						{
+							if last_major_numbers.contains(&major_number)
+							{
+								selected.insert((agent.agent_name().clone(), (*version).clone()));
+							}
+						}
+					}
+				}
+				else
+				{
+					for version in released[released.len().saturating_sub(count)..].iter()
+					{
+						selected.insert((agent.agent_name().clone(), (*version).clone()));
+					}
+				}
+			}),
+			
+			Usage { comparison, usage_percentage, ref regional_usages } => for (agent_name, version, actual_usage) in Self::usages(can_i_use, regional_usage_source, regional_usages)
+			{
+				if comparison.compare(&actual_usage, &usage_percentage)
+				{
+					selected.insert((agent_name, version));
+				}
+			},
+			
+			Cover { usage_percentage, ref regional_usages } =>
+			{
+				let mut usages = Self::usages(can_i_use, regional_usage_source, regional_usages);
+				usages.sort_by_key(|&(_, _, usage)| Reverse(usage));
+				
+				let mut covered = UsagePercentage::Zero;
+				for (agent_name, version, actual_usage) in usages
+				{
+					if covered >= usage_percentage
+					{
+						break;
+					}
+					covered += actual_usage;
+					selected.insert((agent_name, version));
+				}
+			}
+			
+			Since(oldest_release_date) => Self::for_each_agent(can_i_use, &None, |agent|
+			{
+				agent.version_details_for_current_and_older_versions().for_each(|(version, version_detail)|
+				{
+					if let Some(release_date) = version_detail.release_date()
+					{
+						if release_date >= oldest_release_date
+						{
+							selected.insert((agent.agent_name().clone(), version.clone()));
+						}
+					}
+				})
+			}),
+			
+			UnreleasedVersions { ref agent_name } => Self::for_each_agent(can_i_use, agent_name, |agent|
+			{
+				use self::Bound::*;
+				
+				for (version, _version_detail) in agent.version_details().range((Excluded(agent.current_version()), Unbounded))
+				{
+					selected.insert((agent.agent_name().clone(), version.clone()));
+				}
+			}),
+			
+			AgentVersionComparison { ref agent_name, comparison, ref version } => Self::for_each_agent(can_i_use, &Some(agent_name.clone()), |agent|
+			{
+				for (known_version, _version_detail) in agent.version_details().iter()
+				{
+					if known_version.major_number().is_some() && comparison.compare(known_version, version)
+					{
+						selected.insert((agent.agent_name().clone(), known_version.clone()));
+					}
+				}
+			}),
+			
+			AgentVersion { ref agent_name, ref version } => Self::for_each_agent(can_i_use, &Some(agent_name.clone()), |agent|
+			{
+				if let Some((known_version, _version_detail)) = version.find_in(agent.version_details())
+				{
+					selected.insert((agent.agent_name().clone(), known_version.clone()));
+				}
+			}),
+			
+			AgentVersionRange { ref agent_name, ref first, ref last } => Self::for_each_agent(can_i_use, &Some(agent_name.clone()), |agent|
+			{
+				for (known_version, _version_detail) in agent.version_details().iter()
+				{
+					if known_version.major_number().is_some() && known_version >= first && known_version <= last
+					{
+						selected.insert((agent.agent_name().clone(), known_version.clone()));
+					}
+				}
+			}),
+		}
+		
+		selected
+	}
+	
+	#[inline(always)]
+	fn select_query<S: RegionalUsageSource>(can_i_use: &CanIUse, regional_usage_source: &S, query: &str, selected: &mut HashSet<(AgentName, Version)>)
+	{
+		let query: BrowserslistQuery = query.parse().expect("built-in browserslist queries are valid");
+		selected.extend(query.evaluate(can_i_use, regional_usage_source).iter().cloned())
+	}
+	
+	#[inline(always)]
+	fn select_firefox_extended_support_release(can_i_use: &CanIUse, selected: &mut HashSet<(AgentName, Version)>)
+	{
+		const TwoFirefoxReleaseCyclesInWeeks: i64 = 12;
+		
+		if let Some(agent) = AgentName::MozillaFirefox.agent(can_i_use)
+		{
+			let mut extended_support_releases = agent.version_details_for_current_and_older_versions().filter(|&(version, _version_detail)|
+			{
+				match version.major_number()
+				{
+					None => false,
+					Some(major_number) => Self::FirefoxExtendedSupportReleaseMajorVersions.contains(&major_number),
+				}
+			}).rev();
+			
+			if let Some((latest, latest_version_detail)) = extended_support_releases.next()
+			{
+				selected.insert((AgentName::MozillaFirefox, latest.clone()));
+				
+				let is_within_overlap = match latest_version_detail.release_date()
+				{
+					None => true,
+					Some(release_date) => can_i_use.last_updated().signed_duration_since(release_date) < Duration::weeks(TwoFirefoxReleaseCyclesInWeeks),
+				};
+				
+				if is_within_overlap
+				{
+					if let Some((previous, _version_detail)) = extended_support_releases.next()
+					{
+						selected.insert((AgentName::MozillaFirefox, previous.clone()));
+					}
+				}
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn for_each_agent<F: FnMut(&Agent)>(can_i_use: &CanIUse, agent_name: &Option<AgentName>, mut agent_user: F)
+	{
+		match *agent_name
+		{
+			None => for agent_name in can_i_use.known_agent_names()
+			{
+				if let Some(agent) = agent_name.agent(can_i_use)
+				{
+					agent_user(&agent)
+				}
+			},
+			
+			Some(ref agent_name) => if let Some(agent) = agent_name.agent(can_i_use)
+			{
+				agent_user(&agent)
+			},
+		}
+	}
+	
+	#[inline(always)]
+	fn usages<S: RegionalUsageSource>(can_i_use: &CanIUse, regional_usage_source: &S, regional_usages: &Option<RegionalUsages>) -> Vec<(AgentName, Version, UsagePercentage)>
+	{
+		let mut usages = Vec::new();
+		
+		match *regional_usages
+		{
+			None => Self::for_each_agent(can_i_use, &None, |agent|
+			{
+				for (version, _version_detail) in agent.version_details().iter()
+				{
+					if !version.is_invalid_or_unknown()
+					{
+						if let Some(usage) = agent.global_usage(version)
+						{
+							usages.push((agent.agent_name().clone(), version.clone(), usage));
+						}
+					}
+				}
+			}),
+			
+			Some(regional_usages) =>
+			{
+				let regional_usage = match regional_usage_source.regional_usage(regional_usages)
+				{
+					None => return usages,
+					Some(regional_usage) => regional_usage,
+				};
+				for (agent_name, version) in regional_usage.query(can_i_use, |_agent_name, _version, _usage_percentage, _agent_type| true)
+				{
+					if let Some(Some(&Some(usage))) = regional_usage.usage_of_version(agent_name, version)
+					{
+						usages.push((agent_name.clone(), version.clone(), usage));
+					}
+				}
+			}
+		}
+		
+		usages
+	}
+	
+	#[inline(always)]
+	fn tokenize(selector: &str) -> Vec<String>
+	{
+		let mut tokens = Vec::new();
+		
+		for token in selector.split_whitespace()
+		{
+			// Split comparisons written without a space, eg `>5%` or `>=5%`
+			let comparison_length = token.chars().take_while(|character| *character == '>' || *character == '<' || *character == '=').count();
+			if comparison_length != 0 && comparison_length != token.len()
+			{
+				tokens.push(token[..comparison_length].to_owned());
+				tokens.push(token[comparison_length..].to_owned());
+			}
+			else
+			{
+				tokens.push(token.to_owned());
+			}
+		}
+		
+		tokens
+	}
+	
+	#[inline(always)]
+	fn parse_count(count: &str) -> Result<usize, BrowserslistQueryParseError>
+	{
+		count.parse().map_err(|_| BrowserslistQueryParseError::InvalidNumber(count.to_owned()))
+	}
+	
+	#[inline(always)]
+	fn parse_comparison(comparison: &str, selector: &str) -> Result<BrowserslistComparison, BrowserslistQueryParseError>
+	{
+		comparison.parse().map_err(|_| BrowserslistQueryParseError::UnknownQuery(selector.to_owned()))
+	}
+	
+	#[inline(always)]
+	fn parse_usage_percentage(usage_percentage: &str) -> Result<UsagePercentage, BrowserslistQueryParseError>
+	{
+		let invalid = || BrowserslistQueryParseError::InvalidPercentage(usage_percentage.to_owned());
+		
+		if !usage_percentage.ends_with('%')
+		{
+			return Err(invalid());
+		}
+		
+		match usage_percentage[..usage_percentage.len() - 1].parse::<f64>()
+		{
+			Ok(value) if value.is_finite() => Ok(UsagePercentage::new(value)),
+			_ => Err(invalid()),
+		}
+	}
+	
+	#[inline(always)]
+	fn parse_regional_usages(can_i_use_iso_like_code: &str) -> Result<RegionalUsages, BrowserslistQueryParseError>
+	{
+		// browserslist accepts both `alt-as` and `alt-AS`; country codes are upper case
+		let normalized = if can_i_use_iso_like_code.to_ascii_lowercase().starts_with("alt-")
+		{
+			can_i_use_iso_like_code.to_ascii_lowercase()
+		}
+		else
+		{
+			can_i_use_iso_like_code.to_ascii_uppercase()
+		};
+		
+		normalized.parse().map_err(|_| BrowserslistQueryParseError::UnknownRegion(can_i_use_iso_like_code.to_owned()))
+	}
+	
+	#[inline(always)]
+	fn parse_date(date: &str) -> Result<DateTime<Utc>, BrowserslistQueryParseError>
+	{
+		let invalid = || BrowserslistQueryParseError::InvalidDate(date.to_owned());
+		
+		let mut parts = date.split('-');
+		let year = parts.next().ok_or_else(invalid)?.parse::<i32>().map_err(|_| invalid())?;
+		let month = match parts.next()
+		{
+			None => 1,
+			Some(month) => month.parse::<u32>().map_err(|_| invalid())?,
+		};
+		let day = match parts.next()
+		{
+			None => 1,
+			Some(day) => day.parse::<u32>().map_err(|_| invalid())?,
+		};
+		if parts.next().is_some()
+		{
+			return Err(invalid());
+		}
+		
+		match NaiveDate::from_ymd_opt(year, month, day)
+		{
+			None => Err(invalid()),
+			Some(naive_date) => Ok(Utc.from_utc_datetime(&naive_date.and_hms_opt(0, 0, 0).unwrap())),
+		}
+	}
+	
+	/// Maps a browserslist browser name (case insensitive) to an AgentName; accepts caniuse.com identifiers (eg `and_chr`) and browserslist's aliases (eg `ChromeAndroid`).
+	/// Agents caniuse.com added after this crate was written, ie `kaios`, are `AgentName::Unknown`; they select nothing unless the database has them.
+	pub fn parse_browser(browser: &str) -> Result<AgentName, BrowserslistQueryParseError>
+	{
+		use self::AgentName::*;
+		
+		let agent_name = match &browser.to_ascii_lowercase()[..]
+		{
+			"ie" | "explorer" => MicrosoftInternetExplorer,
+			"edge" => MicrosoftEdge,
+			"firefox" | "ff" | "fx" => MozillaFirefox,
+			"chrome" => GoogleChrome,
+			"safari" => AppleSafari,
+			"opera" => Opera,
+			"ios_saf" | "ios" => AppleSafariIOs,
+			"op_mini" | "operamini" => OperaMini,
+			"android" => GoogleAndroidBrowserAndWebComponent,
+			"bb" | "blackberry" => Blackberry,
+			"op_mob" | "operamobile" => OperaMobile,
+			"and_chr" | "chromeandroid" => GoogleChromeAndroid,
+			"and_ff" | "firefoxandroid" => MozillaFirefoxAndroid,
+			"ie_mob" | "explorermobile" => MicrosoftInternetExplorerMobile,
+			"and_uc" | "ucandroid" => UcBrowserAndroid,
+			"samsung" => SamsungBrowserAndroid,
+			"and_qq" | "qqandroid" => QqBrowserAndroid,
+			"baidu" => BaiduBrowserAndroid,
+			"kaios" => Unknown("kaios".to_owned()),
+			
+			_ => return Err(BrowserslistQueryParseError::UnknownBrowser(browser.to_owned())),
+		};
+		
+		Ok(agent_name)
+	}
+}
@@ -0,0 +1,18 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+use super::*;
+use ::std::error::Error;
+use ::std::io;
+use ::std::path::PathBuf;
+
+
+include!("BrowserslistClause.rs");
+include!("BrowserslistCombinator.rs");
+include!("BrowserslistComparison.rs");
+include!("BrowserslistConfiguration.rs");
+include!("BrowserslistConfigurationError.rs");
+include!("BrowserslistQuery.rs");
+include!("BrowserslistQueryParseError.rs");
+include!("BrowserslistSelector.rs");
@@ -0,0 +1,38 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// What a block (`{ ... }`) of a stylesheet contains.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum CssBlock
+{
+	/// Rules, eg the top level of a stylesheet or `@media`.
+	Rules,
+	
+	/// Declarations, eg a style rule or `@font-face`.
+	Declarations,
+}
+
+impl CssBlock
+{
+	/// The block following an at-rule, eg `media` (without the leading `@`).
+	#[inline(always)]
+	fn of_at_rule(at_rule: &str) -> Self
+	{
+		// Vendor prefixed, eg `-webkit-keyframes`.
+		let at_rule = if at_rule.starts_with('-')
+		{
+			at_rule.splitn(3, '-').nth(2).unwrap_or(at_rule)
+		}
+		else
+		{
+			at_rule
+		};
+		
+		match at_rule
+		{
+			"media" | "supports" | "document" | "layer" | "container" | "scope" | "starting-style" | "keyframes" => CssBlock::Rules,
+			_ => CssBlock::Declarations,
+		}
+	}
+}
@@ -0,0 +1,41 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Syntax in a stylesheet using a feature that some of the targeted agents and versions do not support.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CssCompatibilityIssue
+{
+	/// Where the syntax is.
+	pub location: CssLocation,
+	
+	/// The syntax.
+	pub syntax: CssSyntax,
+	
+	/// The feature the syntax uses.
+	pub feature_name: FeatureName,
+	
+	/// The targeted agents and versions which do not support the feature.
+	pub unsupported: BTreeSet<(AgentName, Version)>,
+}
+
+impl Display for CssCompatibilityIssue
+{
+	/// Displays as, eg, `12:5: value 'grid' of 'display' (css-grid) is not supported by ie 11, safari 10.1`.
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "{}: {} ({}) is not supported by ", self.location, self.syntax, self.feature_name.0)?;
+		let mut after_first = false;
+		for (agent_name, version) in self.unsupported.iter()
+		{
+			if after_first
+			{
+				write!(f, ", ")?;
+			}
+			write!(f, "{} {}", agent_name.can_i_use_identifier(), version)?;
+			after_first = true;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,75 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Checks stylesheets for properties, values, at-rules and selectors that the targeted agents and versions do not support, in the manner of doiuse.
+#[derive(Debug, Clone)]
+pub struct CssCompatibilityLinter<'a>
+{
+	can_i_use: &'a CanIUse,
+	targets: &'a AgentNameAndVersionSet,
+	policy: SupportPolicy,
+}
+
+impl<'a> CssCompatibilityLinter<'a>
+{
+	/// Creates a new instance; `policy` decides whether almost supported, supported using a polyfill and disabled by default count as support.
+	/// Support requiring a prefix never counts, whatever `policy.accept_prefixed`, as the syntax checked is unprefixed.
+	#[inline(always)]
+	pub fn new(can_i_use: &'a CanIUse, targets: &'a AgentNameAndVersionSet, policy: SupportPolicy) -> Self
+	{
+		Self
+		{
+			can_i_use,
+			targets,
+			policy,
+		}
+	}
+	
+	/// Checks a stylesheet, returning issues in order of location.
+	/// Syntax is matched to features with `CssSyntax::scan()` and `CssSyntax::feature_names()`, and each feature is evaluated for each target with `Feature::implementation()`.
+	/// Features not in the database, and targets for which support is not known, are not reported.
+	#[inline(always)]
+	pub fn lint(&self, css: &str) -> Vec<CssCompatibilityIssue>
+	{
+		let policy = SupportPolicy { accept_prefixed: false, .. self.policy };
+		let mut issues = Vec::new();
+		
+		for (location, syntax) in CssSyntax::scan(css)
+		{
+			for feature_name in syntax.feature_names()
+			{
+				if let Some(feature) = feature_name.feature(self.can_i_use)
+				{
+					let unsupported: BTreeSet<(AgentName, Version)> = self.targets.iter().filter(|(agent_name, version)| match feature.implementation(agent_name, version)
+					{
+						Some(Some(support)) => support.maturity() != SupportMaturity::SupportUnknown && !support.is_supported(&policy),
+						_ => false,
+					}).cloned().collect();
+					
+					if !unsupported.is_empty()
+					{
+						issues.push(CssCompatibilityIssue
+						{
+							location,
+							syntax: syntax.clone(),
+							feature_name: feature_name.clone(),
+							unsupported,
+						});
+					}
+				}
+			}
+		}
+		
+		issues
+	}
+	
+	/// Checks a stylesheet file; see `lint()`.
+	#[inline(always)]
+	pub fn lint_file<P: AsRef<Path>>(&self, css_file_path: P) -> Result<Vec<CssCompatibilityIssue>, io::Error>
+	{
+		let mut css = String::new();
+		File::open(css_file_path)?.read_to_string(&mut css)?;
+		Ok(self.lint(&css))
+	}
+}
@@ -0,0 +1,301 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// A tolerant scanner of stylesheets for syntax that may need support from agents; see `CssSyntax::scan()`.
+struct CssScanner
+{
+	characters: Vec<(char, CssLocation)>,
+	syntax: Vec<(CssLocation, CssSyntax)>,
+}
+
+impl CssScanner
+{
+	#[inline(always)]
+	fn scan(css: &str) -> Vec<(CssLocation, CssSyntax)>
+	{
+		let mut scanner = CssScanner
+		{
+			characters: Self::blank_comments_strings_and_urls(css),
+			syntax: Vec::new(),
+		};
+		scanner.parse();
+		scanner.syntax
+	}
+	
+	/// Replaces comments, strings and the insides of unquoted `url()`s with spaces, so that their content, eg the `;` of a data URL, is not mistaken for syntax.
+	fn blank_comments_strings_and_urls(css: &str) -> Vec<(char, CssLocation)>
+	{
+		let characters: Vec<char> = css.chars().collect();
+		let mut blanked = Vec::with_capacity(characters.len());
+		
+		let mut location = CssLocation
+		{
+			line: 1,
+			column: 1,
+		};
+		
+		let mut in_comment = false;
+		let mut comment_start = 0;
+		let mut in_string = None;
+		let mut in_url = false;
+		let mut escaped = false;
+		
+		for (index, &character) in characters.iter().enumerate()
+		{
+			let mut blank = true;
+			
+			if in_comment
+			{
+				if character == '/' && index >= comment_start + 3 && characters[index - 1] == '*'
+				{
+					in_comment = false;
+				}
+			}
+			else if let Some(quote) = in_string
+			{
+				if escaped
+				{
+					escaped = false;
+				}
+				else if character == '\\'
+				{
+					escaped = true;
+				}
+				else if character == quote || character == '\n'
+				{
+					in_string = None;
+				}
+			}
+			else if in_url
+			{
+				if character == ')'
+				{
+					in_url = false;
+					blank = false;
+				}
+			}
+			else
+			{
+				match character
+				{
+					'/' if characters.get(index + 1) == Some(&'*') =>
+					{
+						in_comment = true;
+						comment_start = index;
+					}
+					'"' | '\'' => in_string = Some(character),
+					'(' =>
+					{
+						in_url = Self::is_url_function(&characters, index);
+						blank = false;
+					}
+					_ => blank = false,
+				}
+			}
+			
+			blanked.push((if blank && character != '\n' { ' ' } else { character }, location));
+			
+			if character == '\n'
+			{
+				location.line += 1;
+				location.column = 1;
+			}
+			else
+			{
+				location.column += 1;
+			}
+		}
+		
+		blanked
+	}
+	
+	/// Is the `(` at `index` that of an unquoted `url()`?
+	#[inline(always)]
+	fn is_url_function(characters: &[char], index: usize) -> bool
+	{
+		if index < 3
+		{
+			return false;
+		}
+		
+		let name: String = characters[index - 3 .. index].iter().collect();
+		if !name.eq_ignore_ascii_case("url") || (index > 3 && Self::is_name_character(characters[index - 4]))
+		{
+			return false;
+		}
+		
+		let is_quoted = characters[index + 1 ..].iter().find(|character| !character.is_whitespace()).map(|&character| character == '"' || character == '\'').unwrap_or(false);
+		!is_quoted
+	}
+	
+	fn parse(&mut self)
+	{
+		let mut blocks = vec![CssBlock::Rules];
+		let mut start = 0;
+		
+		for index in 0 .. self.characters.len()
+		{
+			let block = *blocks.last().unwrap();
+			
+			match self.characters[index].0
+			{
+				'{' =>
+				{
+					let nested_block = self.prelude(start, index);
+					blocks.push(nested_block);
+					start = index + 1;
+				}
+				
+				';' =>
+				{
+					if block == CssBlock::Declarations
+					{
+						self.declaration(start, index);
+					}
+					else
+					{
+						self.prelude(start, index);
+					}
+					start = index + 1;
+				}
+				
+				'}' =>
+				{
+					if block == CssBlock::Declarations
+					{
+						self.declaration(start, index);
+					}
+					if blocks.len() > 1
+					{
+						blocks.pop();
+					}
+					start = index + 1;
+				}
+				
+				_ => (),
+			}
+		}
+	}
+	
+	/// The prelude of a rule, ie an at-rule or a selector list, ending at `end`; returns what the rule's block, if any, contains.
+	fn prelude(&mut self, start: usize, end: usize) -> CssBlock
+	{
+		let start = match self.skip_whitespace(start, end)
+		{
+			None => return CssBlock::Declarations,
+			Some(start) => start,
+		};
+		
+		let (character, location) = self.characters[start];
+		if character == '@'
+		{
+			let at_rule = self.name(start + 1, end).to_lowercase();
+			let block = CssBlock::of_at_rule(&at_rule);
+			if !at_rule.is_empty()
+			{
+				self.syntax.push((location, CssSyntax::AtRule(at_rule)));
+			}
+			return block;
+		}
+		
+		let mut index = start;
+		while index < end
+		{
+			let (character, location) = self.characters[index];
+			if character == ':'
+			{
+				let colons = if index + 1 < end && self.characters[index + 1].0 == ':' { 2 } else { 1 };
+				let name = self.name(index + colons, end);
+				index += colons + name.chars().count();
+				if !name.is_empty()
+				{
+					let selector = format!("{}{}", if colons == 2 { "::" } else { ":" }, name.to_lowercase());
+					self.syntax.push((location, CssSyntax::Selector(selector)));
+				}
+			}
+			else
+			{
+				index += 1;
+			}
+		}
+		
+		CssBlock::Declarations
+	}
+	
+	/// A declaration, eg `display: flex !important`, ending at `end`.
+	fn declaration(&mut self, start: usize, end: usize)
+	{
+		let start = match self.skip_whitespace(start, end)
+		{
+			None => return,
+			Some(start) => start,
+		};
+		
+		let colon = match (start .. end).find(|&index| self.characters[index].0 == ':')
+		{
+			None => return,
+			Some(colon) => colon,
+		};
+		
+		let property: String = self.characters[start .. colon].iter().map(|&(character, _)| character).collect::<String>().trim().to_lowercase();
+		if property.is_empty()
+		{
+			return;
+		}
+		self.syntax.push((self.characters[start].1, CssSyntax::Property(property.clone())));
+		
+		if property.starts_with("--")
+		{
+			return;
+		}
+		
+		let mut index = colon + 1;
+		while index < end
+		{
+			let (character, location) = self.characters[index];
+			let previous_character = self.characters[index - 1].0;
+			
+			if Self::is_name_start_character(character) && !Self::is_name_character(previous_character) && previous_character != '!' && previous_character != '#' && previous_character != '.'
+			{
+				let value = self.name(index, end);
+				index += value.chars().count();
+				
+				let is_number = value.starts_with('-') && value[1..].starts_with(|character: char| character.is_ascii_digit() || character == '.');
+				if !is_number && value != "-"
+				{
+					self.syntax.push((location, CssSyntax::Value { property: property.clone(), value: value.to_lowercase() }));
+				}
+			}
+			else
+			{
+				index += 1;
+			}
+		}
+	}
+	
+	/// The name, ie identifier, starting at `start`; empty if there is none.
+	#[inline(always)]
+	fn name(&self, start: usize, end: usize) -> String
+	{
+		self.characters[start .. end].iter().map(|&(character, _)| character).take_while(|&character| Self::is_name_character(character)).collect()
+	}
+	
+	#[inline(always)]
+	fn skip_whitespace(&self, start: usize, end: usize) -> Option<usize>
+	{
+		(start .. end).find(|&index| !self.characters[index].0.is_whitespace())
+	}
+	
+	#[inline(always)]
+	fn is_name_start_character(character: char) -> bool
+	{
+		character.is_ascii_alphabetic() || character == '-' || character == '_' || !character.is_ascii()
+	}
+	
+	#[inline(always)]
+	fn is_name_character(character: char) -> bool
+	{
+		Self::is_name_start_character(character) || character.is_ascii_digit()
+	}
+}
@@ -0,0 +1,81 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// CSS syntax that may need support from agents.
+/// Names are lower case.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum CssSyntax
+{
+	/// A property, eg `transform` or the custom property `--main-color`.
+	Property(String),
+	
+	/// A value of a property, either a keyword or a function (without parentheses), eg `flex` for `display: flex` or `linear-gradient` for `background-image: linear-gradient(red, blue)`.
+	Value
+	{
+		/// Property.
+		property: String,
+		
+		/// Keyword or function.
+		value: String,
+	},
+	
+	/// An at-rule, without the leading `@`, eg `supports`.
+	AtRule(String),
+	
+	/// A pseudo-class or pseudo-element, with leading colons, eg `:focus-visible`.
+	Selector(String),
+}
+
+impl Display for CssSyntax
+{
+	/// Displays as, eg, `property 'transform'`, `value 'flex' of 'display'`, `at-rule '@supports'` or `selector ':focus-visible'`.
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::CssSyntax::*;
+		
+		match *self
+		{
+			Property(ref property) => write!(f, "property '{}'", property),
+			Value { ref property, ref value } => write!(f, "value '{}' of '{}'", value, property),
+			AtRule(ref at_rule) => write!(f, "at-rule '@{}'", at_rule),
+			Selector(ref selector) => write!(f, "selector '{}'", selector),
+		}
+	}
+}
+
+impl CssSyntax
+{
+	/// Scans a stylesheet for syntax that may need support from agents, in order of location.
+	/// Comments, strings and `url()`s are skipped, as are the values of custom properties.
+	/// The scan is tolerant: it never fails, even for invalid CSS.
+	#[inline(always)]
+	pub fn scan(css: &str) -> Vec<(CssLocation, CssSyntax)>
+	{
+		CssScanner::scan(css)
+	}
+	
+	/// The names of the caniuse.com features covering this syntax, eg `css-grid` for `display: grid`, `css-focus-visible` for `:focus-visible` and `css-featurequeries` for `@supports`; see `CssFeatureCoverage`.
+	/// Custom properties are covered by `css-variables`.
+	#[inline(always)]
+	pub fn feature_names(&self) -> Vec<FeatureName>
+	{
+		use self::CssSyntax::*;
+		
+		match *self
+		{
+			Property(ref property) => if property.starts_with("--")
+			{
+				vec![FeatureName::from("css-variables")]
+			}
+			else
+			{
+				CssFeatureCoverage::feature_names_covering_property(property)
+			},
+			Value { ref property, ref value } => CssFeatureCoverage::feature_names_covering_value(property, value),
+			AtRule(ref at_rule) => CssFeatureCoverage::feature_names_covering_at_rule(at_rule),
+			Selector(ref selector) => CssFeatureCoverage::feature_names_covering_selector(selector),
+		}
+	}
+}
@@ -0,0 +1,17 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Versions of an agent known to both databases that were added or removed.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct AgentVersionsDiff
+{
+	/// Agent.
+	pub agent_name: AgentName,
+	
+	/// Versions only in the new database, in order.
+	pub added_versions: Vec<Version>,
+	
+	/// Versions only in the old database, in order.
+	pub removed_versions: Vec<Version>,
+}
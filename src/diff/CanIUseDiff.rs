@@ -0,0 +1,226 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+impl CanIUse
+{
+	/// What changed between an `old` and a `new` database, eg when updating the caniuse.com data this crate embeds.
+	#[inline(always)]
+	pub fn diff(old: &CanIUse, new: &CanIUse) -> CanIUseDiff
+	{
+		CanIUseDiff::diff(old, new)
+	}
+}
+
+/// What changed between an old and a new caniuse.com database; obtain with `CanIUse::diff()`.
+/// Everything is in order (agents by `AgentName`, features by name, versions by `Version`) so that rendered output is reproducible.
+/// Serializes to JSON (eg with `::serde_json::to_string()`) for machine consumption; use `write_markdown()` for humans.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct CanIUseDiff
+{
+	/// When each database was last updated.
+	pub last_updated: Change<DateTime<Utc>>,
+	
+	/// Agents only in the new database.
+	pub added_agents: Vec<AgentName>,
+	
+	/// Agents only in the old database.
+	pub removed_agents: Vec<AgentName>,
+	
+	/// Versions added or removed for agents in both databases.
+	pub agent_versions: Vec<AgentVersionsDiff>,
+	
+	/// Features only in the new database.
+	pub added_features: Vec<FeatureName>,
+	
+	/// Features only in the old database.
+	pub removed_features: Vec<FeatureName>,
+	
+	/// Changes to features in both databases.
+	pub changed_features: Vec<FeatureDiff>,
+}
+
+impl CanIUseDiff
+{
+	#[inline(always)]
+	fn diff(old: &CanIUse, new: &CanIUse) -> Self
+	{
+		let (added_agents, removed_agents, common_agents) = Self::added_removed_and_common(&old.agents, &new.agents);
+		
+		let agent_versions = common_agents.into_iter().filter_map(|agent_name|
+		{
+			let old_version_list = &old.agents[agent_name].version_list;
+			let new_version_list = &new.agents[agent_name].version_list;
+			
+			let added_versions: Vec<Version> = new_version_list.keys().filter(|version| !old_version_list.contains_key(version)).cloned().collect();
+			let removed_versions: Vec<Version> = old_version_list.keys().filter(|version| !new_version_list.contains_key(version)).cloned().collect();
+			
+			if added_versions.is_empty() && removed_versions.is_empty()
+			{
+				None
+			}
+			else
+			{
+				Some(AgentVersionsDiff
+				{
+					agent_name: agent_name.clone(),
+					added_versions,
+					removed_versions,
+				})
+			}
+		}).collect();
+		
+		let (added_features, removed_features, common_features) = Self::added_removed_and_common(&old.features, &new.features);
+		
+		let changed_features = common_features.into_iter().filter_map(|feature_name| FeatureDiff::diff(old, new, feature_name, &old.features[feature_name], &new.features[feature_name])).collect();
+		
+		CanIUseDiff
+		{
+			last_updated: Change
+			{
+				old: old.updated,
+				new: new.updated,
+			},
+			added_agents,
+			removed_agents,
+			agent_versions,
+			added_features,
+			removed_features,
+			changed_features,
+		}
+	}
+	
+	/// Is there no difference (apart from when the databases were last updated)?
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.added_agents.is_empty() && self.removed_agents.is_empty() && self.agent_versions.is_empty() && self.added_features.is_empty() && self.removed_features.is_empty() && self.changed_features.is_empty()
+	}
+	
+	/// Support changes for `feature_name` that were supported according to `policy` in the old database but not in the new one.
+	/// Useful to fail a CI build when a feature depended upon regresses; a removed feature is not a regression, so check `removed_features`, too.
+	#[inline(always)]
+	pub fn regressions(&self, feature_name: &FeatureName, policy: &SupportPolicy) -> Vec<&SupportChange>
+	{
+		match self.changed_features.iter().find(|feature_diff| &feature_diff.feature_name == feature_name)
+		{
+			None => vec![],
+			Some(feature_diff) => feature_diff.support_changes.iter().filter(|support_change| support_change.is_regression(policy)).collect(),
+		}
+	}
+	
+	/// Writes this diff as Markdown, eg to attach to a pull request.
+	#[inline(always)]
+	pub fn write_markdown<W: Write>(&self, writer: &mut W) -> io::Result<()>
+	{
+		writeln!(writer, "# caniuse.com database changes")?;
+		writeln!(writer)?;
+		writeln!(writer, "Last updated {} (was {}).", self.last_updated.new.format("%Y-%m-%d %H:%M:%S UTC"), self.last_updated.old.format("%Y-%m-%d %H:%M:%S UTC"))?;
+		
+		if self.is_empty()
+		{
+			writeln!(writer)?;
+			return writeln!(writer, "No changes.");
+		}
+		
+		if !self.added_agents.is_empty() || !self.removed_agents.is_empty() || !self.agent_versions.is_empty()
+		{
+			writeln!(writer)?;
+			writeln!(writer, "## Agents")?;
+			writeln!(writer)?;
+			for agent_name in self.added_agents.iter()
+			{
+				writeln!(writer, "* Added `{}`", agent_name.can_i_use_identifier())?;
+			}
+			for agent_name in self.removed_agents.iter()
+			{
+				writeln!(writer, "* Removed `{}`", agent_name.can_i_use_identifier())?;
+			}
+			for agent_versions_diff in self.agent_versions.iter()
+			{
+				if !agent_versions_diff.added_versions.is_empty()
+				{
+					writeln!(writer, "* Added `{}` versions {}", agent_versions_diff.agent_name.can_i_use_identifier(), Self::markdown_list(&agent_versions_diff.added_versions))?;
+				}
+				if !agent_versions_diff.removed_versions.is_empty()
+				{
+					writeln!(writer, "* Removed `{}` versions {}", agent_versions_diff.agent_name.can_i_use_identifier(), Self::markdown_list(&agent_versions_diff.removed_versions))?;
+				}
+			}
+		}
+		
+		if !self.added_features.is_empty() || !self.removed_features.is_empty()
+		{
+			writeln!(writer)?;
+			writeln!(writer, "## Features")?;
+			writeln!(writer)?;
+			for feature_name in self.added_features.iter()
+			{
+				writeln!(writer, "* Added `{}`", feature_name.0)?;
+			}
+			for feature_name in self.removed_features.iter()
+			{
+				writeln!(writer, "* Removed `{}`", feature_name.0)?;
+			}
+		}
+		
+		for feature_diff in self.changed_features.iter()
+		{
+			writeln!(writer)?;
+			writeln!(writer, "### `{}`", feature_diff.feature_name.0)?;
+			writeln!(writer)?;
+			if let Some(ref status) = feature_diff.status
+			{
+				writeln!(writer, "* Status `{}` → `{}`", status.old.can_i_use_identifier(), status.new.can_i_use_identifier())?;
+			}
+			if let Some(ref supported_by_default_usage) = feature_diff.supported_by_default_usage
+			{
+				writeln!(writer, "* Supported usage {} → {}", supported_by_default_usage.old, supported_by_default_usage.new)?;
+			}
+			for support_change in feature_diff.support_changes.iter()
+			{
+				writeln!(writer, "* `{}` {}: {} → {}", support_change.agent_name.can_i_use_identifier(), support_change.version, Self::markdown_support_state(&support_change.old), Self::markdown_support_state(&support_change.new))?;
+			}
+		}
+		
+		Ok(())
+	}
+	
+	/// Writes this diff as pretty-printed JSON.
+	#[inline(always)]
+	pub fn write_json<W: Write>(&self, writer: &mut W) -> io::Result<()>
+	{
+		::serde_json::to_writer_pretty(writer, self).map_err(io::Error::from)
+	}
+	
+	#[inline(always)]
+	fn added_removed_and_common<'a, K: Clone + Eq + Hash + Ord, V>(old: &'a HashMap<K, V>, new: &'a HashMap<K, V>) -> (Vec<K>, Vec<K>, Vec<&'a K>)
+	{
+		let mut added: Vec<K> = new.keys().filter(|key| !old.contains_key(key)).cloned().collect();
+		added.sort();
+		
+		let mut removed: Vec<K> = old.keys().filter(|key| !new.contains_key(key)).cloned().collect();
+		removed.sort();
+		
+		let mut common: Vec<&K> = old.keys().filter(|key| new.contains_key(key)).collect();
+		common.sort();
+		
+		(added, removed, common)
+	}
+	
+	#[inline(always)]
+	fn markdown_list(versions: &[Version]) -> String
+	{
+		versions.iter().map(|version| format!("`{}`", version)).collect::<Vec<_>>().join(", ")
+	}
+	
+	#[inline(always)]
+	fn markdown_support_state(support_state: &Option<SupportState>) -> String
+	{
+		match *support_state
+		{
+			None => "none".to_owned(),
+			Some(ref support_state) => format!("`{}`", support_state),
+		}
+	}
+}
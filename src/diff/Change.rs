@@ -0,0 +1,35 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// A value that differs between an old and a new database.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Change<T>
+{
+	/// Value in the old database.
+	pub old: T,
+	
+	/// Value in the new database.
+	pub new: T,
+}
+
+impl<T: PartialEq> Change<T>
+{
+	/// Returns None if `old` and `new` are equal.
+	#[inline(always)]
+	pub fn if_changed(old: T, new: T) -> Option<Self>
+	{
+		if old == new
+		{
+			None
+		}
+		else
+		{
+			Some(Change
+			{
+				old,
+				new,
+			})
+		}
+	}
+}
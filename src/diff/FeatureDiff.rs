@@ -0,0 +1,77 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Changes to a feature present in both databases.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct FeatureDiff
+{
+	/// Feature.
+	pub feature_name: FeatureName,
+	
+	/// Status transition, eg from `wd` to `cr`, if any.
+	pub status: Option<Change<Status>>,
+	
+	/// Change in the usage of agents where the feature is `SupportMaturity::SupportedByDefault` (`usage_perc_y`), if any.
+	pub supported_by_default_usage: Option<Change<UsagePercentage>>,
+	
+	/// Changes in support, by agent then version, for versions of agents known to both databases.
+	pub support_changes: Vec<SupportChange>,
+}
+
+impl FeatureDiff
+{
+	#[inline(always)]
+	fn diff(old: &CanIUse, new: &CanIUse, feature_name: &FeatureName, old_feature_detail: &FeatureDetail, new_feature_detail: &FeatureDetail) -> Option<Self>
+	{
+		let mut agent_names: Vec<&AgentName> = old_feature_detail.implementations_by_agents.keys().chain(new_feature_detail.implementations_by_agents.keys()).collect();
+		agent_names.sort();
+		agent_names.dedup();
+		
+		let mut support_changes = Vec::new();
+		for agent_name in agent_names
+		{
+			let (old_agent_detail, new_agent_detail) = match (old.agents.get(agent_name), new.agents.get(agent_name))
+			{
+				(Some(old_agent_detail), Some(new_agent_detail)) => (old_agent_detail, new_agent_detail),
+				_ => continue,
+			};
+			
+			let old_implementations = old_feature_detail.implementations_by_agents.get(agent_name);
+			let new_implementations = new_feature_detail.implementations_by_agents.get(agent_name);
+			
+			for version in old_agent_detail.version_list.keys().filter(|version| new_agent_detail.version_list.contains_key(version))
+			{
+				let old_support = old_implementations.and_then(|implementations| implementations.get(version)).map(|support_detail| SupportState::new(support_detail, old_feature_detail));
+				let new_support = new_implementations.and_then(|implementations| implementations.get(version)).map(|support_detail| SupportState::new(support_detail, new_feature_detail));
+				if old_support != new_support
+				{
+					support_changes.push(SupportChange
+					{
+						agent_name: agent_name.clone(),
+						version: version.clone(),
+						old: old_support,
+						new: new_support,
+					});
+				}
+			}
+		}
+		
+		let feature_diff = FeatureDiff
+		{
+			feature_name: feature_name.clone(),
+			status: Change::if_changed(old_feature_detail.status.clone(), new_feature_detail.status.clone()),
+			supported_by_default_usage: Change::if_changed(old_feature_detail.supported_by_default_usage, new_feature_detail.supported_by_default_usage),
+			support_changes,
+		};
+		
+		if feature_diff.status.is_none() && feature_diff.supported_by_default_usage.is_none() && feature_diff.support_changes.is_empty()
+		{
+			None
+		}
+		else
+		{
+			Some(feature_diff)
+		}
+	}
+}
@@ -0,0 +1,33 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// A change in the support of a version of an agent for a feature.
+/// `old` or `new` is None if the feature had no support data for that version of the agent in that database.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct SupportChange
+{
+	/// Agent.
+	pub agent_name: AgentName,
+	
+	/// Version of the agent.
+	pub version: Version,
+	
+	/// Support in the old database.
+	pub old: Option<SupportState>,
+	
+	/// Support in the new database.
+	pub new: Option<SupportState>,
+}
+
+impl SupportChange
+{
+	/// Was this version of the agent supported according to `policy` in the old database but not in the new one?
+	#[inline(always)]
+	pub fn is_regression(&self, policy: &SupportPolicy) -> bool
+	{
+		let was_supported = self.old.as_ref().map(|old| old.is_supported(policy)).unwrap_or(false);
+		let is_supported = self.new.as_ref().map(|new| new.is_supported(policy)).unwrap_or(false);
+		was_supported && !is_supported
+	}
+}
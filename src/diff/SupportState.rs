@@ -0,0 +1,80 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// The support of a version of an agent for a feature, with note numbers resolved to note text so that it can be compared across databases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SupportState
+{
+	/// How mature is support?
+	pub maturity: SupportMaturity,
+	
+	/// Does support require a prefix?
+	pub requires_prefix: bool,
+	
+	/// Is support behind a flag or some other mechanism that isn't normally enabled in a default install?
+	pub disabled_by_default: bool,
+	
+	/// Note text, in note number order.
+	pub notes: Vec<String>,
+}
+
+impl Serialize for SupportState
+{
+	/// Serialize using Serde; `maturity` is serialized as its caniuse.com identifier, eg `y`.
+	#[inline(always)]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		let mut state = serializer.serialize_struct("SupportState", 4)?;
+		state.serialize_field("maturity", self.maturity.can_i_use_identifier())?;
+		state.serialize_field("requires_prefix", &self.requires_prefix)?;
+		state.serialize_field("disabled_by_default", &self.disabled_by_default)?;
+		state.serialize_field("notes", &self.notes)?;
+		state.end()
+	}
+}
+
+impl Display for SupportState
+{
+	/// Displays like a caniuse.com `stats` entry with notes quoted, eg `a x "Only supports the old syntax."`.
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "{}", self.maturity.can_i_use_identifier())?;
+		if self.requires_prefix
+		{
+			write!(f, " x")?;
+		}
+		if self.disabled_by_default
+		{
+			write!(f, " d")?;
+		}
+		for note in self.notes.iter()
+		{
+			write!(f, " {:?}", note)?;
+		}
+		Ok(())
+	}
+}
+
+impl SupportState
+{
+	#[inline(always)]
+	fn new(support_detail: &SupportDetail, feature_detail: &FeatureDetail) -> Self
+	{
+		SupportState
+		{
+			maturity: support_detail.maturity(),
+			requires_prefix: support_detail.requires_prefix(),
+			disabled_by_default: support_detail.disabled_by_default(),
+			notes: support_detail.notes(feature_detail).into_iter().map(|(_, note)| note.to_owned()).collect(),
+		}
+	}
+	
+	/// Does this support count as supported according to `policy`?
+	#[inline(always)]
+	pub fn is_supported(&self, policy: &SupportPolicy) -> bool
+	{
+		policy.supports_maturity(self.maturity, self.requires_prefix, self.disabled_by_default)
+	}
+}
@@ -0,0 +1,13 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+use super::*;
+
+
+include!("AgentVersionsDiff.rs");
+include!("CanIUseDiff.rs");
+include!("Change.rs");
+include!("FeatureDiff.rs");
+include!("SupportChange.rs");
+include!("SupportState.rs");
@@ -15,6 +15,7 @@
 //!
 //! ```
 //! extern crate caniuse_serde;
+//! # #[cfg(feature = "embedded-database")]
 //! use ::caniuse_serde::{EmbeddedCanIUseDatabase, AgentName, FeatureName};
 //! ```
 //!
@@ -22,24 +23,220 @@
 //! ### To look up an agent's details
 //!
 //! ```
-//! let agent = AgentName::MozillaFirefox.agent(EmbeddedCanIUseDatabase).unwrap();
+//! # extern crate caniuse_serde;
+//! # use ::caniuse_serde::*;
+//! # #[cfg(feature = "embedded-database")]
+//! # {
+//! let agent = AgentName::MozillaFirefox.agent(&EmbeddedCanIUseDatabase).unwrap();
+//! # }
 //! ```
 //!
 //!
 //! ### To look up a feature's details
 //!
 //! ```
-//! let feature = "transform3d".into().feature(EmbeddedCanIUseDatabase).unwrap();
+//! # extern crate caniuse_serde;
+//! # use ::caniuse_serde::*;
+//! # #[cfg(feature = "embedded-database")]
+//! # {
+//! let feature_name = FeatureName::from("transform3d");
+//! let feature = feature_name.feature(&EmbeddedCanIUseDatabase).unwrap();
+//! # }
+//! ```
+//!
+//!
+//! ### To load a database from a checkout of the caniuse.com git repository pinned to an exact commit
+//!
+//! ```no_run
+//! # extern crate caniuse_serde;
+//! # use ::caniuse_serde::*;
+//! # use ::caniuse_serde::regional_usage::*;
+//! let can_i_use = CanIUse::from_caniuse_repository("lib/caniuse").unwrap();
+//! let regional_usages = RegionalUsageRegistry::from_caniuse_repository("lib/caniuse").unwrap();
+//! ```
+//!
+//!
+//! ### To load a database strictly, eg in a data pipeline that must know when caniuse.com adds a new agent or category
+//!
+//! ```no_run
+//! # extern crate caniuse_serde;
+//! # use ::caniuse_serde::*;
+//! let can_i_use = CanIUse::from_path_with_options("lib/caniuse/fulldata-json/data-2.0.json", &LoadOptions::Strict).unwrap();
+//! ```
+//!
+//!
+//! ### To write a (perhaps filtered) database back out as `data-2.0.json`
+//!
+//! ```
+//! # extern crate caniuse_serde;
+//! # use ::caniuse_serde::*;
+//! # extern crate serde_json;
+//! # #[cfg(feature = "embedded-database")]
+//! # {
+//! let json = ::serde_json::to_string(&*EmbeddedCanIUseDatabase).unwrap();
+//! # }
+//! ```
+//!
+//!
+//! ### To find the agent and version of a HTTP `User-Agent` header
+//!
+//! ```
+//! # extern crate caniuse_serde;
+//! # use ::caniuse_serde::*;
+//! # #[cfg(feature = "embedded-database")]
+//! # {
+//! let resolution = EmbeddedCanIUseDatabase.resolve_user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/62.0.3202.94 Safari/537.36");
+//! # }
+//! ```
+//!
+//!
+//! ### To decide at request time whether a HTTP `User-Agent` header supports a feature
+//!
+//! ```
+//! # extern crate caniuse_serde;
+//! # use ::caniuse_serde::*;
+//! # #[cfg(feature = "embedded-database")]
+//! # {
+//! # let user_agent = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/62.0.3202.94 Safari/537.36";
+//! let support = EmbeddedCanIUseDatabase.supports(user_agent, &"es6-module".into(), &SupportPolicy::Strict);
+//! # }
+//! ```
+//!
+//!
+//! ### To search for a feature, tolerant of typos
+//!
+//! ```
+//! # extern crate caniuse_serde;
+//! # use ::caniuse_serde::*;
+//! # #[cfg(feature = "embedded-database")]
+//! # {
+//! let (feature_name, score) = EmbeddedCanIUseDatabase.search("flexbox gap").remove(0);
+//! # }
+//! ```
+//!
+//!
+//! ### To find which vendor prefixes CSS features need, eg to autoprefix CSS
+//!
+//! ```
+//! # extern crate caniuse_serde;
+//! # use ::caniuse_serde::*;
+//! # use ::caniuse_serde::regional_usage::*;
+//! # use ::caniuse_serde::prefixes::*;
+//! # #[cfg(all(feature = "embedded-database", feature = "embedded-world-wide"))]
+//! # {
+//! # let choices = AgentNameAndVersionSet::browserslist(&EmbeddedCanIUseDatabase, &EmbeddedRegionalUsages, "defaults").unwrap();
+//! let prefix_requirements = choices.prefix_requirements(&EmbeddedCanIUseDatabase, &["flexbox".into(), "css-sticky".into()]);
+//! let properties = CssFeatureCoverage::of(&"flexbox".into()).unwrap().properties;
+//! # }
+//! ```
+//!
+//!
+//! ### To check a stylesheet for CSS that targeted agents and versions do not support, eg `display: grid` or `:focus-visible`
+//!
+//! ```no_run
+//! # extern crate caniuse_serde;
+//! # use ::caniuse_serde::*;
+//! # use ::caniuse_serde::regional_usage::*;
+//! # use ::caniuse_serde::css_compatibility::*;
+//! # #[cfg(all(feature = "embedded-database", feature = "embedded-world-wide"))]
+//! # {
+//! # let choices = AgentNameAndVersionSet::browserslist(&EmbeddedCanIUseDatabase, &EmbeddedRegionalUsages, "defaults").unwrap();
+//! let issues = CssCompatibilityLinter::new(&EmbeddedCanIUseDatabase, &choices, SupportPolicy::Strict).lint_file("style.css").unwrap();
+//! # }
+//! ```
+//!
+//!
+//! ### To render targets for Babel, SWC, esbuild or browserslist, eg `chrome58,edge16,firefox57,safari11`
+//!
+//! ```
+//! # extern crate caniuse_serde;
+//! # use ::caniuse_serde::*;
+//! # use ::caniuse_serde::regional_usage::*;
+//! # #[cfg(all(feature = "embedded-database", feature = "embedded-world-wide"))]
+//! # {
+//! let minimum_versions = AgentNameAndVersionSet::browserslist(&EmbeddedCanIUseDatabase, &EmbeddedRegionalUsages, "defaults").unwrap().minimum_versions();
+//! // "defaults" includes Opera Mini, which esbuild can not express; the error still contains the target of the other agents.
+//! let esbuild_target = minimum_versions.esbuild_target().unwrap_or_else(|error| error.target);
+//! let babel_targets = minimum_versions.babel_targets();
+//! # }
+//! ```
+//!
+//!
+//! ### To find out what changed when updating the database, eg to fail a CI build if a feature depended upon regresses
+//!
+//! ```
+//! # extern crate caniuse_serde;
+//! # use ::caniuse_serde::*;
+//! # #[cfg(feature = "embedded-database")]
+//! # {
+//! # let old = CanIUse::default();
+//! # let new = CanIUse::default();
+//! let diff = CanIUse::diff(&old, &new);
+//! diff.write_markdown(&mut ::std::io::stdout()).unwrap();
+//! assert!(diff.regressions(&"css-grid".into(), &SupportPolicy::Strict).is_empty());
+//! # }
+//! ```
+//!
+//!
+//! ### To check a database for questionable or inconsistent data, eg to gate data updates in CI
+//!
+//! ```no_run
+//! # extern crate caniuse_serde;
+//! # use ::caniuse_serde::*;
+//! # use ::caniuse_serde::validation::*;
+//! # #[cfg(feature = "embedded-database")]
+//! # {
+//! # let can_i_use = CanIUse::default();
+//! let diagnostics = can_i_use.validate();
+//! assert!(!diagnostics.iter().any(Diagnostic::is_error));
+//! # }
+//! ```
+//!
+//!
+//! ### To compile the database into static tables in a build script, so there is no JSON parsing at start up
+//!
+//! In `build.rs`:-
+//!
+//! ```no_run
+//! # extern crate caniuse_serde;
+//! # use ::caniuse_serde::*;
+//! # use ::std::env;
+//! # use ::std::fs::File;
+//! # use ::std::path::Path;
+//! let mut file = File::create(Path::new(&env::var("OUT_DIR").unwrap()).join("caniuse.rs")).unwrap();
+//! CanIUse::from_path("data-2.0.json").unwrap().write_static_rust_source(&mut file, "CanIUseDatabase").unwrap();
+//! ```
+//!
+//! Then in the crate:-
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/caniuse.rs"));
+//!
+//! let feature = CanIUseDatabase.feature("transform3d").unwrap();
 //! ```
 //!
 //! ## Regional Usage
 //!
 //! * Use the constants in the `regional_usage` module to get regional, continental and world-wide usage data.
 //! * To replicate the functionality of 'browserlist', use the `query()` method on RegionalUsage.
+//! * To replicate the functionality of 'browserlist' queries such as `> 0.5% in AU, last 2 versions, not dead`, use `AgentNameAndVersionSet::browserslist()` with `EmbeddedRegionalUsages` or a `RegionalUsageRegistry`.
 //! * Or read below for a more useful approach.
 //! * Use the enum `RegionalUsages` with the method `regional_usage()` to obtain a reference to an embedded RegionalUsage database.
 //!
 //!
+//! ## Embedded data and cargo features
+//!
+//! By default the caniuse.com database and every regional usage database are embedded, which adds several megabytes to a binary.
+//! Use `default-features = false` and enable just those needed:-
+//!
+//! * `embedded-database`: the caniuse.com database, for `CanIUse::default()`, `EmbeddedCanIUseDatabase` and `sensible_choices()`;
+//! * `embedded-world-wide`: world-wide usage, for `RegionalUsage::default()` and `RegionalUsages::WorldWide`;
+//! * `embedded-continents`: continental usage, eg `RegionalUsages::Europe`;
+//! * `embedded-countries`: usage for every country, or `embedded-country-au` (say) for just one.
+//!
+//! `RegionalUsages::regional_usage()` returns a `RegionalUsageNotEmbeddedError` naming the missing cargo feature for data that is not embedded.
+//!
+//!
 //! ## A strategy for using the caniuse database with [browserlist](https://github.com/ai/browserslist) like behaviour
 //! The concept of version is differently understood by the browser vendors (eg IE vs Chrome, say), and so just saying 'last 2 versions' isn't particularly useful.
 //! In practice, a combination of selection rules is needed to identify a set of browser and browser versions to support, using the data in the database. These selection rules are likely to be stable for months and years, but not in the long term.
@@ -54,11 +251,13 @@
 //! ```
 //! extern crate caniuse_serde;
 //! use ::caniuse_serde::*;
-//! use ::caniuse_serde::regional_usage::*;
+//! use ::std::collections::HashSet;
 //!
+//! # #[cfg(all(feature = "embedded-database", feature = "embedded-continents", feature = "embedded-country-au", feature = "embedded-country-nz"))]
+//! # {
 //! let (can_i_use, choices) = sensible_choices_default();
 //!
-//! let feature_name = FeatureName("css-focus-ring".to_owned());
+//! let feature_name = FeatureName::from("css-focus-ring");
 //! let mut unique_prefixes = HashSet::new();
 //! choices.support_for_a_feature(&can_i_use, &feature_name, |agent, version, support| {
 //! 	if support.requires_prefix() {
@@ -68,6 +267,7 @@
 //!
 //! assert!(unique_prefixes.contains(&Prefix::moz));
 //! assert_eq!(unique_prefixes.len(), 1);
+//! # }
 //! ```
 //!
 //!
@@ -135,7 +335,7 @@
 
 
 extern crate chrono;
-#[macro_use] extern crate lazy_static;
+#[cfg(feature = "lazy_static")] #[macro_use] extern crate lazy_static;
 #[macro_use] extern crate maplit;
 extern crate serde;
 #[macro_use] extern crate serde_derive;
@@ -144,37 +344,52 @@ extern crate url;
 extern crate url_serde;
 
 
+use self::browserslist::*;
 use self::regional_usage::*;
+use self::user_agent::*;
 use ::chrono::Duration;
 use ::chrono::prelude::*;
 use ::serde::de;
 use ::serde::de::Deserialize;
+use ::serde::de::DeserializeOwned;
 use ::serde::de::DeserializeSeed;
+use ::serde::de::IgnoredAny;
 use ::serde::de::Deserializer;
 use ::serde::de::MapAccess;
 use ::serde::de::SeqAccess;
 use ::serde::de::Visitor;
+use ::serde::ser::Serialize;
+use ::serde::ser::SerializeStruct;
+use ::serde::ser::Serializer;
+use ::serde_json::Value;
 use ::std::collections::Bound;
 use ::std::collections::BTreeMap;
 use ::std::collections::HashMap;
 use ::std::collections::HashSet;
 use ::std::collections::hash_map::Keys;
 use ::std::collections::btree_map::Range;
+use ::std::cell::RefCell;
 use ::std::cmp::Ordering;
 use ::std::cmp::Eq;
 use ::std::cmp::Ord;
 use ::std::cmp::PartialEq;
 use ::std::cmp::PartialOrd;
+use ::std::cmp::Reverse;
+use ::std::error::Error;
 use ::std::fmt;
 use ::std::fmt::Display;
 use ::std::fmt::Formatter;
 use ::std::fs::File;
+use ::std::fs::read_dir;
 use ::std::hash::Hash;
 use ::std::hash::Hasher;
+use ::std::io;
 use ::std::io::Read;
+use ::std::io::Write;
 use ::std::iter::DoubleEndedIterator;
 use ::std::iter::ExactSizeIterator;
 use ::std::iter::Iterator;
+use ::std::marker::PhantomData;
 use ::std::ops::Add;
 use ::std::ops::AddAssign;
 use ::std::ops::Deref;
@@ -187,10 +402,48 @@ use ::url::Url;
 
 #[cfg(test)] mod systemTests;
 
+/// Static tables written by `CanIUse::write_static_rust_source()` name this crate `::caniuse_serde`; this lets the tests `include!` them.
+#[cfg(test)] mod caniuse_serde
+{
+	pub use super::AgentType;
+	pub use super::SupportMaturity;
+	pub use super::static_data;
+}
+
+/// Support for importing MDN's browser-compat-data.
+pub mod browser_compat_data;
+
+/// Support for checking stylesheets for CSS that targeted agents and versions do not support, in the manner of doiuse.
+pub mod css_compatibility;
+
+/// Support for comparing two caniuse.com databases.
+pub mod diff;
+
+/// Support for selecting agents and versions using [browserslist](https://github.com/ai/browserslist) queries.
+pub mod browserslist;
+
+/// Support for deciding which vendor prefixes CSS needs for a set of agents and versions, as an autoprefixer would.
+pub mod prefixes;
+
 /// Support for Agent regional, continental and world-wide usage by version.
 /// Use the `RegionalUsages` enum preferably.
 pub mod regional_usage;
 
+/// Support for searching the features of a caniuse.com database, tolerant of typos.
+pub mod search;
+
+/// Static tables of a caniuse.com database and regional usage, generated by `CanIUse::write_static_rust_source()` and `RegionalUsage::write_static_rust_source()`, and a borrowed view of them.
+pub mod static_data;
+
+/// Support for rendering agents and versions as the targets of tools such as Babel, SWC, esbuild and browserslist.
+pub mod targets;
+
+/// Support for resolving HTTP `User-Agent` headers to agents and versions.
+pub mod user_agent;
+
+/// Support for checking a caniuse.com database for questionable or inconsistent data.
+pub mod validation;
+
 
 
 include!("Agent.rs");
@@ -202,23 +455,34 @@ include!("AgentType.rs");
 include!("Bug.rs");
 include!("CanIUse.rs");
 include!("Category.rs");
+include!("CommaSeparatedStrings.rs");
+include!("Engine.rs");
 include!("Feature.rs");
 include!("FeatureDetail.rs");
 include!("FeatureName.rs");
 include!("FeatureNameIterator.rs");
 include!("Link.rs");
+include!("LoadError.rs");
+include!("LoadErrorContext.rs");
+include!("LoadOptions.rs");
 include!("ParentCategory.rs");
 include!("ParentCategoryIterator.rs");
 include!("Prefix.rs");
 include!("PrefixVisitor.rs");
+include!("SchemaViolationContext.rs");
 include!("sensible_choices.rs");
 include!("sensible_choices_default.rs");
 include!("Status.rs");
 include!("StatusIterator.rs");
 include!("Support.rs");
 include!("SupportDetail.rs");
+include!("SupportInference.rs");
 include!("SupportRangeIterator.rs");
+include!("SupportRemoval.rs");
 include!("SupportMaturity.rs");
+include!("StaticRustSource.rs");
+include!("StrictLoad.rs");
+include!("SupportPolicy.rs");
 include!("UsagePercentage.rs");
 include!("Version.rs");
 include!("VersionDetail.rs");
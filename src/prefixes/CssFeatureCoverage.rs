@@ -0,0 +1,170 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// What a caniuse.com CSS feature covers in CSS: properties, values, at-rules and selectors, so that a CSS post-processor can add or strip the prefixes a feature needs (see `AgentNameAndVersionSet::prefix_requirements()`).
+/// Only those features with CSS syntax known to this crate, mostly those that have, or have had, prefixed implementations, are known; see `CssFeatureCoverage::All`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CssFeatureCoverage
+{
+	/// Property names, eg `transform`.
+	pub properties: &'static [&'static str],
+	
+	/// Property values, either keywords or functions (without parentheses), eg `flex` or `linear-gradient`.
+	pub values: &'static [&'static str],
+	
+	/// The properties `values` are values of, eg `display` for `flex`; empty if they are values of any property, eg `calc`.
+	pub value_properties: &'static [&'static str],
+	
+	/// At-rules, without the leading `@`, eg `keyframes`.
+	pub at_rules: &'static [&'static str],
+	
+	/// Pseudo-classes and pseudo-elements, with leading colons, eg `::placeholder`.
+	pub selectors: &'static [&'static str],
+}
+
+impl CssFeatureCoverage
+{
+	/// Every known caniuse.com CSS feature name and what it covers.
+	pub const All: &'static [(&'static str, CssFeatureCoverage)] = &[
+		("background-clip-text", CssFeatureCoverage { values: &["text"], value_properties: &["background-clip"], .. Self::Nothing }),
+		("background-img-opts", CssFeatureCoverage { properties: &["background-origin", "background-size"], .. Self::Nothing }),
+		("border-image", CssFeatureCoverage { properties: &["border-image"], .. Self::Nothing }),
+		("border-radius", CssFeatureCoverage { properties: &["border-radius", "border-top-left-radius", "border-top-right-radius", "border-bottom-right-radius", "border-bottom-left-radius"], .. Self::Nothing }),
+		("calc", CssFeatureCoverage { values: &["calc"], .. Self::Nothing }),
+		("css-animation", CssFeatureCoverage { properties: &["animation", "animation-name", "animation-duration", "animation-delay", "animation-direction", "animation-fill-mode", "animation-iteration-count", "animation-play-state", "animation-timing-function"], at_rules: &["keyframes"], .. Self::Nothing }),
+		("css-any-link", CssFeatureCoverage { selectors: &[":any-link"], .. Self::Nothing }),
+		("css-appearance", CssFeatureCoverage { properties: &["appearance"], .. Self::Nothing }),
+		("css-aspect-ratio", CssFeatureCoverage { properties: &["aspect-ratio"], .. Self::Nothing }),
+		("css-backdrop-filter", CssFeatureCoverage { properties: &["backdrop-filter"], .. Self::Nothing }),
+		("css-backgroundblendmode", CssFeatureCoverage { properties: &["background-blend-mode"], .. Self::Nothing }),
+		("css-boxdecorationbreak", CssFeatureCoverage { properties: &["box-decoration-break"], .. Self::Nothing }),
+		("css-boxshadow", CssFeatureCoverage { properties: &["box-shadow"], .. Self::Nothing }),
+		("css-caret-color", CssFeatureCoverage { properties: &["caret-color"], .. Self::Nothing }),
+		("css-cascade-layers", CssFeatureCoverage { at_rules: &["layer"], .. Self::Nothing }),
+		("css-clip-path", CssFeatureCoverage { properties: &["clip-path"], .. Self::Nothing }),
+		("css-conic-gradients", CssFeatureCoverage { values: &["conic-gradient", "repeating-conic-gradient"], .. Self::Nothing }),
+		("css-container-queries", CssFeatureCoverage { properties: &["container", "container-name", "container-type"], at_rules: &["container"], .. Self::Nothing }),
+		("css-containment", CssFeatureCoverage { properties: &["contain"], .. Self::Nothing }),
+		("css-counter-styles", CssFeatureCoverage { at_rules: &["counter-style"], .. Self::Nothing }),
+		("css-crisp-edges", CssFeatureCoverage { values: &["crisp-edges", "pixelated"], value_properties: &["image-rendering"], .. Self::Nothing }),
+		("css-cross-fade", CssFeatureCoverage { values: &["cross-fade"], .. Self::Nothing }),
+		("css-default-pseudo", CssFeatureCoverage { selectors: &[":default"], .. Self::Nothing }),
+		("css-deviceadaptation", CssFeatureCoverage { at_rules: &["viewport"], .. Self::Nothing }),
+		("css-dir-pseudo", CssFeatureCoverage { selectors: &[":dir"], .. Self::Nothing }),
+		("css-display-contents", CssFeatureCoverage { values: &["contents"], value_properties: &["display"], .. Self::Nothing }),
+		("css-element-function", CssFeatureCoverage { values: &["element"], .. Self::Nothing }),
+		("css-env-function", CssFeatureCoverage { values: &["env"], .. Self::Nothing }),
+		("css-featurequeries", CssFeatureCoverage { at_rules: &["supports"], .. Self::Nothing }),
+		("css-filters", CssFeatureCoverage { properties: &["filter"], .. Self::Nothing }),
+		("css-focus-ring", CssFeatureCoverage { selectors: &[":focus-ring"], .. Self::Nothing }),
+		("css-focus-visible", CssFeatureCoverage { selectors: &[":focus-visible"], .. Self::Nothing }),
+		("css-focus-within", CssFeatureCoverage { selectors: &[":focus-within"], .. Self::Nothing }),
+		("css-font-rendering-controls", CssFeatureCoverage { properties: &["font-display"], .. Self::Nothing }),
+		("css-gradients", CssFeatureCoverage { values: &["linear-gradient", "repeating-linear-gradient", "radial-gradient", "repeating-radial-gradient"], value_properties: &["background", "background-image", "border-image", "border-image-source", "content", "list-style", "list-style-image", "mask", "mask-image"], .. Self::Nothing }),
+		("css-grid", CssFeatureCoverage { properties: &["grid", "grid-area", "grid-column", "grid-column-end", "grid-column-gap", "grid-column-start", "grid-gap", "grid-row", "grid-row-end", "grid-row-gap", "grid-row-start", "grid-template", "grid-template-areas", "grid-template-columns", "grid-template-rows"], values: &["grid", "inline-grid"], value_properties: &["display"], .. Self::Nothing }),
+		("css-has", CssFeatureCoverage { selectors: &[":has"], .. Self::Nothing }),
+		("css-hyphens", CssFeatureCoverage { properties: &["hyphens"], .. Self::Nothing }),
+		("css-image-set", CssFeatureCoverage { values: &["image-set"], .. Self::Nothing }),
+		("css-in-out-of-range", CssFeatureCoverage { selectors: &[":in-range", ":out-of-range"], .. Self::Nothing }),
+		("css-indeterminate-pseudo", CssFeatureCoverage { selectors: &[":indeterminate"], .. Self::Nothing }),
+		("css-initial-letter", CssFeatureCoverage { properties: &["initial-letter"], .. Self::Nothing }),
+		("css-logical-props", CssFeatureCoverage { properties: &["margin-inline-start", "margin-inline-end", "padding-inline-start", "padding-inline-end", "border-inline-start", "border-inline-end", "margin-block-start", "margin-block-end", "padding-block-start", "padding-block-end", "border-block-start", "border-block-end"], .. Self::Nothing }),
+		("css-marker-pseudo", CssFeatureCoverage { selectors: &["::marker"], .. Self::Nothing }),
+		("css-masks", CssFeatureCoverage { properties: &["mask", "mask-clip", "mask-composite", "mask-image", "mask-origin", "mask-position", "mask-repeat", "mask-size", "mask-border", "mask-border-outset", "mask-border-repeat", "mask-border-slice", "mask-border-source", "mask-border-width"], .. Self::Nothing }),
+		("css-matches-pseudo", CssFeatureCoverage { selectors: &[":is", ":matches"], .. Self::Nothing }),
+		("css-math-functions", CssFeatureCoverage { values: &["clamp", "max", "min"], .. Self::Nothing }),
+		("css-mixblendmode", CssFeatureCoverage { properties: &["mix-blend-mode"], .. Self::Nothing }),
+		("css-optional-pseudo", CssFeatureCoverage { selectors: &[":optional", ":required"], .. Self::Nothing }),
+		("css-overscroll-behavior", CssFeatureCoverage { properties: &["overscroll-behavior", "overscroll-behavior-x", "overscroll-behavior-y"], .. Self::Nothing }),
+		("css-paged-media", CssFeatureCoverage { at_rules: &["page"], .. Self::Nothing }),
+		("css-placeholder", CssFeatureCoverage { selectors: &["::placeholder"], .. Self::Nothing }),
+		("css-placeholder-shown", CssFeatureCoverage { selectors: &[":placeholder-shown"], .. Self::Nothing }),
+		("css-read-only-write", CssFeatureCoverage { selectors: &[":read-only", ":read-write"], .. Self::Nothing }),
+		("css-regions", CssFeatureCoverage { properties: &["flow-into", "flow-from", "region-fragment"], .. Self::Nothing }),
+		("css-resize", CssFeatureCoverage { properties: &["resize"], .. Self::Nothing }),
+		("css-scroll-behavior", CssFeatureCoverage { properties: &["scroll-behavior"], .. Self::Nothing }),
+		("css-selection", CssFeatureCoverage { selectors: &["::selection"], .. Self::Nothing }),
+		("css-shapes", CssFeatureCoverage { properties: &["shape-margin", "shape-outside", "shape-image-threshold"], .. Self::Nothing }),
+		("css-snappoints", CssFeatureCoverage { properties: &["scroll-snap-type", "scroll-snap-coordinate", "scroll-snap-destination", "scroll-snap-points-x", "scroll-snap-points-y"], .. Self::Nothing }),
+		("css-sticky", CssFeatureCoverage { values: &["sticky"], value_properties: &["position"], .. Self::Nothing }),
+		("css-text-align-last", CssFeatureCoverage { properties: &["text-align-last"], .. Self::Nothing }),
+		("css-text-spacing", CssFeatureCoverage { properties: &["text-spacing"], .. Self::Nothing }),
+		("css-transitions", CssFeatureCoverage { properties: &["transition", "transition-property", "transition-duration", "transition-delay", "transition-timing-function"], .. Self::Nothing }),
+		("css-unicode-bidi", CssFeatureCoverage { values: &["isolate", "isolate-override", "plaintext"], value_properties: &["unicode-bidi"], .. Self::Nothing }),
+		("css-variables", CssFeatureCoverage { values: &["var"], .. Self::Nothing }),
+		("css-writing-mode", CssFeatureCoverage { properties: &["writing-mode"], .. Self::Nothing }),
+		("css3-boxsizing", CssFeatureCoverage { properties: &["box-sizing"], .. Self::Nothing }),
+		("css3-cursors-grab", CssFeatureCoverage { values: &["grab", "grabbing"], value_properties: &["cursor"], .. Self::Nothing }),
+		("css3-cursors-newer", CssFeatureCoverage { values: &["zoom-in", "zoom-out"], value_properties: &["cursor"], .. Self::Nothing }),
+		("css3-tabsize", CssFeatureCoverage { properties: &["tab-size"], .. Self::Nothing }),
+		("flexbox", CssFeatureCoverage { properties: &["flex", "flex-grow", "flex-shrink", "flex-basis", "flex-direction", "flex-wrap", "flex-flow", "justify-content", "order", "align-items", "align-self", "align-content"], values: &["flex", "inline-flex"], value_properties: &["display"], .. Self::Nothing }),
+		("flow-root", CssFeatureCoverage { values: &["flow-root"], value_properties: &["display"], .. Self::Nothing }),
+		("font-feature", CssFeatureCoverage { properties: &["font-feature-settings", "font-variant-ligatures", "font-language-override"], .. Self::Nothing }),
+		("font-kerning", CssFeatureCoverage { properties: &["font-kerning"], .. Self::Nothing }),
+		("fontface", CssFeatureCoverage { at_rules: &["font-face"], .. Self::Nothing }),
+		("fullscreen", CssFeatureCoverage { selectors: &[":fullscreen", "::backdrop"], .. Self::Nothing }),
+		("intrinsic-width", CssFeatureCoverage { values: &["max-content", "min-content", "fit-content", "fill", "fill-available", "stretch"], value_properties: &["width", "min-width", "max-width", "height", "min-height", "max-height", "inline-size", "min-inline-size", "max-inline-size", "block-size", "min-block-size", "max-block-size", "grid", "grid-template", "grid-template-rows", "grid-template-columns", "grid-auto-columns", "grid-auto-rows"], .. Self::Nothing }),
+		("multicolumn", CssFeatureCoverage { properties: &["columns", "column-width", "column-gap", "column-rule", "column-rule-color", "column-rule-width", "column-rule-style", "column-count", "column-span", "column-fill", "break-before", "break-after", "break-inside"], .. Self::Nothing }),
+		("object-fit", CssFeatureCoverage { properties: &["object-fit", "object-position"], .. Self::Nothing }),
+		("text-decoration", CssFeatureCoverage { properties: &["text-decoration-style", "text-decoration-color", "text-decoration-line", "text-decoration-skip"], .. Self::Nothing }),
+		("text-emphasis", CssFeatureCoverage { properties: &["text-emphasis", "text-emphasis-position", "text-emphasis-style", "text-emphasis-color"], .. Self::Nothing }),
+		("text-overflow", CssFeatureCoverage { properties: &["text-overflow"], .. Self::Nothing }),
+		("text-size-adjust", CssFeatureCoverage { properties: &["text-size-adjust"], .. Self::Nothing }),
+		("transforms2d", CssFeatureCoverage { properties: &["transform", "transform-origin"], .. Self::Nothing }),
+		("transforms3d", CssFeatureCoverage { properties: &["perspective", "perspective-origin", "transform-style", "backface-visibility"], .. Self::Nothing }),
+		("user-select-none", CssFeatureCoverage { properties: &["user-select"], .. Self::Nothing }),
+		("will-change", CssFeatureCoverage { properties: &["will-change"], .. Self::Nothing }),
+	];
+	
+	const Nothing: CssFeatureCoverage = CssFeatureCoverage
+	{
+		properties: &[],
+		values: &[],
+		value_properties: &[],
+		at_rules: &[],
+		selectors: &[],
+	};
+	
+	/// What a caniuse.com CSS feature covers, eg the `flexbox` feature covers the `flex` property and the `flex` value of the `display` property.
+	/// Returns None if the feature is not known.
+	#[inline(always)]
+	pub fn of(feature_name: &FeatureName) -> Option<Self>
+	{
+		Self::All.iter().find(|&&(name, _)| name == feature_name.0).map(|&(_, css_feature_coverage)| css_feature_coverage)
+	}
+	
+	/// The names of the caniuse.com CSS features covering a property, eg `transforms2d` for `transform`.
+	#[inline(always)]
+	pub fn feature_names_covering_property(property: &str) -> Vec<FeatureName>
+	{
+		Self::feature_names_covering(|css_feature_coverage| css_feature_coverage.properties.contains(&property))
+	}
+	
+	/// The names of the caniuse.com CSS features covering a value of a property, eg `flexbox` for `display: flex` or `css-gradients` for `background-image: linear-gradient(...)`; `value` is a keyword or a function without its parentheses.
+	#[inline(always)]
+	pub fn feature_names_covering_value(property: &str, value: &str) -> Vec<FeatureName>
+	{
+		Self::feature_names_covering(|css_feature_coverage| css_feature_coverage.values.contains(&value) && (css_feature_coverage.value_properties.is_empty() || css_feature_coverage.value_properties.contains(&property)))
+	}
+	
+	/// The names of the caniuse.com CSS features covering an at-rule (without the leading `@`), eg `css-animation` for `keyframes`.
+	#[inline(always)]
+	pub fn feature_names_covering_at_rule(at_rule: &str) -> Vec<FeatureName>
+	{
+		Self::feature_names_covering(|css_feature_coverage| css_feature_coverage.at_rules.contains(&at_rule))
+	}
+	
+	/// The names of the caniuse.com CSS features covering a pseudo-class or pseudo-element (with leading colons), eg `css-placeholder` for `::placeholder`.
+	#[inline(always)]
+	pub fn feature_names_covering_selector(selector: &str) -> Vec<FeatureName>
+	{
+		Self::feature_names_covering(|css_feature_coverage| css_feature_coverage.selectors.contains(&selector))
+	}
+	
+	#[inline(always)]
+	fn feature_names_covering<P: Fn(&CssFeatureCoverage) -> bool>(predicate: P) -> Vec<FeatureName>
+	{
+		Self::All.iter().filter(|&&(_, css_feature_coverage)| predicate(&css_feature_coverage)).map(|&(name, _)| FeatureName::from(name)).collect()
+	}
+}
@@ -0,0 +1,89 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+impl AgentNameAndVersionSet
+{
+	/// The vendor prefixes each of `feature_names` needs for the agents and versions in this set; see `PrefixRequirement`.
+	/// Features not in the database are omitted.
+	#[inline(always)]
+	pub fn prefix_requirements(&self, can_i_use: &CanIUse, feature_names: &[FeatureName]) -> HashMap<FeatureName, PrefixRequirement>
+	{
+		let mut prefix_requirements = HashMap::with_capacity(feature_names.len());
+		
+		for feature_name in feature_names.iter()
+		{
+			if let Some(feature) = feature_name.feature(can_i_use)
+			{
+				let mut prefix_requirement = PrefixRequirement
+				{
+					prefixes: BTreeMap::new(),
+					unprefixed: BTreeSet::new(),
+					upper_case_prefix: feature.upper_case_prefix(),
+				};
+				
+				self.support_for_a_feature(can_i_use, feature_name, |agent, version, support|
+				{
+					match support.maturity()
+					{
+						SupportMaturity::SupportedByDefault | SupportMaturity::AlmostSupported => (),
+						_ => return,
+					}
+					
+					let agent_name_and_version = (agent.agent_name().clone(), version.clone());
+					if support.requires_prefix()
+					{
+						prefix_requirement.prefixes.entry(agent.prefix(version).clone()).or_default().insert(agent_name_and_version);
+					}
+					else
+					{
+						prefix_requirement.unprefixed.insert(agent_name_and_version);
+					}
+				});
+				
+				prefix_requirements.insert(feature_name.clone(), prefix_requirement);
+			}
+		}
+		
+		prefix_requirements
+	}
+}
+
+/// The vendor prefixes a feature needs for a set of agents and versions, as an autoprefixer would decide them: a prefix is needed by an agent and version whose support (`SupportMaturity::SupportedByDefault` or `SupportMaturity::AlmostSupported`) requires one, and that prefix is the agent's for that version (see `Agent::prefix()`).
+/// A prefix not needed can be stripped.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PrefixRequirement
+{
+	/// Prefixes needed, with the agents and versions needing each.
+	pub prefixes: BTreeMap<Prefix, BTreeSet<(AgentName, Version)>>,
+	
+	/// Agents and versions with support that does not require a prefix.
+	pub unprefixed: BTreeSet<(AgentName, Version)>,
+	
+	/// Are prefixes upper case, eg `Webkit` rather than `webkit`? This is only so for JavaScript APIs; see `Feature::upper_case_prefix()`.
+	pub upper_case_prefix: bool,
+}
+
+impl PrefixRequirement
+{
+	/// Is `prefix` needed?
+	#[inline(always)]
+	pub fn needs(&self, prefix: &Prefix) -> bool
+	{
+		self.prefixes.contains_key(prefix)
+	}
+	
+	/// Is any prefix needed?
+	#[inline(always)]
+	pub fn needs_any_prefix(&self) -> bool
+	{
+		!self.prefixes.is_empty()
+	}
+	
+	/// The prefixes needed, formatted for CSS with leading and trailing hyphens, eg `-webkit-`.
+	#[inline(always)]
+	pub fn css_prefixes(&self) -> Vec<String>
+	{
+		self.prefixes.keys().map(|prefix| format!("-{}-", prefix.can_i_use_identifier())).collect()
+	}
+}
@@ -0,0 +1,16 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// The regional usage data embedded in this crate; only regions whose cargo feature, eg `embedded-country-au`, is enabled are available.
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct EmbeddedRegionalUsages;
+
+impl RegionalUsageSource for EmbeddedRegionalUsages
+{
+	#[inline(always)]
+	fn regional_usage(&self, regional_usages: RegionalUsages) -> Option<&RegionalUsage>
+	{
+		regional_usages.regional_usage().ok()
+	}
+}
@@ -0,0 +1,48 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// The regional usage database was not embedded in this crate because the cargo feature for it was not enabled.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct RegionalUsageNotEmbeddedError(pub RegionalUsages);
+
+impl RegionalUsageNotEmbeddedError
+{
+	/// The cargo feature that would embed the regional usage database, eg `embedded-country-au`.
+	#[inline(always)]
+	pub fn cargo_feature(&self) -> String
+	{
+		use self::RegionalUsages::*;
+		
+		match self.0
+		{
+			WorldWide => "embedded-world-wide".to_owned(),
+			Africa | Antarctica | Asia | Europe | NorthAmerica | Oceania | SouthAmerica => "embedded-continents".to_owned(),
+			country => format!("embedded-country-{}", country.can_i_use_iso_like_code().to_ascii_lowercase()),
+		}
+	}
+}
+
+impl Error for RegionalUsageNotEmbeddedError
+{
+	#[inline(always)]
+	fn description(&self) -> &str
+	{
+		"regional usage database not embedded"
+	}
+	
+	#[inline(always)]
+	fn cause(&self) -> Option<&dyn Error>
+	{
+		None
+	}
+}
+
+impl Display for RegionalUsageNotEmbeddedError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "regional usage database not embedded '{}'; enable the cargo feature '{}'", self.0.can_i_use_iso_like_code(), self.cargo_feature())
+	}
+}
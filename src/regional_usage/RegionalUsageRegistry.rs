@@ -0,0 +1,65 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Regional, continental and world-wide usage data loaded at runtime, eg from a checkout of the caniuse.com git repository, rather than embedded.
+/// Keyed by caniuse ISO-like code, eg `AU` or `alt-ww`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionalUsageRegistry(HashMap<String, RegionalUsage>);
+
+impl Deref for RegionalUsageRegistry
+{
+	type Target = HashMap<String, RegionalUsage>;
+	
+	/// Dereferences to HashMap<String, RegionalUsage>
+	#[inline(always)]
+	fn deref(&self) -> &Self::Target
+	{
+		&self.0
+	}
+}
+
+impl RegionalUsageRegistry
+{
+	/// Loads every `*.json` file in the `region-usage-json/` folder of a checkout of the caniuse.com git repository.
+	#[inline(always)]
+	pub fn from_caniuse_repository<P: AsRef<Path>>(caniuse_repository_folder_path: P) -> Result<Self, LoadError>
+	{
+		Self::from_folder(caniuse_repository_folder_path.as_ref().join("region-usage-json"))
+	}
+	
+	/// Loads every `*.json` file in a folder, such as `region-usage-json/`.
+	#[inline(always)]
+	pub fn from_folder<P: AsRef<Path>>(regional_usage_folder_path: P) -> Result<Self, LoadError>
+	{
+		let mut registry = HashMap::new();
+		for entry in read_dir(regional_usage_folder_path)?
+		{
+			let regional_usage_file_path = entry?.path();
+			if regional_usage_file_path.extension() != Some("json".as_ref())
+			{
+				continue;
+			}
+			
+			let regional_usage = RegionalUsage::from_path(&regional_usage_file_path)?;
+			registry.insert(regional_usage.identifier().to_owned(), regional_usage);
+		}
+		Ok(RegionalUsageRegistry(registry))
+	}
+	
+	/// Regional usage for one of the `RegionalUsages`, if loaded.
+	#[inline(always)]
+	pub fn regional_usage(&self, regional_usages: RegionalUsages) -> Option<&RegionalUsage>
+	{
+		self.0.get(regional_usages.can_i_use_iso_like_code())
+	}
+}
+
+impl RegionalUsageSource for RegionalUsageRegistry
+{
+	#[inline(always)]
+	fn regional_usage(&self, regional_usages: RegionalUsages) -> Option<&RegionalUsage>
+	{
+		RegionalUsageRegistry::regional_usage(self, regional_usages)
+	}
+}
@@ -0,0 +1,10 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// A source of regional usage data for browserslist queries such as `> 5% in AU`, eg `EmbeddedRegionalUsages` or a `RegionalUsageRegistry`.
+pub trait RegionalUsageSource
+{
+	/// Regional usage for one of the `RegionalUsages`, if this source has it.
+	fn regional_usage(&self, regional_usages: RegionalUsages) -> Option<&RegionalUsage>;
+}
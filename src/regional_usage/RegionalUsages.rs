@@ -533,265 +533,519 @@ impl FromStr for RegionalUsages
 			"ZM" => Ok(ZM),
 			"ZW" => Ok(ZW),
 			
-			_ => Err(RegionalUsagesFromStrError)
+			_ => Err(RegionalUsagesFromStrError(can_i_use_iso_like_code.to_owned()))
 		}
 	}
 }
 
 impl RegionalUsages
 {
-	/// Returns a reference to an embedded regional usage database
+	/// Every regional usage: world-wide, then continents, then countries in order of ISO 3166 code.
+	/// Not all of them are necessarily embedded; see `regional_usage()`.
+	pub const All: &'static [RegionalUsages] = &
+	[
+		RegionalUsages::WorldWide,
+		
+		RegionalUsages::Africa,
+		RegionalUsages::Antarctica,
+		RegionalUsages::Asia,
+		RegionalUsages::Europe,
+		RegionalUsages::NorthAmerica,
+		RegionalUsages::Oceania,
+		RegionalUsages::SouthAmerica,
+		
+		RegionalUsages::AD,
+		RegionalUsages::AE,
+		RegionalUsages::AF,
+		RegionalUsages::AG,
+		RegionalUsages::AI,
+		RegionalUsages::AL,
+		RegionalUsages::AM,
+		RegionalUsages::AN,
+		RegionalUsages::AO,
+		RegionalUsages::AR,
+		RegionalUsages::AS,
+		RegionalUsages::AT,
+		RegionalUsages::AU,
+		RegionalUsages::AW,
+		RegionalUsages::AX,
+		RegionalUsages::AZ,
+		RegionalUsages::BA,
+		RegionalUsages::BB,
+		RegionalUsages::BD,
+		RegionalUsages::BE,
+		RegionalUsages::BF,
+		RegionalUsages::BG,
+		RegionalUsages::BH,
+		RegionalUsages::BI,
+		RegionalUsages::BJ,
+		RegionalUsages::BM,
+		RegionalUsages::BN,
+		RegionalUsages::BO,
+		RegionalUsages::BR,
+		RegionalUsages::BS,
+		RegionalUsages::BT,
+		RegionalUsages::BW,
+		RegionalUsages::BY,
+		RegionalUsages::BZ,
+		RegionalUsages::CA,
+		RegionalUsages::CD,
+		RegionalUsages::CF,
+		RegionalUsages::CG,
+		RegionalUsages::CH,
+		RegionalUsages::CI,
+		RegionalUsages::CK,
+		RegionalUsages::CL,
+		RegionalUsages::CM,
+		RegionalUsages::CN,
+		RegionalUsages::CO,
+		RegionalUsages::CR,
+		RegionalUsages::CU,
+		RegionalUsages::CV,
+		RegionalUsages::CX,
+		RegionalUsages::CY,
+		RegionalUsages::CZ,
+		RegionalUsages::DE,
+		RegionalUsages::DJ,
+		RegionalUsages::DK,
+		RegionalUsages::DM,
+		RegionalUsages::DO,
+		RegionalUsages::DZ,
+		RegionalUsages::EC,
+		RegionalUsages::EE,
+		RegionalUsages::EG,
+		RegionalUsages::ER,
+		RegionalUsages::ES,
+		RegionalUsages::ET,
+		RegionalUsages::FI,
+		RegionalUsages::FJ,
+		RegionalUsages::FK,
+		RegionalUsages::FM,
+		RegionalUsages::FO,
+		RegionalUsages::FR,
+		RegionalUsages::GA,
+		RegionalUsages::GB,
+		RegionalUsages::GD,
+		RegionalUsages::GE,
+		RegionalUsages::GF,
+		RegionalUsages::GG,
+		RegionalUsages::GH,
+		RegionalUsages::GI,
+		RegionalUsages::GL,
+		RegionalUsages::GM,
+		RegionalUsages::GN,
+		RegionalUsages::GP,
+		RegionalUsages::GQ,
+		RegionalUsages::GR,
+		RegionalUsages::GT,
+		RegionalUsages::GU,
+		RegionalUsages::GW,
+		RegionalUsages::GY,
+		RegionalUsages::HK,
+		RegionalUsages::HN,
+		RegionalUsages::HR,
+		RegionalUsages::HT,
+		RegionalUsages::HU,
+		RegionalUsages::ID,
+		RegionalUsages::IE,
+		RegionalUsages::IL,
+		RegionalUsages::IM,
+		RegionalUsages::IN,
+		RegionalUsages::IQ,
+		RegionalUsages::IR,
+		RegionalUsages::IS,
+		RegionalUsages::IT,
+		RegionalUsages::JE,
+		RegionalUsages::JM,
+		RegionalUsages::JO,
+		RegionalUsages::JP,
+		RegionalUsages::KE,
+		RegionalUsages::KG,
+		RegionalUsages::KH,
+		RegionalUsages::KI,
+		RegionalUsages::KM,
+		RegionalUsages::KN,
+		RegionalUsages::KP,
+		RegionalUsages::KR,
+		RegionalUsages::KW,
+		RegionalUsages::KY,
+		RegionalUsages::KZ,
+		RegionalUsages::LA,
+		RegionalUsages::LB,
+		RegionalUsages::LC,
+		RegionalUsages::LI,
+		RegionalUsages::LK,
+		RegionalUsages::LR,
+		RegionalUsages::LS,
+		RegionalUsages::LT,
+		RegionalUsages::LU,
+		RegionalUsages::LV,
+		RegionalUsages::LY,
+		RegionalUsages::MA,
+		RegionalUsages::MC,
+		RegionalUsages::MD,
+		RegionalUsages::ME,
+		RegionalUsages::MG,
+		RegionalUsages::MH,
+		RegionalUsages::MK,
+		RegionalUsages::ML,
+		RegionalUsages::MM,
+		RegionalUsages::MN,
+		RegionalUsages::MO,
+		RegionalUsages::MP,
+		RegionalUsages::MQ,
+		RegionalUsages::MR,
+		RegionalUsages::MS,
+		RegionalUsages::MT,
+		RegionalUsages::MU,
+		RegionalUsages::MV,
+		RegionalUsages::MW,
+		RegionalUsages::MX,
+		RegionalUsages::MY,
+		RegionalUsages::MZ,
+		RegionalUsages::NA,
+		RegionalUsages::NC,
+		RegionalUsages::NE,
+		RegionalUsages::NF,
+		RegionalUsages::NG,
+		RegionalUsages::NI,
+		RegionalUsages::NL,
+		RegionalUsages::NO,
+		RegionalUsages::NP,
+		RegionalUsages::NR,
+		RegionalUsages::NU,
+		RegionalUsages::NZ,
+		RegionalUsages::OM,
+		RegionalUsages::PA,
+		RegionalUsages::PE,
+		RegionalUsages::PF,
+		RegionalUsages::PG,
+		RegionalUsages::PH,
+		RegionalUsages::PK,
+		RegionalUsages::PL,
+		RegionalUsages::PM,
+		RegionalUsages::PN,
+		RegionalUsages::PR,
+		RegionalUsages::PS,
+		RegionalUsages::PT,
+		RegionalUsages::PW,
+		RegionalUsages::PY,
+		RegionalUsages::QA,
+		RegionalUsages::RE,
+		RegionalUsages::RO,
+		RegionalUsages::RS,
+		RegionalUsages::RU,
+		RegionalUsages::RW,
+		RegionalUsages::SA,
+		RegionalUsages::SB,
+		RegionalUsages::SC,
+		RegionalUsages::SD,
+		RegionalUsages::SE,
+		RegionalUsages::SG,
+		RegionalUsages::SH,
+		RegionalUsages::SI,
+		RegionalUsages::SK,
+		RegionalUsages::SL,
+		RegionalUsages::SM,
+		RegionalUsages::SN,
+		RegionalUsages::SO,
+		RegionalUsages::SR,
+		RegionalUsages::ST,
+		RegionalUsages::SV,
+		RegionalUsages::SY,
+		RegionalUsages::SZ,
+		RegionalUsages::TC,
+		RegionalUsages::TD,
+		RegionalUsages::TG,
+		RegionalUsages::TH,
+		RegionalUsages::TJ,
+		RegionalUsages::TK,
+		RegionalUsages::TL,
+		RegionalUsages::TM,
+		RegionalUsages::TN,
+		RegionalUsages::TO,
+		RegionalUsages::TR,
+		RegionalUsages::TT,
+		RegionalUsages::TV,
+		RegionalUsages::TW,
+		RegionalUsages::TZ,
+		RegionalUsages::UA,
+		RegionalUsages::UG,
+		RegionalUsages::US,
+		RegionalUsages::UY,
+		RegionalUsages::UZ,
+		RegionalUsages::VA,
+		RegionalUsages::VC,
+		RegionalUsages::VE,
+		RegionalUsages::VG,
+		RegionalUsages::VI,
+		RegionalUsages::VN,
+		RegionalUsages::VU,
+		RegionalUsages::WF,
+		RegionalUsages::WS,
+		RegionalUsages::YE,
+		RegionalUsages::YT,
+		RegionalUsages::ZA,
+		RegionalUsages::ZM,
+		RegionalUsages::ZW,
+	];
+	
+	/// Returns a reference to an embedded regional usage database.
+	/// Returns an error if the cargo feature embedding it, eg `embedded-country-au`, is not enabled.
 	#[inline(always)]
-	pub fn regional_usage(&self) -> &'static RegionalUsage
+	#[allow(unreachable_patterns)]
+	pub fn regional_usage(&self) -> Result<&'static RegionalUsage, RegionalUsageNotEmbeddedError>
 	{
-		use self::RegionalUsages::*;
+		#[allow(unused_imports)] use self::RegionalUsages::*;
 		
 		match *self
 		{
-			WorldWide => &::regional_usage::WorldWide,
+			#[cfg(feature = "embedded-world-wide")] WorldWide => Ok(&::regional_usage::WorldWide),
+			
+			#[cfg(feature = "embedded-continents")] Africa => Ok(&::regional_usage::Africa),
+			#[cfg(feature = "embedded-continents")] Antarctica => Ok(&::regional_usage::Antarctica),
+			#[cfg(feature = "embedded-continents")] Asia => Ok(&::regional_usage::Asia),
+			#[cfg(feature = "embedded-continents")] Europe => Ok(&::regional_usage::Europe),
+			#[cfg(feature = "embedded-continents")] NorthAmerica => Ok(&::regional_usage::NorthAmerica),
+			#[cfg(feature = "embedded-continents")] Oceania => Ok(&::regional_usage::Oceania),
+			#[cfg(feature = "embedded-continents")] SouthAmerica => Ok(&::regional_usage::SouthAmerica),
 			
-			Africa => &::regional_usage::Africa,
-			Antarctica => &::regional_usage::Antarctica,
-			Asia => &::regional_usage::Asia,
-			Europe => &::regional_usage::Europe,
-			NorthAmerica => &::regional_usage::NorthAmerica,
-			Oceania => &::regional_usage::Oceania,
-			SouthAmerica => &::regional_usage::SouthAmerica,
+			#[cfg(feature = "embedded-country-ad")] AD => Ok(&::regional_usage::AD),
+			#[cfg(feature = "embedded-country-ae")] AE => Ok(&::regional_usage::AE),
+			#[cfg(feature = "embedded-country-af")] AF => Ok(&::regional_usage::AF),
+			#[cfg(feature = "embedded-country-ag")] AG => Ok(&::regional_usage::AG),
+			#[cfg(feature = "embedded-country-ai")] AI => Ok(&::regional_usage::AI),
+			#[cfg(feature = "embedded-country-al")] AL => Ok(&::regional_usage::AL),
+			#[cfg(feature = "embedded-country-am")] AM => Ok(&::regional_usage::AM),
+			#[cfg(feature = "embedded-country-an")] AN => Ok(&::regional_usage::AN),
+			#[cfg(feature = "embedded-country-ao")] AO => Ok(&::regional_usage::AO),
+			#[cfg(feature = "embedded-country-ar")] AR => Ok(&::regional_usage::AR),
+			#[cfg(feature = "embedded-country-as")] AS => Ok(&::regional_usage::AS),
+			#[cfg(feature = "embedded-country-at")] AT => Ok(&::regional_usage::AT),
+			#[cfg(feature = "embedded-country-au")] AU => Ok(&::regional_usage::AU),
+			#[cfg(feature = "embedded-country-aw")] AW => Ok(&::regional_usage::AW),
+			#[cfg(feature = "embedded-country-ax")] AX => Ok(&::regional_usage::AX),
+			#[cfg(feature = "embedded-country-az")] AZ => Ok(&::regional_usage::AZ),
+			#[cfg(feature = "embedded-country-ba")] BA => Ok(&::regional_usage::BA),
+			#[cfg(feature = "embedded-country-bb")] BB => Ok(&::regional_usage::BB),
+			#[cfg(feature = "embedded-country-bd")] BD => Ok(&::regional_usage::BD),
+			#[cfg(feature = "embedded-country-be")] BE => Ok(&::regional_usage::BE),
+			#[cfg(feature = "embedded-country-bf")] BF => Ok(&::regional_usage::BF),
+			#[cfg(feature = "embedded-country-bg")] BG => Ok(&::regional_usage::BG),
+			#[cfg(feature = "embedded-country-bh")] BH => Ok(&::regional_usage::BH),
+			#[cfg(feature = "embedded-country-bi")] BI => Ok(&::regional_usage::BI),
+			#[cfg(feature = "embedded-country-bj")] BJ => Ok(&::regional_usage::BJ),
+			#[cfg(feature = "embedded-country-bm")] BM => Ok(&::regional_usage::BM),
+			#[cfg(feature = "embedded-country-bn")] BN => Ok(&::regional_usage::BN),
+			#[cfg(feature = "embedded-country-bo")] BO => Ok(&::regional_usage::BO),
+			#[cfg(feature = "embedded-country-br")] BR => Ok(&::regional_usage::BR),
+			#[cfg(feature = "embedded-country-bs")] BS => Ok(&::regional_usage::BS),
+			#[cfg(feature = "embedded-country-bt")] BT => Ok(&::regional_usage::BT),
+			#[cfg(feature = "embedded-country-bw")] BW => Ok(&::regional_usage::BW),
+			#[cfg(feature = "embedded-country-by")] BY => Ok(&::regional_usage::BY),
+			#[cfg(feature = "embedded-country-bz")] BZ => Ok(&::regional_usage::BZ),
+			#[cfg(feature = "embedded-country-ca")] CA => Ok(&::regional_usage::CA),
+			#[cfg(feature = "embedded-country-cd")] CD => Ok(&::regional_usage::CD),
+			#[cfg(feature = "embedded-country-cf")] CF => Ok(&::regional_usage::CF),
+			#[cfg(feature = "embedded-country-cg")] CG => Ok(&::regional_usage::CG),
+			#[cfg(feature = "embedded-country-ch")] CH => Ok(&::regional_usage::CH),
+			#[cfg(feature = "embedded-country-ci")] CI => Ok(&::regional_usage::CI),
+			#[cfg(feature = "embedded-country-ck")] CK => Ok(&::regional_usage::CK),
+			#[cfg(feature = "embedded-country-cl")] CL => Ok(&::regional_usage::CL),
+			#[cfg(feature = "embedded-country-cm")] CM => Ok(&::regional_usage::CM),
+			#[cfg(feature = "embedded-country-cn")] CN => Ok(&::regional_usage::CN),
+			#[cfg(feature = "embedded-country-co")] CO => Ok(&::regional_usage::CO),
+			#[cfg(feature = "embedded-country-cr")] CR => Ok(&::regional_usage::CR),
+			#[cfg(feature = "embedded-country-cu")] CU => Ok(&::regional_usage::CU),
+			#[cfg(feature = "embedded-country-cv")] CV => Ok(&::regional_usage::CV),
+			#[cfg(feature = "embedded-country-cx")] CX => Ok(&::regional_usage::CX),
+			#[cfg(feature = "embedded-country-cy")] CY => Ok(&::regional_usage::CY),
+			#[cfg(feature = "embedded-country-cz")] CZ => Ok(&::regional_usage::CZ),
+			#[cfg(feature = "embedded-country-de")] DE => Ok(&::regional_usage::DE),
+			#[cfg(feature = "embedded-country-dj")] DJ => Ok(&::regional_usage::DJ),
+			#[cfg(feature = "embedded-country-dk")] DK => Ok(&::regional_usage::DK),
+			#[cfg(feature = "embedded-country-dm")] DM => Ok(&::regional_usage::DM),
+			#[cfg(feature = "embedded-country-do")] DO => Ok(&::regional_usage::DO),
+			#[cfg(feature = "embedded-country-dz")] DZ => Ok(&::regional_usage::DZ),
+			#[cfg(feature = "embedded-country-ec")] EC => Ok(&::regional_usage::EC),
+			#[cfg(feature = "embedded-country-ee")] EE => Ok(&::regional_usage::EE),
+			#[cfg(feature = "embedded-country-eg")] EG => Ok(&::regional_usage::EG),
+			#[cfg(feature = "embedded-country-er")] ER => Ok(&::regional_usage::ER),
+			#[cfg(feature = "embedded-country-es")] ES => Ok(&::regional_usage::ES),
+			#[cfg(feature = "embedded-country-et")] ET => Ok(&::regional_usage::ET),
+			#[cfg(feature = "embedded-country-fi")] FI => Ok(&::regional_usage::FI),
+			#[cfg(feature = "embedded-country-fj")] FJ => Ok(&::regional_usage::FJ),
+			#[cfg(feature = "embedded-country-fk")] FK => Ok(&::regional_usage::FK),
+			#[cfg(feature = "embedded-country-fm")] FM => Ok(&::regional_usage::FM),
+			#[cfg(feature = "embedded-country-fo")] FO => Ok(&::regional_usage::FO),
+			#[cfg(feature = "embedded-country-fr")] FR => Ok(&::regional_usage::FR),
+			#[cfg(feature = "embedded-country-ga")] GA => Ok(&::regional_usage::GA),
+			#[cfg(feature = "embedded-country-gb")] GB => Ok(&::regional_usage::GB),
+			#[cfg(feature = "embedded-country-gd")] GD => Ok(&::regional_usage::GD),
+			#[cfg(feature = "embedded-country-ge")] GE => Ok(&::regional_usage::GE),
+			#[cfg(feature = "embedded-country-gf")] GF => Ok(&::regional_usage::GF),
+			#[cfg(feature = "embedded-country-gg")] GG => Ok(&::regional_usage::GG),
+			#[cfg(feature = "embedded-country-gh")] GH => Ok(&::regional_usage::GH),
+			#[cfg(feature = "embedded-country-gi")] GI => Ok(&::regional_usage::GI),
+			#[cfg(feature = "embedded-country-gl")] GL => Ok(&::regional_usage::GL),
+			#[cfg(feature = "embedded-country-gm")] GM => Ok(&::regional_usage::GM),
+			#[cfg(feature = "embedded-country-gn")] GN => Ok(&::regional_usage::GN),
+			#[cfg(feature = "embedded-country-gp")] GP => Ok(&::regional_usage::GP),
+			#[cfg(feature = "embedded-country-gq")] GQ => Ok(&::regional_usage::GQ),
+			#[cfg(feature = "embedded-country-gr")] GR => Ok(&::regional_usage::GR),
+			#[cfg(feature = "embedded-country-gt")] GT => Ok(&::regional_usage::GT),
+			#[cfg(feature = "embedded-country-gu")] GU => Ok(&::regional_usage::GU),
+			#[cfg(feature = "embedded-country-gw")] GW => Ok(&::regional_usage::GW),
+			#[cfg(feature = "embedded-country-gy")] GY => Ok(&::regional_usage::GY),
+			#[cfg(feature = "embedded-country-hk")] HK => Ok(&::regional_usage::HK),
+			#[cfg(feature = "embedded-country-hn")] HN => Ok(&::regional_usage::HN),
+			#[cfg(feature = "embedded-country-hr")] HR => Ok(&::regional_usage::HR),
+			#[cfg(feature = "embedded-country-ht")] HT => Ok(&::regional_usage::HT),
+			#[cfg(feature = "embedded-country-hu")] HU => Ok(&::regional_usage::HU),
+			#[cfg(feature = "embedded-country-id")] ID => Ok(&::regional_usage::ID),
+			#[cfg(feature = "embedded-country-ie")] IE => Ok(&::regional_usage::IE),
+			#[cfg(feature = "embedded-country-il")] IL => Ok(&::regional_usage::IL),
+			#[cfg(feature = "embedded-country-im")] IM => Ok(&::regional_usage::IM),
+			#[cfg(feature = "embedded-country-in")] IN => Ok(&::regional_usage::IN),
+			#[cfg(feature = "embedded-country-iq")] IQ => Ok(&::regional_usage::IQ),
+			#[cfg(feature = "embedded-country-ir")] IR => Ok(&::regional_usage::IR),
+			#[cfg(feature = "embedded-country-is")] IS => Ok(&::regional_usage::IS),
+			#[cfg(feature = "embedded-country-it")] IT => Ok(&::regional_usage::IT),
+			#[cfg(feature = "embedded-country-je")] JE => Ok(&::regional_usage::JE),
+			#[cfg(feature = "embedded-country-jm")] JM => Ok(&::regional_usage::JM),
+			#[cfg(feature = "embedded-country-jo")] JO => Ok(&::regional_usage::JO),
+			#[cfg(feature = "embedded-country-jp")] JP => Ok(&::regional_usage::JP),
+			#[cfg(feature = "embedded-country-ke")] KE => Ok(&::regional_usage::KE),
+			#[cfg(feature = "embedded-country-kg")] KG => Ok(&::regional_usage::KG),
+			#[cfg(feature = "embedded-country-kh")] KH => Ok(&::regional_usage::KH),
+			#[cfg(feature = "embedded-country-ki")] KI => Ok(&::regional_usage::KI),
+			#[cfg(feature = "embedded-country-km")] KM => Ok(&::regional_usage::KM),
+			#[cfg(feature = "embedded-country-kn")] KN => Ok(&::regional_usage::KN),
+			#[cfg(feature = "embedded-country-kp")] KP => Ok(&::regional_usage::KP),
+			#[cfg(feature = "embedded-country-kr")] KR => Ok(&::regional_usage::KR),
+			#[cfg(feature = "embedded-country-kw")] KW => Ok(&::regional_usage::KW),
+			#[cfg(feature = "embedded-country-ky")] KY => Ok(&::regional_usage::KY),
+			#[cfg(feature = "embedded-country-kz")] KZ => Ok(&::regional_usage::KZ),
+			#[cfg(feature = "embedded-country-la")] LA => Ok(&::regional_usage::LA),
+			#[cfg(feature = "embedded-country-lb")] LB => Ok(&::regional_usage::LB),
+			#[cfg(feature = "embedded-country-lc")] LC => Ok(&::regional_usage::LC),
+			#[cfg(feature = "embedded-country-li")] LI => Ok(&::regional_usage::LI),
+			#[cfg(feature = "embedded-country-lk")] LK => Ok(&::regional_usage::LK),
+			#[cfg(feature = "embedded-country-lr")] LR => Ok(&::regional_usage::LR),
+			#[cfg(feature = "embedded-country-ls")] LS => Ok(&::regional_usage::LS),
+			#[cfg(feature = "embedded-country-lt")] LT => Ok(&::regional_usage::LT),
+			#[cfg(feature = "embedded-country-lu")] LU => Ok(&::regional_usage::LU),
+			#[cfg(feature = "embedded-country-lv")] LV => Ok(&::regional_usage::LV),
+			#[cfg(feature = "embedded-country-ly")] LY => Ok(&::regional_usage::LY),
+			#[cfg(feature = "embedded-country-ma")] MA => Ok(&::regional_usage::MA),
+			#[cfg(feature = "embedded-country-mc")] MC => Ok(&::regional_usage::MC),
+			#[cfg(feature = "embedded-country-md")] MD => Ok(&::regional_usage::MD),
+			#[cfg(feature = "embedded-country-me")] ME => Ok(&::regional_usage::ME),
+			#[cfg(feature = "embedded-country-mg")] MG => Ok(&::regional_usage::MG),
+			#[cfg(feature = "embedded-country-mh")] MH => Ok(&::regional_usage::MH),
+			#[cfg(feature = "embedded-country-mk")] MK => Ok(&::regional_usage::MK),
+			#[cfg(feature = "embedded-country-ml")] ML => Ok(&::regional_usage::ML),
+			#[cfg(feature = "embedded-country-mm")] MM => Ok(&::regional_usage::MM),
+			#[cfg(feature = "embedded-country-mn")] MN => Ok(&::regional_usage::MN),
+			#[cfg(feature = "embedded-country-mo")] MO => Ok(&::regional_usage::MO),
+			#[cfg(feature = "embedded-country-mp")] MP => Ok(&::regional_usage::MP),
+			#[cfg(feature = "embedded-country-mq")] MQ => Ok(&::regional_usage::MQ),
+			#[cfg(feature = "embedded-country-mr")] MR => Ok(&::regional_usage::MR),
+			#[cfg(feature = "embedded-country-ms")] MS => Ok(&::regional_usage::MS),
+			#[cfg(feature = "embedded-country-mt")] MT => Ok(&::regional_usage::MT),
+			#[cfg(feature = "embedded-country-mu")] MU => Ok(&::regional_usage::MU),
+			#[cfg(feature = "embedded-country-mv")] MV => Ok(&::regional_usage::MV),
+			#[cfg(feature = "embedded-country-mw")] MW => Ok(&::regional_usage::MW),
+			#[cfg(feature = "embedded-country-mx")] MX => Ok(&::regional_usage::MX),
+			#[cfg(feature = "embedded-country-my")] MY => Ok(&::regional_usage::MY),
+			#[cfg(feature = "embedded-country-mz")] MZ => Ok(&::regional_usage::MZ),
+			#[cfg(feature = "embedded-country-na")] NA => Ok(&::regional_usage::NA),
+			#[cfg(feature = "embedded-country-nc")] NC => Ok(&::regional_usage::NC),
+			#[cfg(feature = "embedded-country-ne")] NE => Ok(&::regional_usage::NE),
+			#[cfg(feature = "embedded-country-nf")] NF => Ok(&::regional_usage::NF),
+			#[cfg(feature = "embedded-country-ng")] NG => Ok(&::regional_usage::NG),
+			#[cfg(feature = "embedded-country-ni")] NI => Ok(&::regional_usage::NI),
+			#[cfg(feature = "embedded-country-nl")] NL => Ok(&::regional_usage::NL),
+			#[cfg(feature = "embedded-country-no")] NO => Ok(&::regional_usage::NO),
+			#[cfg(feature = "embedded-country-np")] NP => Ok(&::regional_usage::NP),
+			#[cfg(feature = "embedded-country-nr")] NR => Ok(&::regional_usage::NR),
+			#[cfg(feature = "embedded-country-nu")] NU => Ok(&::regional_usage::NU),
+			#[cfg(feature = "embedded-country-nz")] NZ => Ok(&::regional_usage::NZ),
+			#[cfg(feature = "embedded-country-om")] OM => Ok(&::regional_usage::OM),
+			#[cfg(feature = "embedded-country-pa")] PA => Ok(&::regional_usage::PA),
+			#[cfg(feature = "embedded-country-pe")] PE => Ok(&::regional_usage::PE),
+			#[cfg(feature = "embedded-country-pf")] PF => Ok(&::regional_usage::PF),
+			#[cfg(feature = "embedded-country-pg")] PG => Ok(&::regional_usage::PG),
+			#[cfg(feature = "embedded-country-ph")] PH => Ok(&::regional_usage::PH),
+			#[cfg(feature = "embedded-country-pk")] PK => Ok(&::regional_usage::PK),
+			#[cfg(feature = "embedded-country-pl")] PL => Ok(&::regional_usage::PL),
+			#[cfg(feature = "embedded-country-pm")] PM => Ok(&::regional_usage::PM),
+			#[cfg(feature = "embedded-country-pn")] PN => Ok(&::regional_usage::PN),
+			#[cfg(feature = "embedded-country-pr")] PR => Ok(&::regional_usage::PR),
+			#[cfg(feature = "embedded-country-ps")] PS => Ok(&::regional_usage::PS),
+			#[cfg(feature = "embedded-country-pt")] PT => Ok(&::regional_usage::PT),
+			#[cfg(feature = "embedded-country-pw")] PW => Ok(&::regional_usage::PW),
+			#[cfg(feature = "embedded-country-py")] PY => Ok(&::regional_usage::PY),
+			#[cfg(feature = "embedded-country-qa")] QA => Ok(&::regional_usage::QA),
+			#[cfg(feature = "embedded-country-re")] RE => Ok(&::regional_usage::RE),
+			#[cfg(feature = "embedded-country-ro")] RO => Ok(&::regional_usage::RO),
+			#[cfg(feature = "embedded-country-rs")] RS => Ok(&::regional_usage::RS),
+			#[cfg(feature = "embedded-country-ru")] RU => Ok(&::regional_usage::RU),
+			#[cfg(feature = "embedded-country-rw")] RW => Ok(&::regional_usage::RW),
+			#[cfg(feature = "embedded-country-sa")] SA => Ok(&::regional_usage::SA),
+			#[cfg(feature = "embedded-country-sb")] SB => Ok(&::regional_usage::SB),
+			#[cfg(feature = "embedded-country-sc")] SC => Ok(&::regional_usage::SC),
+			#[cfg(feature = "embedded-country-sd")] SD => Ok(&::regional_usage::SD),
+			#[cfg(feature = "embedded-country-se")] SE => Ok(&::regional_usage::SE),
+			#[cfg(feature = "embedded-country-sg")] SG => Ok(&::regional_usage::SG),
+			#[cfg(feature = "embedded-country-sh")] SH => Ok(&::regional_usage::SH),
+			#[cfg(feature = "embedded-country-si")] SI => Ok(&::regional_usage::SI),
+			#[cfg(feature = "embedded-country-sk")] SK => Ok(&::regional_usage::SK),
+			#[cfg(feature = "embedded-country-sl")] SL => Ok(&::regional_usage::SL),
+			#[cfg(feature = "embedded-country-sm")] SM => Ok(&::regional_usage::SM),
+			#[cfg(feature = "embedded-country-sn")] SN => Ok(&::regional_usage::SN),
+			#[cfg(feature = "embedded-country-so")] SO => Ok(&::regional_usage::SO),
+			#[cfg(feature = "embedded-country-sr")] SR => Ok(&::regional_usage::SR),
+			#[cfg(feature = "embedded-country-st")] ST => Ok(&::regional_usage::ST),
+			#[cfg(feature = "embedded-country-sv")] SV => Ok(&::regional_usage::SV),
+			#[cfg(feature = "embedded-country-sy")] SY => Ok(&::regional_usage::SY),
+			#[cfg(feature = "embedded-country-sz")] SZ => Ok(&::regional_usage::SZ),
+			#[cfg(feature = "embedded-country-tc")] TC => Ok(&::regional_usage::TC),
+			#[cfg(feature = "embedded-country-td")] TD => Ok(&::regional_usage::TD),
+			#[cfg(feature = "embedded-country-tg")] TG => Ok(&::regional_usage::TG),
+			#[cfg(feature = "embedded-country-th")] TH => Ok(&::regional_usage::TH),
+			#[cfg(feature = "embedded-country-tj")] TJ => Ok(&::regional_usage::TJ),
+			#[cfg(feature = "embedded-country-tk")] TK => Ok(&::regional_usage::TK),
+			#[cfg(feature = "embedded-country-tl")] TL => Ok(&::regional_usage::TL),
+			#[cfg(feature = "embedded-country-tm")] TM => Ok(&::regional_usage::TM),
+			#[cfg(feature = "embedded-country-tn")] TN => Ok(&::regional_usage::TN),
+			#[cfg(feature = "embedded-country-to")] TO => Ok(&::regional_usage::TO),
+			#[cfg(feature = "embedded-country-tr")] TR => Ok(&::regional_usage::TR),
+			#[cfg(feature = "embedded-country-tt")] TT => Ok(&::regional_usage::TT),
+			#[cfg(feature = "embedded-country-tv")] TV => Ok(&::regional_usage::TV),
+			#[cfg(feature = "embedded-country-tw")] TW => Ok(&::regional_usage::TW),
+			#[cfg(feature = "embedded-country-tz")] TZ => Ok(&::regional_usage::TZ),
+			#[cfg(feature = "embedded-country-ua")] UA => Ok(&::regional_usage::UA),
+			#[cfg(feature = "embedded-country-ug")] UG => Ok(&::regional_usage::UG),
+			#[cfg(feature = "embedded-country-us")] US => Ok(&::regional_usage::US),
+			#[cfg(feature = "embedded-country-uy")] UY => Ok(&::regional_usage::UY),
+			#[cfg(feature = "embedded-country-uz")] UZ => Ok(&::regional_usage::UZ),
+			#[cfg(feature = "embedded-country-va")] VA => Ok(&::regional_usage::VA),
+			#[cfg(feature = "embedded-country-vc")] VC => Ok(&::regional_usage::VC),
+			#[cfg(feature = "embedded-country-ve")] VE => Ok(&::regional_usage::VE),
+			#[cfg(feature = "embedded-country-vg")] VG => Ok(&::regional_usage::VG),
+			#[cfg(feature = "embedded-country-vi")] VI => Ok(&::regional_usage::VI),
+			#[cfg(feature = "embedded-country-vn")] VN => Ok(&::regional_usage::VN),
+			#[cfg(feature = "embedded-country-vu")] VU => Ok(&::regional_usage::VU),
+			#[cfg(feature = "embedded-country-wf")] WF => Ok(&::regional_usage::WF),
+			#[cfg(feature = "embedded-country-ws")] WS => Ok(&::regional_usage::WS),
+			#[cfg(feature = "embedded-country-ye")] YE => Ok(&::regional_usage::YE),
+			#[cfg(feature = "embedded-country-yt")] YT => Ok(&::regional_usage::YT),
+			#[cfg(feature = "embedded-country-za")] ZA => Ok(&::regional_usage::ZA),
+			#[cfg(feature = "embedded-country-zm")] ZM => Ok(&::regional_usage::ZM),
+			#[cfg(feature = "embedded-country-zw")] ZW => Ok(&::regional_usage::ZW),
 			
-			AD => &::regional_usage::AD,
-			AE => &::regional_usage::AE,
-			AF => &::regional_usage::AF,
-			AG => &::regional_usage::AG,
-			AI => &::regional_usage::AI,
-			AL => &::regional_usage::AL,
-			AM => &::regional_usage::AM,
-			AN => &::regional_usage::AN,
-			AO => &::regional_usage::AO,
-			AR => &::regional_usage::AR,
-			AS => &::regional_usage::AS,
-			AT => &::regional_usage::AT,
-			AU => &::regional_usage::AU,
-			AW => &::regional_usage::AW,
-			AX => &::regional_usage::AX,
-			AZ => &::regional_usage::AZ,
-			BA => &::regional_usage::BA,
-			BB => &::regional_usage::BB,
-			BD => &::regional_usage::BD,
-			BE => &::regional_usage::BE,
-			BF => &::regional_usage::BF,
-			BG => &::regional_usage::BG,
-			BH => &::regional_usage::BH,
-			BI => &::regional_usage::BI,
-			BJ => &::regional_usage::BJ,
-			BM => &::regional_usage::BM,
-			BN => &::regional_usage::BN,
-			BO => &::regional_usage::BO,
-			BR => &::regional_usage::BR,
-			BS => &::regional_usage::BS,
-			BT => &::regional_usage::BT,
-			BW => &::regional_usage::BW,
-			BY => &::regional_usage::BY,
-			BZ => &::regional_usage::BZ,
-			CA => &::regional_usage::CA,
-			CD => &::regional_usage::CD,
-			CF => &::regional_usage::CF,
-			CG => &::regional_usage::CG,
-			CH => &::regional_usage::CH,
-			CI => &::regional_usage::CI,
-			CK => &::regional_usage::CK,
-			CL => &::regional_usage::CL,
-			CM => &::regional_usage::CM,
-			CN => &::regional_usage::CN,
-			CO => &::regional_usage::CO,
-			CR => &::regional_usage::CR,
-			CU => &::regional_usage::CU,
-			CV => &::regional_usage::CV,
-			CX => &::regional_usage::CX,
-			CY => &::regional_usage::CY,
-			CZ => &::regional_usage::CZ,
-			DE => &::regional_usage::DE,
-			DJ => &::regional_usage::DJ,
-			DK => &::regional_usage::DK,
-			DM => &::regional_usage::DM,
-			DO => &::regional_usage::DO,
-			DZ => &::regional_usage::DZ,
-			EC => &::regional_usage::EC,
-			EE => &::regional_usage::EE,
-			EG => &::regional_usage::EG,
-			ER => &::regional_usage::ER,
-			ES => &::regional_usage::ES,
-			ET => &::regional_usage::ET,
-			FI => &::regional_usage::FI,
-			FJ => &::regional_usage::FJ,
-			FK => &::regional_usage::FK,
-			FM => &::regional_usage::FM,
-			FO => &::regional_usage::FO,
-			FR => &::regional_usage::FR,
-			GA => &::regional_usage::GA,
-			GB => &::regional_usage::GB,
-			GD => &::regional_usage::GD,
-			GE => &::regional_usage::GE,
-			GF => &::regional_usage::GF,
-			GG => &::regional_usage::GG,
-			GH => &::regional_usage::GH,
-			GI => &::regional_usage::GI,
-			GL => &::regional_usage::GL,
-			GM => &::regional_usage::GM,
-			GN => &::regional_usage::GN,
-			GP => &::regional_usage::GP,
-			GQ => &::regional_usage::GQ,
-			GR => &::regional_usage::GR,
-			GT => &::regional_usage::GT,
-			GU => &::regional_usage::GU,
-			GW => &::regional_usage::GW,
-			GY => &::regional_usage::GY,
-			HK => &::regional_usage::HK,
-			HN => &::regional_usage::HN,
-			HR => &::regional_usage::HR,
-			HT => &::regional_usage::HT,
-			HU => &::regional_usage::HU,
-			ID => &::regional_usage::ID,
-			IE => &::regional_usage::IE,
-			IL => &::regional_usage::IL,
-			IM => &::regional_usage::IM,
-			IN => &::regional_usage::IN,
-			IQ => &::regional_usage::IQ,
-			IR => &::regional_usage::IR,
-			IS => &::regional_usage::IS,
-			IT => &::regional_usage::IT,
-			JE => &::regional_usage::JE,
-			JM => &::regional_usage::JM,
-			JO => &::regional_usage::JO,
-			JP => &::regional_usage::JP,
-			KE => &::regional_usage::KE,
-			KG => &::regional_usage::KG,
-			KH => &::regional_usage::KH,
-			KI => &::regional_usage::KI,
-			KM => &::regional_usage::KM,
-			KN => &::regional_usage::KN,
-			KP => &::regional_usage::KP,
-			KR => &::regional_usage::KR,
-			KW => &::regional_usage::KW,
-			KY => &::regional_usage::KY,
-			KZ => &::regional_usage::KZ,
-			LA => &::regional_usage::LA,
-			LB => &::regional_usage::LB,
-			LC => &::regional_usage::LC,
-			LI => &::regional_usage::LI,
-			LK => &::regional_usage::LK,
-			LR => &::regional_usage::LR,
-			LS => &::regional_usage::LS,
-			LT => &::regional_usage::LT,
-			LU => &::regional_usage::LU,
-			LV => &::regional_usage::LV,
-			LY => &::regional_usage::LY,
-			MA => &::regional_usage::MA,
-			MC => &::regional_usage::MC,
-			MD => &::regional_usage::MD,
-			ME => &::regional_usage::ME,
-			MG => &::regional_usage::MG,
-			MH => &::regional_usage::MH,
-			MK => &::regional_usage::MK,
-			ML => &::regional_usage::ML,
-			MM => &::regional_usage::MM,
-			MN => &::regional_usage::MN,
-			MO => &::regional_usage::MO,
-			MP => &::regional_usage::MP,
-			MQ => &::regional_usage::MQ,
-			MR => &::regional_usage::MR,
-			MS => &::regional_usage::MS,
-			MT => &::regional_usage::MT,
-			MU => &::regional_usage::MU,
-			MV => &::regional_usage::MV,
-			MW => &::regional_usage::MW,
-			MX => &::regional_usage::MX,
-			MY => &::regional_usage::MY,
-			MZ => &::regional_usage::MZ,
-			NA => &::regional_usage::NA,
-			NC => &::regional_usage::NC,
-			NE => &::regional_usage::NE,
-			NF => &::regional_usage::NF,
-			NG => &::regional_usage::NG,
-			NI => &::regional_usage::NI,
-			NL => &::regional_usage::NL,
-			NO => &::regional_usage::NO,
-			NP => &::regional_usage::NP,
-			NR => &::regional_usage::NR,
-			NU => &::regional_usage::NU,
-			NZ => &::regional_usage::NZ,
-			OM => &::regional_usage::OM,
-			PA => &::regional_usage::PA,
-			PE => &::regional_usage::PE,
-			PF => &::regional_usage::PF,
-			PG => &::regional_usage::PG,
-			PH => &::regional_usage::PH,
-			PK => &::regional_usage::PK,
-			PL => &::regional_usage::PL,
-			PM => &::regional_usage::PM,
-			PN => &::regional_usage::PN,
-			PR => &::regional_usage::PR,
-			PS => &::regional_usage::PS,
-			PT => &::regional_usage::PT,
-			PW => &::regional_usage::PW,
-			PY => &::regional_usage::PY,
-			QA => &::regional_usage::QA,
-			RE => &::regional_usage::RE,
-			RO => &::regional_usage::RO,
-			RS => &::regional_usage::RS,
-			RU => &::regional_usage::RU,
-			RW => &::regional_usage::RW,
-			SA => &::regional_usage::SA,
-			SB => &::regional_usage::SB,
-			SC => &::regional_usage::SC,
-			SD => &::regional_usage::SD,
-			SE => &::regional_usage::SE,
-			SG => &::regional_usage::SG,
-			SH => &::regional_usage::SH,
-			SI => &::regional_usage::SI,
-			SK => &::regional_usage::SK,
-			SL => &::regional_usage::SL,
-			SM => &::regional_usage::SM,
-			SN => &::regional_usage::SN,
-			SO => &::regional_usage::SO,
-			SR => &::regional_usage::SR,
-			ST => &::regional_usage::ST,
-			SV => &::regional_usage::SV,
-			SY => &::regional_usage::SY,
-			SZ => &::regional_usage::SZ,
-			TC => &::regional_usage::TC,
-			TD => &::regional_usage::TD,
-			TG => &::regional_usage::TG,
-			TH => &::regional_usage::TH,
-			TJ => &::regional_usage::TJ,
-			TK => &::regional_usage::TK,
-			TL => &::regional_usage::TL,
-			TM => &::regional_usage::TM,
-			TN => &::regional_usage::TN,
-			TO => &::regional_usage::TO,
-			TR => &::regional_usage::TR,
-			TT => &::regional_usage::TT,
-			TV => &::regional_usage::TV,
-			TW => &::regional_usage::TW,
-			TZ => &::regional_usage::TZ,
-			UA => &::regional_usage::UA,
-			UG => &::regional_usage::UG,
-			US => &::regional_usage::US,
-			UY => &::regional_usage::UY,
-			UZ => &::regional_usage::UZ,
-			VA => &::regional_usage::VA,
-			VC => &::regional_usage::VC,
-			VE => &::regional_usage::VE,
-			VG => &::regional_usage::VG,
-			VI => &::regional_usage::VI,
-			VN => &::regional_usage::VN,
-			VU => &::regional_usage::VU,
-			WF => &::regional_usage::WF,
-			WS => &::regional_usage::WS,
-			YE => &::regional_usage::YE,
-			YT => &::regional_usage::YT,
-			ZA => &::regional_usage::ZA,
-			ZM => &::regional_usage::ZM,
-			ZW => &::regional_usage::ZW,
+			_ => Err(RegionalUsageNotEmbeddedError(*self)),
 		}
 	}
 	
@@ -3,8 +3,9 @@
 
 
 /// Represents the error that can occur when parsing a str to get a Regional Usages.
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
-pub struct RegionalUsagesFromStrError;
+/// Contains the caniuse ISO-like code that failed to parse.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct RegionalUsagesFromStrError(pub String);
 
 impl Error for RegionalUsagesFromStrError
 {
@@ -26,6 +27,6 @@ impl Display for RegionalUsagesFromStrError
 	#[inline(always)]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
 	{
-		write!(f, "{}", self.description())
+		write!(f, "unknown caniuse ISO-like code '{}'", self.0)
 	}
 }
@@ -97,6 +97,16 @@ impl<'de> Deserialize<'de> for YearMonth
 	}
 }
 
+impl Serialize for YearMonth
+{
+	/// Serialize using Serde
+	#[inline(always)]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		serializer.serialize_str(&format!("{:04}-{:02}", self.year, self.one_based_month))
+	}
+}
+
 impl YearMonth
 {
 	/// Year, eg 2017. Does not support BC, and note there is no such thing as AD 0...
@@ -14,12 +14,19 @@ use ::std::fmt;
 use ::std::fmt::Display;
 use ::std::fmt::Formatter;
 use ::std::fs::File;
+use ::std::fs::read_dir;
+use ::std::io;
 use ::std::io::Read;
+use ::std::io::Write;
 use ::std::path::Path;
 use ::std::str::FromStr;
 
 
+include!("EmbeddedRegionalUsages.rs");
 include!("RegionalUsage.rs");
+include!("RegionalUsageNotEmbeddedError.rs");
+include!("RegionalUsageRegistry.rs");
+include!("RegionalUsageSource.rs");
 include!("RegionalUsages.rs");
 include!("RegionalUsagesFromStrError.rs");
 include!("YearMonth.rs");
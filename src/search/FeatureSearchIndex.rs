@@ -0,0 +1,162 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+impl CanIUse
+{
+	/// Searches feature names, titles, keywords, browser-specific feature identifiers and descriptions, eg for "flexbox gap" or "webp".
+	/// Results are in descending order of score, and then of feature name; see `FeatureSearchIndex::search()`.
+	/// Builds a `FeatureSearchIndex` each time; create one directly to search repeatedly.
+	#[inline(always)]
+	pub fn search(&self, query: &str) -> Vec<(FeatureName, u32)>
+	{
+		FeatureSearchIndex::new(self).search(query)
+	}
+}
+
+/// An index of the words in the features of a caniuse.com database, tolerant of prefixes and typos.
+#[derive(Debug, Clone)]
+pub struct FeatureSearchIndex
+{
+	features: Vec<(FeatureName, HashMap<String, u32>)>,
+}
+
+impl FeatureSearchIndex
+{
+	const FeatureNameWeight: u32 = 10;
+	
+	const TitleWeight: u32 = 8;
+	
+	const KeywordWeight: u32 = 6;
+	
+	const FeatureIdentifierWeight: u32 = 4;
+	
+	const DescriptionWeight: u32 = 1;
+	
+	const ExactMatch: u32 = 4;
+	
+	const PrefixMatch: u32 = 3;
+	
+	const OneTypoMatch: u32 = 2;
+	
+	const TwoTyposMatch: u32 = 1;
+	
+	/// Indexes the features of `can_i_use`, including any imported from MDN's browser-compat-data.
+	#[inline(always)]
+	pub fn new(can_i_use: &CanIUse) -> Self
+	{
+		let mut features: Vec<(FeatureName, HashMap<String, u32>)> = can_i_use.features.iter().map(|(feature_name, feature_detail)|
+		{
+			let mut words = HashMap::new();
+			{
+				let mut index = |text: &str, weight: u32| FeatureSearchTokens::tokenize(text, |token|
+				{
+					let best_weight = words.entry(token).or_insert(weight);
+					if *best_weight < weight
+					{
+						*best_weight = weight;
+					}
+				});
+				
+				index(feature_name, Self::FeatureNameWeight);
+				index(&feature_detail.title, Self::TitleWeight);
+				for keyword in feature_detail.keywords.iter()
+				{
+					index(keyword, Self::KeywordWeight);
+				}
+				for feature_identifier in feature_detail.internet_explorer_feature_identifiers.iter().chain(feature_detail.blink_feature_identifiers.iter()).chain(feature_detail.firefox_feature_identifiers.iter()).chain(feature_detail.webkit_feature_identifiers.iter())
+				{
+					index(feature_identifier, Self::FeatureIdentifierWeight);
+				}
+				index(&feature_detail.description, Self::DescriptionWeight);
+			}
+			(feature_name.clone(), words)
+		}).collect();
+		features.sort_by(|left, right| left.0.cmp(&right.0));
+		
+		FeatureSearchIndex
+		{
+			features,
+		}
+	}
+	
+	/// Searches for features matching any word of `query`, in descending order of score, and then of feature name; features matching no words are omitted.
+	///
+	/// A feature's score is the sum, for each word in `query`, of its best match with a word in the feature, weighted by where the word is found: the feature name counts most, then its title, keywords, browser-specific feature identifiers and, least, its description.
+	/// A word matches exactly, as a prefix (of three or more letters), with one typo (words of four or more letters) or with two typos (words of eight or more letters); a typo is an inserted, deleted, substituted or transposed letter.
+	/// Words are split on punctuation and on changes of case, so "flexbox gap" finds `flexbox-gap` and "grid layout" finds the Blink identifier `CSSGridLayout`.
+	pub fn search(&self, query: &str) -> Vec<(FeatureName, u32)>
+	{
+		let mut query_words = Vec::new();
+		FeatureSearchTokens::tokenize(query, |token| if !query_words.contains(&token)
+		{
+			query_words.push(token)
+		});
+		
+		let mut results: Vec<(FeatureName, u32)> = self.features.iter().filter_map(|(feature_name, words)|
+		{
+			let score: u32 = query_words.iter().map(|query_word| words.iter().map(|(word, weight)| weight * Self::match_quality(query_word, word)).max().unwrap_or(0)).sum();
+			if score == 0
+			{
+				None
+			}
+			else
+			{
+				Some((feature_name.clone(), score))
+			}
+		}).collect();
+		
+		results.sort_by(|&(ref left_feature_name, left_score), &(ref right_feature_name, right_score)| right_score.cmp(&left_score).then_with(|| left_feature_name.cmp(right_feature_name)));
+		results
+	}
+	
+	#[inline(always)]
+	fn match_quality(query_word: &str, word: &str) -> u32
+	{
+		if query_word == word
+		{
+			return Self::ExactMatch;
+		}
+		
+		let query_word: Vec<char> = query_word.chars().collect();
+		let word: Vec<char> = word.chars().collect();
+		
+		if query_word.len() >= 3 && word.starts_with(&query_word)
+		{
+			return Self::PrefixMatch;
+		}
+		
+		let maximum_typos = if query_word.len() >= 8
+		{
+			2
+		}
+		else if query_word.len() >= 4
+		{
+			1
+		}
+		else
+		{
+			return 0
+		};
+		
+		let length_difference = if query_word.len() > word.len()
+		{
+			query_word.len() - word.len()
+		}
+		else
+		{
+			word.len() - query_word.len()
+		};
+		if length_difference > maximum_typos
+		{
+			return 0;
+		}
+		
+		match FeatureSearchTokens::typos(&query_word, &word)
+		{
+			1 => Self::OneTypoMatch,
+			2 if maximum_typos == 2 => Self::TwoTyposMatch,
+			_ => 0,
+		}
+	}
+}
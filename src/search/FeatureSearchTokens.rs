@@ -0,0 +1,83 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Splitting text into lower case words, and comparing them.
+#[derive(Debug, Copy, Clone)]
+struct FeatureSearchTokens;
+
+impl FeatureSearchTokens
+{
+	/// Splits `text` on anything other than letters and digits; a word with changes of case, such as `CSSGridLayout`, is also split into its parts (`css`, `grid` and `layout`).
+	#[inline(always)]
+	fn tokenize<F: FnMut(String)>(text: &str, mut token: F)
+	{
+		for word in text.split(|character: char| !character.is_alphanumeric()).filter(|word| !word.is_empty())
+		{
+			let characters: Vec<char> = word.chars().collect();
+			let mut parts = Vec::new();
+			let mut start = 0;
+			for index in 1 .. characters.len()
+			{
+				let previous = characters[index - 1];
+				let current = characters[index];
+				let next_is_lower_case = index + 1 < characters.len() && characters[index + 1].is_lowercase();
+				if current.is_uppercase() && (previous.is_lowercase() || previous.is_numeric() || (previous.is_uppercase() && next_is_lower_case))
+				{
+					parts.push(&characters[start .. index]);
+					start = index;
+				}
+			}
+			
+			token(word.to_lowercase());
+			if start != 0
+			{
+				parts.push(&characters[start ..]);
+				for part in parts
+				{
+					token(part.iter().collect::<String>().to_lowercase());
+				}
+			}
+		}
+	}
+	
+	/// The number of inserted, deleted, substituted or transposed characters to turn `left` into `right` (the optimal string alignment distance).
+	#[inline(always)]
+	fn typos(left: &[char], right: &[char]) -> usize
+	{
+		let width = right.len() + 1;
+		let mut distances = vec![0; (left.len() + 1) * width];
+		for left_index in 0 .. left.len() + 1
+		{
+			distances[left_index * width] = left_index;
+		}
+		for (right_index, distance) in distances.iter_mut().take(width).enumerate()
+		{
+			*distance = right_index;
+		}
+		
+		for left_index in 1 .. left.len() + 1
+		{
+			for right_index in 1 .. right.len() + 1
+			{
+				let substitution = if left[left_index - 1] == right[right_index - 1]
+				{
+					0
+				}
+				else
+				{
+					1
+				};
+				
+				let mut distance = min(min(distances[(left_index - 1) * width + right_index] + 1, distances[left_index * width + right_index - 1] + 1), distances[(left_index - 1) * width + right_index - 1] + substitution);
+				if left_index > 1 && right_index > 1 && left[left_index - 1] == right[right_index - 2] && left[left_index - 2] == right[right_index - 1]
+				{
+					distance = min(distance, distances[(left_index - 2) * width + right_index - 2] + 1);
+				}
+				distances[left_index * width + right_index] = distance;
+			}
+		}
+		
+		distances[left.len() * width + right.len()]
+	}
+}
@@ -3,6 +3,7 @@
 
 
 /// Obtain (CanIUse, AgentNameAndVersionSet) for, say, `autoprefix_stylesheet()` in the `css-autoprefix` crate.
+#[cfg(feature = "embedded-database")]
 #[inline(always)]
 pub fn sensible_choices(maximum_release_age_from_can_i_use_database_last_updated_in_weeks: u16, minimum_usage_threshold: UsagePercentage, regional_usages: &[&RegionalUsage]) -> (CanIUse, AgentNameAndVersionSet)
 {
@@ -2,22 +2,25 @@
 // Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
 
 
+/// The regions whose usage is taken into account by `sensible_choices_default()`.
+pub const SensibleChoicesDefaultRegionalUsages: [RegionalUsages; 6] = [RegionalUsages::Asia, RegionalUsages::Europe, RegionalUsages::NorthAmerica, RegionalUsages::SouthAmerica, RegionalUsages::AU, RegionalUsages::NZ];
+
 /// Obtain (CanIUse, AgentNameAndVersionSet) for, say, `autoprefix_stylesheet()` in the `css-autoprefix` crate.
+#[cfg(all(feature = "embedded-database", feature = "embedded-continents", feature = "embedded-country-au", feature = "embedded-country-nz"))]
 #[inline(always)]
 pub fn sensible_choices_default() -> (CanIUse, AgentNameAndVersionSet)
 {
-	use ::regional_usage::RegionalUsages::*;
-	
-	let maximum_release_age_from_can_i_use_database_last_updated = 54 + 12; // Firefox ESR release cycle + 12 weeks (2x cycles overlap)
+	let can_i_use = CanIUse::default();
+	let regional_usages: Vec<&RegionalUsage> = SensibleChoicesDefaultRegionalUsages.iter().map(|regional_usages| regional_usages.regional_usage().unwrap()).collect();
+	let choices = sensible_choices_default_for(&can_i_use, &regional_usages);
+	(can_i_use, choices)
+}
+
+/// As `sensible_choices_default()`, but for any database, eg one loaded from a checkout of the caniuse.com git repository, and its regional usages of `SensibleChoicesDefaultRegionalUsages`.
+#[inline(always)]
+pub fn sensible_choices_default_for(can_i_use: &CanIUse, regional_usages: &[&RegionalUsage]) -> AgentNameAndVersionSet
+{
+	let maximum_release_age_from_can_i_use_database_last_updated = Duration::weeks(54 + 12); // Firefox ESR release cycle + 12 weeks (2x cycles overlap)
 	let minimum_usage_threshold = UsagePercentage::OnePerMille;
-	let regional_usages = vec!
-	[
-		Asia.regional_usage(),
-		Europe.regional_usage(),
-		NorthAmerica.regional_usage(),
-		SouthAmerica.regional_usage(),
-		AU.regional_usage(),
-		NZ.regional_usage(),
-	];
-	sensible_choices(maximum_release_age_from_can_i_use_database_last_updated, minimum_usage_threshold, &regional_usages)
+	AgentNameAndVersionSet::a_sensible_set_of_choices_for_an_international_website_in_multiple_languages(can_i_use, maximum_release_age_from_can_i_use_database_last_updated, minimum_usage_threshold, regional_usages)
 }
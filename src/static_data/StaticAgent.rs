@@ -0,0 +1,89 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Static equivalent of `Agent`.
+#[derive(Debug, Copy, Clone)]
+pub struct StaticAgent(&'static StaticAgentDetail);
+
+impl StaticAgent
+{
+	/// Agent name
+	#[inline(always)]
+	pub fn agent_name(&self) -> AgentName
+	{
+		from_can_i_use_identifier(self.0.agent_name)
+	}
+	
+	/// Agent browser name
+	#[inline(always)]
+	pub fn browser_name(&self) -> &'static str
+	{
+		self.0.name
+	}
+	
+	/// Agent detail abbreviated name, eg 'Chr.' for chrome
+	#[inline(always)]
+	pub fn abbreviated_name(&self) -> &'static str
+	{
+		self.0.abbreviated_name
+	}
+	
+	/// prefix to use for this particular version (lacks leading and trailing dash)
+	#[inline(always)]
+	pub fn prefix(&self, version: &str) -> Prefix
+	{
+		match self.0.prefix_exceptions.iter().find(|&&(prefix_exception_version, _)| prefix_exception_version == version)
+		{
+			Some(&(_, prefix)) => from_can_i_use_identifier(prefix),
+			None => from_can_i_use_identifier(self.0.prefix),
+		}
+	}
+	
+	/// Is this a desktop or mobile agent?
+	#[inline(always)]
+	pub fn agent_type(&self) -> AgentType
+	{
+		self.0.agent_type
+	}
+	
+	/// Global usage; see `Agent::global_usage()`.
+	#[inline(always)]
+	pub fn global_usage(&self, version: &str) -> Option<UsagePercentage>
+	{
+		self.0.usage_global.iter().find(|&&(usage_version, _)| usage_version == version).map(|&(_, usage)| UsagePercentage::new(usage))
+	}
+	
+	/// Details of every known version, in order.
+	#[inline(always)]
+	pub fn version_details(&self) -> &'static [StaticVersionDetail]
+	{
+		self.0.version_list
+	}
+	
+	/// Current version as of `StaticCanIUse.last_updated()`.
+	#[inline(always)]
+	pub fn current_version(&self) -> &'static str
+	{
+		self.0.current_version
+	}
+	
+	/// Details of versions that are current or older
+	#[inline(always)]
+	pub fn version_details_for_current_and_older_versions(&self) -> &'static [StaticVersionDetail]
+	{
+		let version_list = self.0.version_list;
+		match version_list.iter().position(|version_detail| version_detail.version == self.0.current_version)
+		{
+			None => version_list,
+			Some(index) => &version_list[..index + 1],
+		}
+	}
+	
+	/// Details of version
+	#[inline(always)]
+	pub fn version_detail(&self, version: &str) -> Option<&'static StaticVersionDetail>
+	{
+		self.0.version_list.iter().find(|version_detail| version_detail.version == version)
+	}
+}
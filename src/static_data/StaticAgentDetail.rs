@@ -0,0 +1,20 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Static equivalent of an agent's details; constructed by code generated with `CanIUse::write_static_rust_source()`.
+/// Use `StaticCanIUse::agent()` to obtain a `StaticAgent` rather than using this directly.
+#[allow(missing_docs)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StaticAgentDetail
+{
+	pub agent_name: &'static str,
+	pub name: &'static str,
+	pub abbreviated_name: &'static str,
+	pub prefix: &'static str,
+	pub agent_type: AgentType,
+	pub usage_global: &'static [(&'static str, f64)],
+	pub version_list: &'static [StaticVersionDetail],
+	pub current_version: &'static str,
+	pub prefix_exceptions: &'static [(&'static str, &'static str)],
+}
@@ -0,0 +1,89 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// A caniuse.com database compiled into static tables, so that there is no parsing or heap copy at start up.
+/// Construct with code generated by `CanIUse::write_static_rust_source()`, typically from a build script.
+/// The accessors mirror those of `CanIUse`, `Agent`, `Feature` and `Support`, except that versions and feature names are `&str`.
+#[allow(missing_docs)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StaticCanIUse
+{
+	pub agents: &'static [StaticAgentDetail],
+	pub statuses: &'static [(&'static str, &'static str)],
+	pub child_categories: &'static [(&'static str, &'static [&'static str])],
+	pub updated: i64,
+	/// Sorted by feature name.
+	pub features: &'static [StaticFeatureDetail],
+}
+
+impl StaticCanIUse
+{
+	/// A timestamp of when this particular database was last updated.
+	#[inline(always)]
+	pub fn last_updated(&self) -> DateTime<Utc>
+	{
+		Utc.timestamp_opt(self.updated, 0).single().expect("updated is a timestamp written from a DateTime<Utc>")
+	}
+	
+	/// Known agents.
+	#[inline(always)]
+	pub fn known_agent_names(&self) -> Map<Iter<'static, StaticAgentDetail>, fn(&'static StaticAgentDetail) -> AgentName>
+	{
+		#[inline(always)]
+		fn agent_name(agent_detail: &'static StaticAgentDetail) -> AgentName
+		{
+			from_can_i_use_identifier(agent_detail.agent_name)
+		}
+		
+		self.agents.iter().map(agent_name)
+	}
+	
+	/// Known features, in order.
+	#[inline(always)]
+	pub fn known_feature_names(&self) -> Map<Iter<'static, StaticFeatureDetail>, fn(&'static StaticFeatureDetail) -> &'static str>
+	{
+		#[inline(always)]
+		fn feature_name(feature_detail: &'static StaticFeatureDetail) -> &'static str
+		{
+			feature_detail.feature_name
+		}
+		
+		self.features.iter().map(feature_name)
+	}
+	
+	/// Find an agent.
+	#[inline(always)]
+	pub fn agent(&self, agent_name: &AgentName) -> Option<StaticAgent>
+	{
+		let can_i_use_identifier = agent_name.can_i_use_identifier();
+		self.agents.iter().find(|agent_detail| agent_detail.agent_name == can_i_use_identifier).map(StaticAgent)
+	}
+	
+	/// Find a feature.
+	#[inline(always)]
+	pub fn feature(&self, feature_name: &str) -> Option<StaticFeature>
+	{
+		match self.features.binary_search_by(|feature_detail| feature_detail.feature_name.cmp(feature_name))
+		{
+			Err(_) => None,
+			Ok(index) => Some(StaticFeature(&self.features[index])),
+		}
+	}
+	
+	/// Description of a status, eg "W3C Recommendation".
+	#[inline(always)]
+	pub fn status_description(&self, status: &Status) -> Option<&'static str>
+	{
+		let can_i_use_identifier = status.can_i_use_identifier();
+		self.statuses.iter().find(|&&(status, _)| status == can_i_use_identifier).map(|&(_, description)| description)
+	}
+	
+	/// Child categories of a parent category.
+	#[inline(always)]
+	pub fn child_categories(&self, parent_category: &ParentCategory) -> Option<StaticCategories>
+	{
+		let can_i_use_identifier = parent_category.can_i_use_identifier();
+		self.child_categories.iter().find(|&&(parent_category, _)| parent_category == can_i_use_identifier).map(|&(_, child_categories)| child_categories.iter().map(from_can_i_use_identifier_reference as fn(&&'static str) -> Category))
+	}
+}
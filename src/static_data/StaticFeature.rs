@@ -0,0 +1,166 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Static equivalent of `Feature`.
+#[derive(Debug, Copy, Clone)]
+pub struct StaticFeature(&'static StaticFeatureDetail);
+
+impl StaticFeature
+{
+	/// The name of this feature.
+	#[inline(always)]
+	pub fn feature_name(&self) -> &'static str
+	{
+		self.0.feature_name
+	}
+	
+	/// The title of this feature.
+	#[inline(always)]
+	pub fn title(&self) -> &'static str
+	{
+		self.0.title
+	}
+	
+	/// The description of this feature.
+	#[inline(always)]
+	pub fn description(&self) -> &'static str
+	{
+		self.0.description
+	}
+	
+	/// The URL at which the specification of this feature can be found.
+	#[inline(always)]
+	pub fn specification_url(&self) -> &'static str
+	{
+		self.0.specification_url
+	}
+	
+	/// The status of this feature.
+	#[inline(always)]
+	pub fn status(&self) -> Status
+	{
+		from_can_i_use_identifier(self.0.status)
+	}
+	
+	/// Links to additional documents detailing this feature or aspects of it, as pairs of URL and title.
+	#[inline(always)]
+	pub fn links(&self) -> &'static [(&'static str, &'static str)]
+	{
+		self.0.links
+	}
+	
+	/// Any bugs with this feature. Rarely used in the caniuse.com database.
+	#[inline(always)]
+	pub fn bugs(&self) -> &'static [&'static str]
+	{
+		self.0.bugs
+	}
+	
+	/// The caniuse.com database's categorisations of this feature.
+	#[inline(always)]
+	pub fn categories(&self) -> StaticCategories
+	{
+		self.0.categories.iter().map(from_can_i_use_identifier_reference as fn(&&'static str) -> Category)
+	}
+	
+	/// The caniuse.com database's notes on this feature.
+	#[inline(always)]
+	pub fn general_notes(&self) -> &'static str
+	{
+		self.0.notes
+	}
+	
+	/// implementation; returns None if agent_name has no known usages.
+	/// version need not be known; it is resolved to a known version as `Feature::implementation()` does.
+	/// returns Some(None) if agent_name exists but not for the version.
+	/// returns Some(Some(support) if agent_name exists and the version has known support
+	#[inline(always)]
+	pub fn implementation(&self, agent_name: &AgentName, version: &str) -> Option<Option<StaticSupport>>
+	{
+		let can_i_use_identifier = agent_name.can_i_use_identifier();
+		match self.0.implementations_by_agents.iter().find(|&&(agent_name, _)| agent_name == can_i_use_identifier)
+		{
+			None => None,
+			Some(&(_, implementations)) =>
+			{
+				let feature_detail = self.0;
+				Some(resolve_static_version(implementations, version).map(|support_detail| StaticSupport
+				{
+					support_detail,
+					feature_detail,
+				}))
+			}
+		}
+	}
+	
+	/// The supported usage of this feature; those agents where the feature is SupportMaturity::SupportedByDefault.
+	#[inline(always)]
+	pub fn supported_by_default_usage(&self) -> UsagePercentage
+	{
+		UsagePercentage::new(self.0.supported_by_default_usage)
+	}
+	
+	/// The almost supported usage of this feature; those agents where the feature is SupportMaturity::AlmostSupported.
+	#[inline(always)]
+	pub fn almost_supported_usage(&self) -> UsagePercentage
+	{
+		UsagePercentage::new(self.0.almost_supported_usage)
+	}
+	
+	/// The parent feature this one belongs to use; not widely used by the caniuse.com's database.
+	#[inline(always)]
+	pub fn parent_feature_if_any(&self) -> Option<&'static str>
+	{
+		self.0.parent
+	}
+	
+	/// A list of keywords to make it easier to search for this feature.
+	#[inline(always)]
+	pub fn keywords(&self) -> &'static [&'static str]
+	{
+		self.0.keywords
+	}
+	
+	/// Identifiers to related MSDN sections.
+	#[inline(always)]
+	pub fn internet_explorer_feature_identifiers(&self) -> &'static [&'static str]
+	{
+		self.0.internet_explorer_feature_identifiers
+	}
+	
+	/// Identifiers to related blink (Google Chrome) bugs
+	#[inline(always)]
+	pub fn blink_feature_identifiers(&self) -> &'static [&'static str]
+	{
+		self.0.blink_feature_identifiers
+	}
+	
+	/// Identifiers to related Mozilla Firefox bugs
+	#[inline(always)]
+	pub fn firefox_feature_identifiers(&self) -> &'static [&'static str]
+	{
+		self.0.firefox_feature_identifiers
+	}
+	
+	/// Identifiers to related WebKit bugs
+	#[inline(always)]
+	pub fn webkit_feature_identifiers(&self) -> &'static [&'static str]
+	{
+		self.0.webkit_feature_identifiers
+	}
+	
+	/// Should any prefix be in uppercase?
+	#[inline(always)]
+	pub fn upper_case_prefix(&self) -> bool
+	{
+		self.0.upper_case_prefix
+	}
+	
+	/// Effectively, is this feature in 'draft' form?
+	#[inline(always)]
+	pub fn this_feature_is_not_yet_complete_or_accurate(&self) -> bool
+	{
+		!self.0.shown
+	}
+}
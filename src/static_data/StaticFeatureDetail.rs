@@ -0,0 +1,32 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Static equivalent of a feature's details; constructed by code generated with `CanIUse::write_static_rust_source()`.
+/// Use `StaticCanIUse::feature()` to obtain a `StaticFeature` rather than using this directly.
+#[allow(missing_docs)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StaticFeatureDetail
+{
+	pub feature_name: &'static str,
+	pub title: &'static str,
+	pub description: &'static str,
+	pub specification_url: &'static str,
+	pub status: &'static str,
+	pub links: &'static [(&'static str, &'static str)],
+	pub bugs: &'static [&'static str],
+	pub categories: &'static [&'static str],
+	pub implementations_by_agents: &'static [(&'static str, &'static [(&'static str, StaticSupportDetail)])],
+	pub notes: &'static str,
+	pub notes_by_one_based_number: &'static [(u8, &'static str)],
+	pub parent: Option<&'static str>,
+	pub supported_by_default_usage: f64,
+	pub almost_supported_usage: f64,
+	pub upper_case_prefix: bool,
+	pub keywords: &'static [&'static str],
+	pub internet_explorer_feature_identifiers: &'static [&'static str],
+	pub blink_feature_identifiers: &'static [&'static str],
+	pub firefox_feature_identifiers: &'static [&'static str],
+	pub webkit_feature_identifiers: &'static [&'static str],
+	pub shown: bool,
+}
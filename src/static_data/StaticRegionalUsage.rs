@@ -0,0 +1,71 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Static equivalent of `RegionalUsage`; construct with code generated by `RegionalUsage::write_static_rust_source()`.
+#[allow(missing_docs)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StaticRegionalUsage
+{
+	pub id: &'static str,
+	pub name: &'static str,
+	pub year: u16,
+	pub one_based_month: u8,
+	/// As `YYYY-MM-DD`.
+	pub access_date: &'static str,
+	pub data: &'static [(&'static str, StaticUsages)],
+	pub total: f64,
+}
+
+impl StaticRegionalUsage
+{
+	/// ISO-like code.
+	#[inline(always)]
+	pub fn identifier(&self) -> &'static str
+	{
+		self.id
+	}
+	
+	/// Country name or similar.
+	#[inline(always)]
+	pub fn country_or_region_name(&self) -> &'static str
+	{
+		self.name
+	}
+	
+	/// Total usage; may not add up to 100% (eg for Andorra, AD, adds up to about 95%).
+	#[inline(always)]
+	pub fn total(&self) -> UsagePercentage
+	{
+		UsagePercentage::new(self.total)
+	}
+	
+	/// Year of the usage data.
+	#[inline(always)]
+	pub fn year(&self) -> u16
+	{
+		self.year
+	}
+	
+	/// One-based month of the usage data.
+	#[inline(always)]
+	pub fn one_based_month(&self) -> u8
+	{
+		self.one_based_month
+	}
+	
+	/// When the usage data was accessed.
+	#[inline(always)]
+	pub fn access_date(&self) -> NaiveDate
+	{
+		NaiveDate::parse_from_str(self.access_date, "%Y-%m-%d").expect("Invalid static tables")
+	}
+	
+	/// Usage for a specific version; see `RegionalUsage::usage_of_version()`.
+	#[inline(always)]
+	pub fn usage_of_version(&self, agent_name: &AgentName, version: &str) -> Option<Option<Option<UsagePercentage>>>
+	{
+		let can_i_use_identifier = agent_name.can_i_use_identifier();
+		self.data.iter().find(|&&(agent_name, _)| agent_name == can_i_use_identifier).map(|&(_, usages)| resolve_static_version(usages, version).map(|usage| usage.map(UsagePercentage::new)))
+	}
+}
@@ -0,0 +1,47 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Static equivalent of `Support`.
+#[derive(Debug, Copy, Clone)]
+pub struct StaticSupport
+{
+	support_detail: &'static StaticSupportDetail,
+	feature_detail: &'static StaticFeatureDetail,
+}
+
+impl StaticSupport
+{
+	/// How mature is support?
+	#[inline(always)]
+	pub fn maturity(&self) -> SupportMaturity
+	{
+		self.support_detail.maturity
+	}
+	
+	/// Does support require a prefix?
+	#[inline(always)]
+	pub fn requires_prefix(&self) -> bool
+	{
+		self.support_detail.requires_prefix
+	}
+	
+	/// Is support behind a flag or some other mechanism that isn't normally enabled in a default install?
+	#[inline(always)]
+	pub fn disabled_by_default(&self) -> bool
+	{
+		self.support_detail.disabled_by_default
+	}
+	
+	/// Returns an iterator of pairs of one-based note numbers and note text
+	/// Note numbers the feature does not contain are skipped; this is only possible if the database the static tables were generated from is invalid
+	#[inline(always)]
+	pub fn notes(&self) -> StaticSupportNotes
+	{
+		StaticSupportNotes
+		{
+			note_numbers: self.support_detail.notes_by_one_based_number.iter(),
+			notes_by_one_based_number: self.feature_detail.notes_by_one_based_number,
+		}
+	}
+}
@@ -0,0 +1,14 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Static equivalent of the support of a version of an agent for a feature; constructed by code generated with `CanIUse::write_static_rust_source()`.
+#[allow(missing_docs)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct StaticSupportDetail
+{
+	pub maturity: SupportMaturity,
+	pub requires_prefix: bool,
+	pub disabled_by_default: bool,
+	pub notes_by_one_based_number: &'static [u8],
+}
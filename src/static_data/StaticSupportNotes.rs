@@ -0,0 +1,23 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// The notes of a `StaticSupport`, as pairs of one-based note number and note text; see `StaticSupport::notes()`.
+#[derive(Debug, Clone)]
+pub struct StaticSupportNotes
+{
+	note_numbers: Iter<'static, u8>,
+	notes_by_one_based_number: &'static [(u8, &'static str)],
+}
+
+impl Iterator for StaticSupportNotes
+{
+	type Item = (u8, &'static str);
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let notes_by_one_based_number = self.notes_by_one_based_number;
+		self.note_numbers.by_ref().filter_map(|&note_number| notes_by_one_based_number.iter().find(|note| note.0 == note_number).map(|note| (note_number, note.1))).next()
+	}
+}
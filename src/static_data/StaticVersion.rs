@@ -0,0 +1,84 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// A version of a static table, eg "15.2-15.3", ordered and matched as `Version` is but without being parsed into one, so that resolving a version does not allocate.
+#[derive(Debug, Copy, Clone)]
+struct StaticVersion<'a>
+{
+	lower_bound: &'a str,
+	upper_bound: &'a str,
+}
+
+impl<'a> StaticVersion<'a>
+{
+	#[inline(always)]
+	fn new(version: &'a str) -> Self
+	{
+		match version.find('-')
+		{
+			None => StaticVersion
+			{
+				lower_bound: version,
+				upper_bound: version,
+			},
+			
+			Some(index) => StaticVersion
+			{
+				lower_bound: &version[..index],
+				upper_bound: &version[index + 1..],
+			},
+		}
+	}
+	
+	/// As `Version::major_number()`.
+	#[inline(always)]
+	fn major_number(&self) -> Option<u64>
+	{
+		match StaticVersionParts::new(self.lower_bound).next()
+		{
+			Some(StaticVersionPart::Number(major_number)) => Some(major_number),
+			_ => None,
+		}
+	}
+	
+	/// As `Version::is_invalid_or_unknown()`.
+	#[inline(always)]
+	fn is_invalid_or_unknown(&self) -> bool
+	{
+		matches!(StaticVersionParts::new(self.lower_bound).next(), Some(StaticVersionPart::Number(0)) | Some(StaticVersionPart::Unknown(_)))
+	}
+	
+	/// As `Version::contains()`.
+	#[inline(always)]
+	fn contains(&self, version: &StaticVersion) -> bool
+	{
+		self.contains_bound(version.lower_bound) && self.contains_bound(version.upper_bound)
+	}
+	
+	#[inline(always)]
+	fn contains_bound(&self, bound: &str) -> bool
+	{
+		Self::compare(self.lower_bound, bound) != Ordering::Greater && (Self::compare(bound, self.upper_bound) != Ordering::Greater || Self::is_prefix_of(self.upper_bound, bound))
+	}
+	
+	/// Compares the lower bounds of versions as `Version` does.
+	#[inline(always)]
+	fn cmp_lower_bound(&self, other: &StaticVersion) -> Ordering
+	{
+		Self::compare(self.lower_bound, other.lower_bound)
+	}
+	
+	#[inline(always)]
+	fn compare(left: &str, right: &str) -> Ordering
+	{
+		StaticVersionParts::new(left).cmp(StaticVersionParts::new(right))
+	}
+	
+	#[inline(always)]
+	fn is_prefix_of(prefix: &str, other: &str) -> bool
+	{
+		let mut other_parts = StaticVersionParts::new(other);
+		StaticVersionParts::new(prefix).all(|part| other_parts.next() == Some(part))
+	}
+}
@@ -0,0 +1,53 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Static equivalent of `VersionDetail`; constructed by code generated with `CanIUse::write_static_rust_source()`.
+#[allow(missing_docs)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StaticVersionDetail
+{
+	pub version: &'static str,
+	pub global_usage: f64,
+	pub release_date: Option<i64>,
+	pub era: i64,
+	pub prefix_override: Option<&'static str>,
+}
+
+impl StaticVersionDetail
+{
+	/// Version, as the caniuse.com database would have it, eg "4.0-4.2".
+	#[inline(always)]
+	pub fn version(&self) -> &'static str
+	{
+		self.version
+	}
+	
+	/// A global usage of this version; see `VersionDetail::global_usage()`.
+	#[inline(always)]
+	pub fn global_usage(&self) -> UsagePercentage
+	{
+		UsagePercentage::new(self.global_usage)
+	}
+	
+	/// A timestamp of when this particular version was released; see `VersionDetail::release_date()`.
+	#[inline(always)]
+	pub fn release_date(&self) -> Option<DateTime<Utc>>
+	{
+		self.release_date.and_then(|release_date| Utc.timestamp_opt(release_date, 0).single())
+	}
+	
+	/// Era; see `VersionDetail::era()`.
+	#[inline(always)]
+	pub fn era(&self) -> i64
+	{
+		self.era
+	}
+	
+	/// Override of prefix; only specified for Opera
+	#[inline(always)]
+	pub fn prefix_override(&self) -> Option<Prefix>
+	{
+		self.prefix_override.map(from_can_i_use_identifier)
+	}
+}
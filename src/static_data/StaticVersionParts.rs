@@ -0,0 +1,47 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// The parts of a bound of a version of a static table, eg "15" and "2" of "15.2", as `Version` parses them.
+#[derive(Debug, Clone)]
+struct StaticVersionParts<'a>
+{
+	bound: &'a str,
+	parts: Split<'a, char>,
+}
+
+impl<'a> StaticVersionParts<'a>
+{
+	#[inline(always)]
+	fn new(bound: &'a str) -> Self
+	{
+		StaticVersionParts
+		{
+			bound,
+			parts: bound.split('.'),
+		}
+	}
+}
+
+impl<'a> Iterator for StaticVersionParts<'a>
+{
+	type Item = StaticVersionPart<'a>;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		use self::StaticVersionPart::*;
+		
+		let part = self.parts.next()?;
+		Some(match self.bound
+		{
+			"TP" => TechnologyPreview,
+			"all" => All,
+			_ => match part.parse::<u64>()
+			{
+				Ok(value) => Number(value),
+				Err(_) => Unknown(part),
+			},
+		})
+	}
+}
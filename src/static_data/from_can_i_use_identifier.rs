@@ -0,0 +1,17 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Converts an identifier in a static table back into, say, a `Status` or an `AgentName`; only unknown identifiers allocate.
+#[inline(always)]
+fn from_can_i_use_identifier<T: Deserialize<'static>>(can_i_use_identifier: &'static str) -> T
+{
+	let deserializer: StrDeserializer<'static, ::serde::de::value::Error> = can_i_use_identifier.into_deserializer();
+	T::deserialize(deserializer).expect("identifiers in static tables are generated from valid values")
+}
+
+#[inline(always)]
+fn from_can_i_use_identifier_reference<T: Deserialize<'static>>(can_i_use_identifier: &&'static str) -> T
+{
+	from_can_i_use_identifier(can_i_use_identifier)
+}
@@ -0,0 +1,30 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+use super::*;
+use ::chrono::naive::NaiveDate;
+use ::serde::de::IntoDeserializer;
+use ::serde::de::value::StrDeserializer;
+use ::std::iter::Map;
+use ::std::slice::Iter;
+use ::std::str::Split;
+
+
+include!("from_can_i_use_identifier.rs");
+include!("resolve_static_version.rs");
+include!("StaticAgent.rs");
+include!("StaticAgentDetail.rs");
+include!("StaticCanIUse.rs");
+include!("StaticCategories.rs");
+include!("StaticFeature.rs");
+include!("StaticFeatureDetail.rs");
+include!("StaticRegionalUsage.rs");
+include!("StaticSupport.rs");
+include!("StaticSupportDetail.rs");
+include!("StaticSupportNotes.rs");
+include!("StaticUsages.rs");
+include!("StaticVersion.rs");
+include!("StaticVersionDetail.rs");
+include!("StaticVersionPart.rs");
+include!("StaticVersionParts.rs");
@@ -0,0 +1,25 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Resolves `version` to an entry of a static table as `Version::resolve()` does for the database, eg "15.2" to iOS Safari's "15.2-15.3", by walking the table; it neither parses versions into `Version` nor allocates.
+/// The entries must be in the order of `Version`, as `StaticRustSource` writes them.
+#[inline(always)]
+fn resolve_static_version<V>(entries: &'static [(&'static str, V)], version: &str) -> Option<&'static V>
+{
+	if let Some((_, value)) = entries.iter().find(|entry| entry.0 == version)
+	{
+		return Some(value);
+	}
+	
+	let version = StaticVersion::new(version);
+	let newest_first = entries.iter().rev().map(|&(entry_version, ref value)| (StaticVersion::new(entry_version), value));
+	
+	if let Some((_, value)) = newest_first.clone().find(|entry| entry.0.contains(&version))
+	{
+		return Some(value);
+	}
+	
+	version.major_number()?;
+	newest_first.filter(|entry| entry.0.major_number().is_some() && !entry.0.is_invalid_or_unknown()).find(|entry| entry.0.cmp_lower_bound(&version) != Ordering::Greater).map(|(_, value)| value)
+}
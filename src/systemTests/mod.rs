@@ -3,20 +3,50 @@
 
 
 use super::*;
+use ::std::collections::BTreeSet;
 
 
+/// The embedded database, modified as JSON.
+#[cfg(feature = "embedded-database")]
+fn can_i_use_modified<F: FnOnce(&mut ::serde_json::Value)>(modify: F) -> CanIUse
+{
+	let mut json = ::serde_json::to_value(CanIUse::default()).unwrap();
+	modify(&mut json);
+	CanIUse::from_str(&json.to_string()).unwrap()
+}
+
+/// The embedded database with the `stats` of agents of features replaced, eg `{ "css-grid": { "chrome": { "50": "n", "57": "y" } } }`.
+#[cfg(feature = "embedded-database")]
+fn can_i_use_with_stats(stats: &str) -> CanIUse
+{
+	let stats: ::serde_json::Value = ::serde_json::from_str(stats).unwrap();
+	can_i_use_modified(|json|
+	{
+		for (feature, feature_stats) in stats.as_object().unwrap().iter()
+		{
+			for (agent, agent_stats) in feature_stats.as_object().unwrap().iter()
+			{
+				json["data"][feature]["stats"][agent] = agent_stats.clone();
+			}
+		}
+	})
+}
+
+#[cfg(feature = "embedded-database")]
 #[test]
 fn can_i_use_default()
 {
 	CanIUse::default();
 }
 
+#[cfg(feature = "embedded-world-wide")]
 #[test]
 fn regional_usage_default()
 {
 	RegionalUsage::default();
 }
 
+#[cfg(all(feature = "embedded-database", feature = "embedded-continents", feature = "embedded-country-au", feature = "embedded-country-nz"))]
 #[test]
 fn sensible_rules_to_prefixes()
 {
@@ -25,12 +55,12 @@ fn sensible_rules_to_prefixes()
 	let minimum_usage_threshold = UsagePercentage::OnePerMille;
 	let regional_usages = vec!
 	[
-		Asia.deref(),
-		Europe.deref(),
-		NorthAmerica.deref(),
-		SouthAmerica.deref(),
-		AU.deref(),
-		NZ.deref(),
+		RegionalUsages::Asia.regional_usage().unwrap(),
+		RegionalUsages::Europe.regional_usage().unwrap(),
+		RegionalUsages::NorthAmerica.regional_usage().unwrap(),
+		RegionalUsages::SouthAmerica.regional_usage().unwrap(),
+		RegionalUsages::AU.regional_usage().unwrap(),
+		RegionalUsages::NZ.regional_usage().unwrap(),
 	];
 	
 	let choices = AgentNameAndVersionSet::a_sensible_set_of_choices_for_an_international_website_in_multiple_languages(&can_i_use, maximum_release_age_from_can_i_use_database_last_updated, minimum_usage_threshold, &regional_usages);
@@ -48,3 +78,1076 @@ fn sensible_rules_to_prefixes()
 	assert!(unique_prefixes.contains(&Prefix::moz));
 	assert_eq!(unique_prefixes.len(), 1);
 }
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn can_i_use_serialize_round_trip()
+{
+	let can_i_use = CanIUse::default();
+	
+	let json = ::serde_json::to_string(&can_i_use).unwrap();
+	let round_tripped: CanIUse = json.parse().unwrap();
+	
+	assert_eq!(can_i_use, round_tripped);
+	let original: ::serde_json::Value = ::serde_json::from_str(include_str!("../data-2.0.json")).unwrap();
+	assert_eq!(::serde_json::to_value(&round_tripped).unwrap(), original);
+}
+
+#[cfg(feature = "embedded-world-wide")]
+#[test]
+fn regional_usage_serialize_round_trip()
+{
+	let regional_usage = RegionalUsage::default();
+	
+	let json = ::serde_json::to_string(&regional_usage).unwrap();
+	let round_tripped: RegionalUsage = json.parse().unwrap();
+	
+	assert_eq!(regional_usage, round_tripped);
+	let original: ::serde_json::Value = ::serde_json::from_str(include_str!("../region-usage-json/alt-ww.json")).unwrap();
+	assert_eq!(::serde_json::to_value(&round_tripped).unwrap(), original);
+}
+
+#[test]
+fn version_ranges_are_retained()
+{
+	let version: Version = "4.4.3-4.4.4".parse().unwrap();
+	
+	assert_eq!(version.to_string(), "4.4.3-4.4.4");
+	assert_ne!(version, Version::major_minor_revision(4, 4, 3));
+	assert_ne!(Version::from("15.2-15.4"), Version::from("15.2-15.3"));
+	
+	let mut versions = BTreeMap::new();
+	versions.insert(Version::major_minor_revision(4, 4, 3), ());
+	versions.insert(version, ());
+	versions.insert("4.4.3-4.4.5".into(), ());
+	assert_eq!(versions.len(), 3);
+	assert_eq!(versions.keys().map(|version| version.to_string()).collect::<Vec<_>>(), vec!["4.4.3", "4.4.3-4.4.4", "4.4.3-4.4.5"]);
+	versions.remove(&Version::major_minor_revision(4, 4, 3));
+	assert_eq!(Version::major_minor_revision(4, 4, 3).resolve(&versions).unwrap().0.to_string(), "4.4.3-4.4.5");
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn version_ranges_contain_versions()
+{
+	let android: Version = "4.4.3-4.4.4".parse().unwrap();
+	assert!(android.is_range());
+	assert_eq!(android.lower_bound(), Version::major_minor_revision(4, 4, 3));
+	assert_eq!(android.upper_bound(), Version::major_minor_revision(4, 4, 4));
+	assert!(android.contains(&Version::major_minor_revision(4, 4, 3)));
+	assert!(android.contains(&Version::major_minor_revision(4, 4, 4)));
+	assert!(!android.contains(&Version::major_minor_revision(4, 4, 2)));
+	assert!(!android.contains(&Version::major_minor_revision(4, 4, 5)));
+	
+	let ios: Version = "15.2-15.3".parse().unwrap();
+	assert!(ios.contains(&Version::major_minor(15, 2)));
+	assert!(ios.contains(&Version::major_minor_revision(15, 3, 1)));
+	assert!(ios.contains(&"15.2-15.3".into()));
+	assert!(!ios.contains(&Version::major_minor(15, 4)));
+	assert!(!ios.contains(&"15.2-15.4".into()));
+	assert!(Version::major_minor(14, 8) < ios && ios < Version::major_minor(15, 4));
+	assert!(Version::major_minor(15, 2) < ios);
+	
+	assert!(Version::major(62).contains(&"62.0.3202.94".into()));
+	assert!(!Version::major(62).contains(&Version::major(63)));
+	assert!(!Version::major(62).is_range());
+	assert!(Version::safari_technology_preview().contains(&Version::safari_technology_preview()));
+	assert!(!Version::safari_technology_preview().contains(&Version::major(11)));
+	
+	let can_i_use = CanIUse::default();
+	let ios_safari = AgentName::AppleSafariIOs.agent(&can_i_use).unwrap();
+	let range = ios_safari.version_details().keys().rev().find(|version| version.is_range()).unwrap().clone();
+	assert_eq!(ios_safari.version_detail(&range.upper_bound()), ios_safari.version_detail(&range));
+	let feature = can_i_use.known_feature_names().next().unwrap().feature(&can_i_use).unwrap();
+	let support = feature.implementation(&AgentName::AppleSafariIOs, &range).unwrap().unwrap();
+	let support_of_upper_bound = feature.implementation(&AgentName::AppleSafariIOs, &range.upper_bound()).unwrap().unwrap();
+	assert_eq!(support_of_upper_bound.maturity(), support.maturity());
+	assert_eq!(support_of_upper_bound.notes(), support.notes());
+}
+
+#[cfg(all(feature = "embedded-database", feature = "embedded-world-wide"))]
+#[test]
+fn versions_resolve_to_known_versions()
+{
+	let mut known_versions = BTreeMap::new();
+	for version in ["4", "10.0-10.2", "10.3", "117", "118", "120", "TP"].iter()
+	{
+		known_versions.insert(Version::from(*version), *version);
+	}
+	let resolve = |version: &str| Version::from(version).resolve(&known_versions).map(|(_, value)| *value);
+	
+	assert_eq!(resolve("118"), Some("118"));
+	assert_eq!(resolve("118.0.5993"), Some("118"));
+	assert_eq!(resolve("119.0.6045.105"), Some("118"));
+	assert_eq!(resolve("125"), Some("120"));
+	assert_eq!(resolve("10.1"), Some("10.0-10.2"));
+	assert_eq!(resolve("10.2.1"), Some("10.0-10.2"));
+	assert_eq!(resolve("10.5"), Some("10.3"));
+	assert_eq!(resolve("3.6"), None);
+	assert_eq!(resolve("TP"), Some("TP"));
+	assert_eq!(resolve("all"), None);
+	assert_eq!(Version::major(1).resolve(&BTreeMap::<Version, ()>::new()), None);
+	
+	let can_i_use = CanIUse::default();
+	let chrome = AgentName::GoogleChrome.agent(&can_i_use).unwrap();
+	let latest = chrome.version_details().keys().next_back().unwrap();
+	let far_future = Version::major(latest.major_number().unwrap() + 100);
+	assert_eq!(chrome.nearest_known_version(&far_future), Some(latest));
+	let patch_level = format!("{}.0.5993", chrome.current_version()).parse().unwrap();
+	assert_eq!(chrome.nearest_known_version(&patch_level), Some(chrome.current_version()));
+	
+	let feature = can_i_use.known_feature_names().next().unwrap().feature(&can_i_use).unwrap();
+	assert_eq!(feature.implementation(&AgentName::GoogleChrome, &patch_level).unwrap().map(|support| support.maturity()), feature.implementation(&AgentName::GoogleChrome, chrome.current_version()).unwrap().map(|support| support.maturity()));
+	assert!(feature.implementation(&AgentName::GoogleChrome, &far_future).unwrap().is_some());
+	let too_old = Version::major(1);
+	assert_eq!(chrome.nearest_known_version(&too_old), None);
+	assert!(feature.implementation(&AgentName::GoogleChrome, &too_old).unwrap().is_none());
+	
+	let regional_usage = RegionalUsage::default();
+	assert_eq!(regional_usage.usage_of_version(&AgentName::GoogleChrome, &patch_level), regional_usage.usage_of_version(&AgentName::GoogleChrome, chrome.current_version()));
+	assert_eq!(regional_usage.usage_of_version(&AgentName::GoogleChrome, &too_old), Some(None));
+}
+
+#[test]
+fn browserslist_query_parses()
+{
+	let query: BrowserslistQuery = "> 0.5% in AU, last 2 versions, not dead, Firefox ESR".parse().unwrap();
+	
+	assert_eq!(query.len(), 4);
+	assert_eq!(query[0].selector(), &BrowserslistSelector::Usage { comparison: BrowserslistComparison::GreaterThan, usage_percentage: UsagePercentage::new(0.5), regional_usages: Some(RegionalUsages::AU) });
+	assert!(query[2].negated());
+	
+	assert_eq!("not dead".parse::<BrowserslistQuery>(), Err(BrowserslistQueryParseError::NotCanNotBeFirst));
+	assert_eq!("last 2 netscape versions".parse::<BrowserslistQuery>(), Err(BrowserslistQueryParseError::UnknownBrowser("netscape".to_owned())));
+	assert_eq!("> 5% in XX".parse::<BrowserslistQuery>(), Err(BrowserslistQueryParseError::UnknownRegion("XX".to_owned())));
+	assert_eq!(BrowserslistSelector::parse_browser("KaiOS"), Ok(AgentName::Unknown("kaios".to_owned())));
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn browserslist_regional_usage_comes_from_the_source()
+{
+	struct Australia(RegionalUsage);
+	
+	impl RegionalUsageSource for Australia
+	{
+		fn regional_usage(&self, regional_usages: RegionalUsages) -> Option<&RegionalUsage>
+		{
+			if regional_usages == RegionalUsages::AU
+			{
+				Some(&self.0)
+			}
+			else
+			{
+				None
+			}
+		}
+	}
+	
+	let can_i_use = CanIUse::default();
+	let australia = Australia(RegionalUsage::from_str(r#"{ "id": "AU", "name": "Australia", "month": "2017-10", "access_date": "2017-11-01", "data": { "chrome": { "58": 12.0, "61": 0.5 }, "firefox": { "57": 3.0 } }, "total": 15.5 }"#).unwrap());
+	
+	let above = AgentNameAndVersionSet::browserslist(&can_i_use, &australia, "> 1% in AU").unwrap();
+	assert_eq!(*above, hashset!((AgentName::GoogleChrome, Version::major(58)), (AgentName::MozillaFirefox, Version::major(57))));
+	let covered = AgentNameAndVersionSet::browserslist(&can_i_use, &australia, "cover 10% in au").unwrap();
+	assert_eq!(*covered, hashset!((AgentName::GoogleChrome, Version::major(58))));
+	
+	assert_eq!(AgentNameAndVersionSet::browserslist(&can_i_use, &australia, "> 1% in NZ, last 1 chrome version").unwrap_err(), BrowserslistQueryParseError::RegionUnavailable(RegionalUsages::NZ));
+	let query: BrowserslistQuery = "> 1% in NZ, last 1 chrome version".parse().unwrap();
+	assert_eq!(query.evaluate(&can_i_use, &australia).len(), 1);
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn browserslist_query_evaluates()
+{
+	let can_i_use = CanIUse::default();
+	let firefox = AgentName::MozillaFirefox.agent(&can_i_use).unwrap();
+	
+	let last_version = AgentNameAndVersionSet::browserslist(&can_i_use, &EmbeddedRegionalUsages, "last 1 Firefox version").unwrap();
+	assert_eq!(last_version.len(), 1);
+	assert!(last_version.contains(&(AgentName::MozillaFirefox, firefox.current_version().clone())));
+	
+	let range = AgentNameAndVersionSet::browserslist(&can_i_use, &EmbeddedRegionalUsages, "ie 9-10").unwrap();
+	assert!(range.contains(&(AgentName::MicrosoftInternetExplorer, Version::major(9))));
+	assert!(range.contains(&(AgentName::MicrosoftInternetExplorer, Version::major(10))));
+	assert_eq!(range.len(), 2);
+	
+	let excluded = AgentNameAndVersionSet::browserslist(&can_i_use, &EmbeddedRegionalUsages, "ie 9-10, not ie 10").unwrap();
+	assert_eq!(excluded.len(), 1);
+	
+	let intersected = AgentNameAndVersionSet::browserslist(&can_i_use, &EmbeddedRegionalUsages, "ie >= 9 and ie < 10").unwrap();
+	assert_eq!(intersected.len(), 1);
+	
+	let defaults = AgentNameAndVersionSet::browserslist(&can_i_use, &EmbeddedRegionalUsages, "defaults").unwrap();
+	assert!(!defaults.is_empty());
+	assert!(!defaults.contains(&(AgentName::MicrosoftInternetExplorer, Version::major(9))));
+	
+	let ios_safari = AgentName::AppleSafariIOs.agent(&can_i_use).unwrap();
+	let range = ios_safari.version_details().keys().rev().find(|version| version.is_range()).unwrap();
+	let within_range = AgentNameAndVersionSet::browserslist(&can_i_use, &EmbeddedRegionalUsages, &format!("ios_saf {}", range.upper_bound())).unwrap();
+	assert_eq!(within_range.iter().map(|(_, version)| version.to_string()).collect::<Vec<_>>(), vec![range.to_string()]);
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn browserslist_usage_cover_and_since_queries_evaluate()
+{
+	let can_i_use = CanIUse::default();
+	let mut usages = Vec::new();
+	let mut release_dates = Vec::new();
+	for agent_name in can_i_use.known_agent_names()
+	{
+		let agent = agent_name.agent(&can_i_use).unwrap();
+		for version in agent.version_details().keys().filter(|version| !version.is_invalid_or_unknown())
+		{
+			if let Some(usage) = agent.global_usage(version)
+			{
+				usages.push(((agent_name.clone(), version.clone()), usage));
+			}
+		}
+		for (version, version_detail) in agent.version_details_for_current_and_older_versions()
+		{
+			if let Some(release_date) = version_detail.release_date()
+			{
+				release_dates.push(((agent_name.clone(), version.clone()), release_date));
+			}
+		}
+	}
+	
+	let above = AgentNameAndVersionSet::browserslist(&can_i_use, &EmbeddedRegionalUsages, "> 0.55%").unwrap();
+	assert!(!above.is_empty());
+	assert_eq!(*above, usages.iter().filter(|&&(_, usage)| usage > UsagePercentage::new(0.55)).map(|(agent_name_and_version, _)| agent_name_and_version.clone()).collect());
+	let at_most = AgentNameAndVersionSet::browserslist(&can_i_use, &EmbeddedRegionalUsages, "<= 0.55%").unwrap();
+	assert_eq!(*at_most, usages.iter().filter(|&&(_, usage)| usage <= UsagePercentage::new(0.55)).map(|(agent_name_and_version, _)| agent_name_and_version.clone()).collect());
+	
+	let half_of_all_usage = UsagePercentage::new((*usages.iter().fold(UsagePercentage::Zero, |total, &(_, usage)| total + usage) / 2.0).floor());
+	let covered = AgentNameAndVersionSet::browserslist(&can_i_use, &EmbeddedRegionalUsages, &format!("cover {}", half_of_all_usage)).unwrap();
+	let mut covered_usages: Vec<UsagePercentage> = usages.iter().filter(|(agent_name_and_version, _)| covered.contains(agent_name_and_version)).map(|&(_, usage)| usage).collect();
+	covered_usages.sort();
+	let total = covered_usages.iter().fold(UsagePercentage::Zero, |total, usage| total + *usage);
+	assert!(total >= half_of_all_usage);
+	assert!(total - covered_usages[0] < half_of_all_usage);
+	assert!(usages.iter().filter(|(agent_name_and_version, _)| !covered.contains(agent_name_and_version)).all(|&(_, usage)| usage <= covered_usages[0]));
+	
+	let since = Utc.from_utc_datetime(&NaiveDate::from_ymd_opt(2017, 3, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+	assert_eq!("since 2017-03".parse::<BrowserslistSelector>(), Ok(BrowserslistSelector::Since(since)));
+	assert_eq!("since 2017-03-01".parse::<BrowserslistSelector>(), Ok(BrowserslistSelector::Since(since)));
+	let released_since = AgentNameAndVersionSet::browserslist(&can_i_use, &EmbeddedRegionalUsages, "since 2017-03").unwrap();
+	let expected: HashSet<(AgentName, Version)> = release_dates.iter().filter(|&&(_, release_date)| release_date >= since).map(|(agent_name_and_version, _)| agent_name_and_version.clone()).collect();
+	assert!(!expected.is_empty());
+	assert_eq!(*released_since, expected);
+}
+
+#[test]
+fn browserslist_configuration_parses_environments()
+{
+	let configuration: BrowserslistConfiguration = "# Comment\nlast 1 version\n> 1%, not dead\n\n[production staging]\n> 0.5% in AU # trailing comment\n\n[development]\nlast 1 chrome version\n".parse().unwrap();
+	
+	assert_eq!(configuration.defaults(), &["last 1 version".to_owned(), "> 1%, not dead".to_owned()]);
+	assert_eq!(configuration.queries("production"), &["> 0.5% in AU".to_owned()]);
+	assert_eq!(configuration.queries("staging"), configuration.queries("production"));
+	assert_eq!(configuration.queries("development"), &["last 1 chrome version".to_owned()]);
+	assert_eq!(configuration.queries("test"), configuration.defaults());
+	
+	assert!("[production]\nie 11\n[production]\nie 10\n".parse::<BrowserslistConfiguration>().is_err());
+	assert!("extends browserslist-config-mycompany".parse::<BrowserslistConfiguration>().is_err());
+}
+
+#[test]
+fn browserslist_configuration_from_package_json()
+{
+	let package_json_file_path = Path::new("package.json");
+	
+	let array = ::serde_json::from_str(r#"{ "name": "x", "browserslist": ["last 1 version", "not dead"] }"#).unwrap();
+	let configuration = BrowserslistConfiguration::from_package_json(&array, package_json_file_path).unwrap().unwrap();
+	assert_eq!(configuration.query("production").unwrap(), "last 1 version, not dead".parse().unwrap());
+	
+	let environments = ::serde_json::from_str(r#"{ "browserslist": { "production": ["> 1%"], "development": "last 1 chrome version" } }"#).unwrap();
+	let configuration = BrowserslistConfiguration::from_package_json(&environments, package_json_file_path).unwrap().unwrap();
+	assert_eq!(configuration.queries("development"), &["last 1 chrome version".to_owned()]);
+	assert_eq!(configuration.query("test").unwrap(), BrowserslistQuery::Defaults.parse().unwrap());
+	
+	let absent = ::serde_json::from_str(r#"{ "name": "x" }"#).unwrap();
+	assert_eq!(BrowserslistConfiguration::from_package_json(&absent, package_json_file_path).unwrap(), None);
+}
+
+#[test]
+fn browserslist_configuration_is_found_in_parent_directories()
+{
+	use ::std::fs::create_dir_all;
+	use ::std::fs::remove_dir_all;
+	use ::std::io::Write;
+	
+	let root = ::std::env::temp_dir().join("caniuse-serde-browserslist_configuration_is_found_in_parent_directories");
+	let nested = root.join("src").join("nested");
+	create_dir_all(&nested).unwrap();
+	File::create(root.join(".browserslistrc")).unwrap().write_all(b"[production]\nie 11\n").unwrap();
+	
+	let (file_path, configuration) = BrowserslistConfiguration::find(&nested).unwrap().unwrap();
+	assert_eq!(file_path, root.join(".browserslistrc"));
+	assert_eq!(configuration.queries("production"), &["ie 11".to_owned()]);
+	
+	File::create(root.join("package.json")).unwrap().write_all(br#"{ "browserslist": ["ie 10"] }"#).unwrap();
+	assert!(BrowserslistConfiguration::find(&nested).is_err());
+	
+	remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn regional_usages_not_embedded_name_the_cargo_feature()
+{
+	assert_eq!(RegionalUsages::AU.regional_usage().is_ok(), cfg!(feature = "embedded-country-au"));
+	
+	assert_eq!(RegionalUsageNotEmbeddedError(RegionalUsages::WorldWide).cargo_feature(), "embedded-world-wide");
+	assert_eq!(RegionalUsageNotEmbeddedError(RegionalUsages::Europe).cargo_feature(), "embedded-continents");
+	let error = RegionalUsageNotEmbeddedError(RegionalUsages::AU);
+	assert_eq!(error.cargo_feature(), "embedded-country-au");
+	assert_eq!(error.to_string(), "regional usage database not embedded 'AU'; enable the cargo feature 'embedded-country-au'");
+}
+
+#[test]
+fn regional_usages_and_cargo_features_cover_the_same_countries()
+{
+	let cargo_toml = include_str!("../../Cargo.toml");
+	let declared_features: BTreeSet<&str> = cargo_toml.lines().filter(|line| line.starts_with("embedded-country-")).map(|line| line.split(' ').next().unwrap()).collect();
+	let embedded_countries: BTreeSet<&str> = cargo_toml.lines().filter(|line| line.starts_with("\t\"embedded-country-")).map(|line| line.trim().trim_matches(|character| character == '"' || character == ',')).collect();
+	
+	let mut countries = BTreeSet::new();
+	for regional_usages in RegionalUsages::All.iter()
+	{
+		assert_eq!(RegionalUsages::from_str(regional_usages.can_i_use_iso_like_code()), Ok(*regional_usages));
+		
+		let cargo_feature = RegionalUsageNotEmbeddedError(*regional_usages).cargo_feature();
+		if cargo_feature.starts_with("embedded-country-")
+		{
+			assert!(countries.insert(cargo_feature));
+		}
+	}
+	
+	let countries: BTreeSet<&str> = countries.iter().map(String::as_str).collect();
+	assert_eq!(declared_features, countries);
+	assert_eq!(embedded_countries, countries);
+	
+	if cfg!(all(feature = "embedded-world-wide", feature = "embedded-continents", feature = "embedded-countries"))
+	{
+		assert!(RegionalUsages::All.iter().all(|regional_usages| regional_usages.regional_usage().is_ok()));
+	}
+}
+
+#[test]
+fn user_agents_parse()
+{
+	assert_eq!(parse_user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/58.0.3029.110 Safari/537.36 Edge/16.16299"), Some((AgentName::MicrosoftEdge, "16.16299".into())));
+	assert_eq!(parse_user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/62.0.3202.94 Safari/537.36"), Some((AgentName::GoogleChrome, "62.0.3202.94".into())));
+	assert_eq!(parse_user_agent("Mozilla/5.0 (Linux; Android 7.0; SAMSUNG SM-G930F Build/NRD90M) AppleWebKit/537.36 (KHTML, like Gecko) SamsungBrowser/6.2 Chrome/56.0.2924.87 Mobile Safari/537.36"), Some((AgentName::SamsungBrowserAndroid, "6.2".into())));
+	assert_eq!(parse_user_agent("Mozilla/5.0 (Linux; U; Android 7.0; en-US; SM-G935F Build/NRD90M) AppleWebKit/534.30 (KHTML, like Gecko) Version/4.0 UCBrowser/11.4.5.1005 U3/0.8.0 Mobile Safari/534.30"), Some((AgentName::UcBrowserAndroid, "11.4.5.1005".into())));
+	assert_eq!(parse_user_agent("Mozilla/5.0 (Linux; U; Android 6.0; zh-cn; MI 5 Build/MRA58K) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/57.0.2987.132 MQQBrowser/8.8 Mobile Safari/537.36"), Some((AgentName::QqBrowserAndroid, "8.8".into())));
+	assert_eq!(parse_user_agent("Mozilla/5.0 (Linux; U; Android 4.4.2; zh-CN; HUAWEI MT7-TL00 Build/HuaweiMT7-TL00) AppleWebKit/534.30 (KHTML, like Gecko) Version/4.0 baidubrowser/7.12.12.0 (Baidu; P1 4.4.2) Mobile Safari/534.30"), Some((AgentName::BaiduBrowserAndroid, "7.12.12.0".into())));
+	assert_eq!(parse_user_agent("Mozilla/5.0 (iPhone; CPU iPhone OS 11_2_1 like Mac OS X) AppleWebKit/604.1.34 (KHTML, like Gecko) CriOS/63.0.3239.73 Mobile/15C153 Safari/604.1"), Some((AgentName::AppleSafariIOs, "11.2.1".into())));
+	assert_eq!(parse_user_agent("Opera/9.80 (Android; Opera Mini/36.2.2254/119.132; U; id) Presto/2.12.423 Version/12.16"), Some((AgentName::OperaMini, Version::opera_mini_all())));
+	assert_eq!(parse_user_agent("Mozilla/5.0 (Linux; Android 4.4.2; Nexus 4 Build/KOT49H) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/30.0.0.0 Mobile Safari/537.36"), Some((AgentName::GoogleAndroidBrowserAndWebComponent, "30.0.0.0".into())));
+	assert_eq!(parse_user_agent("Mozilla/5.0 (Windows NT 6.1; WOW64; Trident/7.0; rv:11.0) like Gecko"), Some((AgentName::MicrosoftInternetExplorer, "11.0".into())));
+	assert_eq!(parse_user_agent("curl/7.54.0"), None);
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn user_agents_resolve_to_known_versions()
+{
+	let can_i_use = CanIUse::default();
+	
+	let chrome = AgentName::GoogleChrome.agent(&can_i_use).unwrap();
+	let current_version = chrome.current_version().clone();
+	let user_agent = format!("Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{}.0.3202.94 Safari/537.36", current_version);
+	assert_eq!(can_i_use.resolve_user_agent(&user_agent), UserAgentResolution::Known
+	{
+		agent_name: AgentName::GoogleChrome,
+		version: current_version,
+		reported_version: format!("{}.0.3202.94", chrome.current_version()).parse().unwrap(),
+	});
+	
+	let internet_explorer = can_i_use.resolve_user_agent("Mozilla/5.0 (Windows NT 6.1; WOW64; Trident/7.0; rv:11.0) like Gecko");
+	assert_eq!(internet_explorer.agent_name_and_version(), Some((&AgentName::MicrosoftInternetExplorer, &Version::major(11))));
+	
+	let opera_mini = can_i_use.resolve_user_agent("Opera/9.80 (J2ME/MIDP; Opera Mini/9.80 (S60; SymbOS; Opera Mobi/23.348; U; en) Presto/2.5.25 Version/10.54");
+	assert_eq!(opera_mini.agent_name_and_version(), Some((&AgentName::OperaMini, &Version::opera_mini_all())));
+	
+	let ios_safari = AgentName::AppleSafariIOs.agent(&can_i_use).unwrap();
+	let ios_range = ios_safari.version_details().keys().find(|version| version.to_string().contains('-')).unwrap();
+	assert_eq!(ios_safari.nearest_known_version(&ios_range.to_string().split('-').next().unwrap().into()), Some(ios_range));
+	
+	assert_eq!(can_i_use.resolve_user_agent("Wget/1.19.1"), UserAgentResolution::Unknown);
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn user_agents_support_features()
+{
+	let can_i_use = CanIUse::default();
+	let feature_name = FeatureName("css-focus-ring".to_owned());
+	
+	let firefox = AgentName::MozillaFirefox.agent(&can_i_use).unwrap();
+	let user_agent = format!("Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:{0}) Gecko/20100101 Firefox/{0}", firefox.current_version());
+	let support = can_i_use.supports(&user_agent, &feature_name, &SupportPolicy::Strict);
+	assert!(support.is_supported());
+	assert_eq!(support.prefix(), Some(&Prefix::moz));
+	
+	let support = can_i_use.supports("Mozilla/5.0 (Windows NT 6.1; WOW64; Trident/7.0; rv:11.0) like Gecko", &feature_name, &SupportPolicy::Lenient);
+	match support
+	{
+		UserAgentSupport::Unsupported { maturity, .. } => assert_eq!(maturity, Some(SupportMaturity::NotSupportedOrDisabledByDefault)),
+		_ => panic!("Internet Explorer does not support css-focus-ring"),
+	}
+	
+	let unknown = can_i_use.supports("curl/7.54.0", &feature_name, &SupportPolicy::default());
+	assert_eq!(unknown, UserAgentSupport::Unsupported { resolution: UserAgentResolution::Unknown, maturity: None, notes: vec![] });
+	
+	let can_i_use = can_i_use_with_stats(r#"{ "css-focus-ring": { "chrome": { "4": "n", "49": "y", "62": "a d" } } }"#);
+	let chrome = |major: u64| format!("Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{}.0.3202.94 Safari/537.36", major);
+	assert!(can_i_use.supports(&chrome(58), &feature_name, &SupportPolicy::Strict).is_supported());
+	
+	let with_disabled_by_default = SupportPolicy { disabled_by_default: true, .. SupportPolicy::Strict };
+	assert!(!can_i_use.supports(&chrome(63), &feature_name, &SupportPolicy::Lenient).is_supported());
+	assert!(!can_i_use.supports(&chrome(63), &feature_name, &with_disabled_by_default).is_supported());
+	assert!(can_i_use.supports(&chrome(63), &feature_name, &SupportPolicy { almost_supported: true, .. with_disabled_by_default }).is_supported());
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn load_errors_name_the_feature_agent_and_version()
+{
+	let can_i_use = CanIUse::default();
+	let firefox = AgentName::MozillaFirefox.agent(&can_i_use).unwrap();
+	let version = firefox.current_version().to_string();
+	
+	let mut json = ::serde_json::to_value(&can_i_use).unwrap();
+	json["data"]["css-focus-ring"]["stats"]["firefox"][&version] = ::serde_json::Value::String("y q".to_owned());
+	match ::serde_json::to_string(&json).unwrap().parse::<CanIUse>()
+	{
+		Err(LoadError::SchemaViolation { context, .. }) =>
+		{
+			assert_eq!(context.feature, Some(FeatureName("css-focus-ring".to_owned())));
+			assert_eq!(context.agent, Some(AgentName::MozillaFirefox));
+			assert_eq!(context.version.map(|broken_version| broken_version.to_string()), Some(version));
+		}
+		result => panic!("Expected a schema violation, not {:?}", result),
+	}
+	
+	match CanIUse::from_slice(b"{\n\"agents\": ")
+	{
+		Err(LoadError::JsonSyntax { line, .. }) => assert_eq!(line, 2),
+		result => panic!("Expected a syntax error, not {:?}", result),
+	}
+	
+	match CanIUse::from_path("/nonexistent/data-2.0.json")
+	{
+		Err(LoadError::Io(_)) => (),
+		result => panic!("Expected an I/O error, not {:?}", result),
+	}
+	
+	assert_eq!("XX".parse::<RegionalUsages>(), Err(RegionalUsagesFromStrError("XX".to_owned())));
+}
+
+#[cfg(all(feature = "embedded-database", feature = "embedded-world-wide", feature = "embedded-continents"))]
+#[test]
+fn can_i_use_from_caniuse_repository()
+{
+	use ::std::fs::create_dir_all;
+	use ::std::fs::remove_dir_all;
+	use ::std::io::Write;
+	
+	let can_i_use = CanIUse::default();
+	
+	let root = ::std::env::temp_dir().join("caniuse-serde-can_i_use_from_caniuse_repository");
+	let fulldata_json = root.join("fulldata-json");
+	let features_json = root.join("features-json");
+	let region_usage_json = root.join("region-usage-json");
+	create_dir_all(&fulldata_json).unwrap();
+	create_dir_all(&features_json).unwrap();
+	create_dir_all(&region_usage_json).unwrap();
+	
+	let json = ::serde_json::to_value(&can_i_use).unwrap();
+	File::create(fulldata_json.join("data-2.0.json")).unwrap().write_all(::serde_json::to_string(&json).unwrap().as_bytes()).unwrap();
+	for (feature_name, feature) in json["data"].as_object().unwrap().iter()
+	{
+		File::create(features_json.join(format!("{}.json", feature_name))).unwrap().write_all(::serde_json::to_string(feature).unwrap().as_bytes()).unwrap();
+	}
+	for regional_usages in [RegionalUsages::WorldWide, RegionalUsages::Asia].iter()
+	{
+		File::create(region_usage_json.join(regional_usages.json_file_name())).unwrap().write_all(::serde_json::to_string(regional_usages.regional_usage().unwrap()).unwrap().as_bytes()).unwrap();
+	}
+	
+	assert_eq!(CanIUse::from_caniuse_repository(&root).unwrap(), can_i_use);
+	
+	let registry = RegionalUsageRegistry::from_caniuse_repository(&root).unwrap();
+	assert_eq!(registry.len(), 2);
+	assert_eq!(registry.regional_usage(RegionalUsages::Asia), Some(RegionalUsages::Asia.regional_usage().unwrap()));
+	assert_eq!(registry.regional_usage(RegionalUsages::Europe), None);
+	
+	remove_dir_all(&root).unwrap();
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn browser_compat_data_is_imported()
+{
+	use ::std::fs::create_dir_all;
+	use ::std::fs::remove_dir_all;
+	use ::std::io::Write;
+	
+	let can_i_use = CanIUse::default();
+	let chrome_current_version = AgentName::GoogleChrome.agent(&can_i_use).unwrap().current_version().clone();
+	let firefox_current_version = AgentName::MozillaFirefox.agent(&can_i_use).unwrap().current_version().clone();
+	
+	let root = ::std::env::temp_dir().join("caniuse-serde-browser_compat_data_is_imported");
+	let api = root.join("api");
+	create_dir_all(&api).unwrap();
+	let json = format!(r#"{{ "api": {{ "AbortController": {{
+		"__compat": {{
+			"mdn_url": "https://developer.mozilla.org/docs/Web/API/AbortController",
+			"support": {{
+				"chrome": {{ "version_added": "{}" }},
+				"firefox": [ {{ "version_added": "1", "prefix": "moz", "notes": "Prefixed." }} ],
+				"ie": {{ "version_added": false }},
+				"safari": {{ "version_added": "1", "flags": [ {{ "type": "preference", "name": "AbortController" }} ] }},
+				"nodejs": {{ "version_added": "15" }}
+			}},
+			"status": {{ "experimental": false, "standard_track": true, "deprecated": false }}
+		}},
+		"abort": {{ "__compat": {{ "support": {{ "chrome": {{ "version_added": "≤{}" }} }}, "status": {{ "experimental": true, "standard_track": true, "deprecated": false }} }} }}
+	}} }} }}"#, chrome_current_version, chrome_current_version);
+	File::create(api.join("AbortController.json")).unwrap().write_all(json.as_bytes()).unwrap();
+	
+	let can_i_use = can_i_use.with_browser_compat_data(&root).unwrap();
+	remove_dir_all(&root).unwrap();
+	
+	let feature_name = FeatureName("mdn-api__abortcontroller".to_owned());
+	let feature = feature_name.feature(&can_i_use).unwrap();
+	assert_eq!(feature.categories(), &[Category::JS_API]);
+	assert_eq!(feature.status(), &Status::W3CCandidateRecommendation);
+	assert_eq!(feature.implementation(&AgentName::GoogleChrome, &chrome_current_version).unwrap().unwrap().maturity(), SupportMaturity::SupportedByDefault);
+	let chrome_oldest_version = AgentName::GoogleChrome.agent(&can_i_use).unwrap().version_details().keys().next().unwrap().clone();
+	assert_eq!(feature.implementation(&AgentName::GoogleChrome, &chrome_oldest_version).unwrap().unwrap().maturity(), SupportMaturity::NotSupportedOrDisabledByDefault);
+	assert_eq!(feature.implementation(&AgentName::MicrosoftInternetExplorer, &Version::major(11)).unwrap().unwrap().maturity(), SupportMaturity::NotSupportedOrDisabledByDefault);
+	let safari_current_version = AgentName::AppleSafari.agent(&can_i_use).unwrap().current_version().clone();
+	assert!(feature.implementation(&AgentName::AppleSafari, &safari_current_version).unwrap().unwrap().disabled_by_default());
+	assert!(feature.implementation(&AgentName::MicrosoftEdge, &Version::major(16)).is_none());
+	
+	let choices = AgentNameAndVersionSet::new(hashset!{ (AgentName::GoogleChrome, chrome_current_version.clone()), (AgentName::MozillaFirefox, firefox_current_version) });
+	let mut prefixes = HashSet::new();
+	let mut notes = Vec::new();
+	choices.support_for_a_feature(&can_i_use, &feature_name, |agent, version, support|
+	{
+		if support.requires_prefix()
+		{
+			prefixes.insert(agent.prefix(version).clone());
+			notes.extend(support.notes().iter().map(|&(_, note)| note.to_owned()));
+		}
+	});
+	assert_eq!(prefixes, hashset!{ Prefix::moz });
+	assert_eq!(notes, vec!["Prefixed.".to_owned()]);
+	
+	let child = FeatureName("mdn-api__abortcontroller__abort".to_owned());
+	let child_feature = child.feature(&can_i_use).unwrap();
+	assert_eq!(child_feature.parent_feature_if_any(), Some(&feature_name));
+	assert_eq!(child_feature.status(), &Status::W3CWorkingDraft);
+	assert_eq!(child_feature.implementation(&AgentName::GoogleChrome, &chrome_current_version).unwrap().unwrap().maturity(), SupportMaturity::SupportedByDefault);
+	assert_eq!(child_feature.implementation(&AgentName::GoogleChrome, &chrome_oldest_version).unwrap().unwrap().maturity(), SupportMaturity::SupportUnknown);
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn can_i_use_diff()
+{
+	let old = CanIUse::default();
+	assert!(CanIUse::diff(&old, &old).is_empty());
+	
+	let mut feature_names: Vec<FeatureName> = old.known_feature_names().cloned().collect();
+	feature_names.sort();
+	let removed_feature_name = feature_names[0].clone();
+	let changed_feature_name = feature_names[1].clone();
+	let chrome_current_version = AgentName::GoogleChrome.agent(&old).unwrap().current_version().to_string();
+	
+	let mut json = ::serde_json::to_value(&old).unwrap();
+	let was_supported;
+	{
+		let features = json["data"].as_object_mut().unwrap();
+		features.remove(&removed_feature_name.0);
+		
+		let feature = &mut features[&changed_feature_name.0];
+		feature["status"] = if feature["status"] == "unoff" { "rec" } else { "unoff" }.into();
+		feature["usage_perc_y"] = 12.5.into();
+		was_supported = feature["stats"]["chrome"][&chrome_current_version] == "y";
+		feature["stats"]["chrome"][&chrome_current_version] = if was_supported { "n d" } else { "y" }.into();
+	}
+	let new = CanIUse::from_str(&json.to_string()).unwrap();
+	
+	let diff = CanIUse::diff(&old, &new);
+	assert_eq!(diff.removed_features, vec![removed_feature_name]);
+	assert!(diff.added_features.is_empty());
+	assert!(diff.added_agents.is_empty() && diff.agent_versions.is_empty());
+	assert_eq!(diff.changed_features.len(), 1);
+	
+	let feature_diff = &diff.changed_features[0];
+	assert_eq!(feature_diff.feature_name, changed_feature_name);
+	assert!(feature_diff.status.is_some());
+	assert_eq!(feature_diff.supported_by_default_usage.as_ref().unwrap().new, UsagePercentage::new(12.5));
+	assert_eq!(feature_diff.support_changes.len(), 1);
+	assert_eq!(feature_diff.support_changes[0].agent_name, AgentName::GoogleChrome);
+	assert_eq!(diff.regressions(&changed_feature_name, &SupportPolicy::Strict).len(), if was_supported { 1 } else { 0 });
+	
+	let mut markdown = Vec::new();
+	diff.write_markdown(&mut markdown).unwrap();
+	let markdown = String::from_utf8(markdown).unwrap();
+	assert!(markdown.contains(&format!("### `{}`", changed_feature_name.0)));
+	assert!(markdown.contains(&format!("* Removed `{}`", diff.removed_features[0].0)));
+	
+	let mut json = Vec::new();
+	diff.write_json(&mut json).unwrap();
+	let json: ::serde_json::Value = ::serde_json::from_slice(&json).unwrap();
+	assert_eq!(json["removed_features"][0], diff.removed_features[0].0.as_str());
+	assert_eq!(json["changed_features"][0]["support_changes"][0]["agent_name"], "chrome");
+	
+	let mut json = ::serde_json::to_value(&old).unwrap();
+	json["data"]["css-focus-ring"]["stats"]["chrome"][&chrome_current_version] = "y #99".into();
+	let dangling_note = CanIUse::from_str(&json.to_string()).unwrap();
+	let diff = CanIUse::diff(&old, &dangling_note);
+	assert!(diff.changed_features[0].support_changes[0].new.as_ref().unwrap().notes.is_empty());
+}
+
+#[cfg(all(feature = "embedded-database", feature = "embedded-world-wide"))]
+#[test]
+fn static_data_is_generated_and_viewed()
+{
+	let can_i_use = CanIUse::default();
+	let mut source = Vec::new();
+	can_i_use.write_static_rust_source(&mut source, "CanIUseDatabase").unwrap();
+	let source = String::from_utf8(source).unwrap();
+	assert!(source.starts_with("pub static CanIUseDatabase: ::caniuse_serde::static_data::StaticCanIUse = "));
+	for feature_name in can_i_use.known_feature_names()
+	{
+		assert!(source.contains(&format!("feature_name: {:?}", feature_name.0)));
+	}
+	assert!(source.contains("agent_name: \"chrome\""));
+	
+	let mut regional_source = Vec::new();
+	RegionalUsage::default().write_static_rust_source(&mut regional_source, "WorldWide").unwrap();
+	let regional_source = String::from_utf8(regional_source).unwrap();
+	assert!(regional_source.starts_with("pub static WorldWide: ::caniuse_serde::static_data::StaticRegionalUsage = "));
+}
+
+include!("static_data_fixture/static_data.rs");
+
+#[test]
+fn static_data_compiles_and_resolves_versions_as_the_database_does()
+{
+	let can_i_use = CanIUse::from_str(include_str!("static_data_fixture/data-2.0.json")).unwrap();
+	let regional_usage = RegionalUsage::from_str(include_str!("static_data_fixture/alt-ww.json")).unwrap();
+	let mut source = Vec::new();
+	can_i_use.write_static_rust_source(&mut source, "CanIUseDatabase").unwrap();
+	regional_usage.write_static_rust_source(&mut source, "WorldWide").unwrap();
+	assert!(String::from_utf8(source).unwrap() == include_str!("static_data_fixture/static_data.rs"), "static_data_fixture/static_data.rs is not what is now generated from static_data_fixture/data-2.0.json and static_data_fixture/alt-ww.json");
+	
+	assert_eq!(CanIUseDatabase.known_agent_names().collect::<Vec<_>>(), vec![AgentName::GoogleChrome, AgentName::MozillaFirefox, AgentName::AppleSafariIOs]);
+	assert_eq!(CanIUseDatabase.status_description(&Status::W3CRecommendation), Some("W3C Recommendation"));
+	assert_eq!(CanIUseDatabase.child_categories(&ParentCategory::CSS).unwrap().collect::<Vec<_>>(), vec![Category::CSS3]);
+	assert_eq!(CanIUseDatabase.last_updated(), can_i_use.last_updated());
+	
+	let agent = CanIUseDatabase.agent(&AgentName::MozillaFirefox).unwrap();
+	assert_eq!(agent.prefix("57"), Prefix::moz);
+	assert_eq!(agent.global_usage("57"), Some(UsagePercentage::new(5.5)));
+	assert_eq!(agent.version_detail("57").unwrap().release_date(), AgentName::MozillaFirefox.agent(&can_i_use).unwrap().version_detail(&Version::major(57)).unwrap().release_date());
+	
+	let feature = CanIUseDatabase.feature("css-focus-ring").unwrap();
+	assert_eq!(feature.status(), Status::W3CWorkingDraft);
+	let support = feature.implementation(&AgentName::MozillaFirefox, "57").unwrap().unwrap();
+	assert!(support.requires_prefix());
+	assert_eq!(support.notes().collect::<Vec<_>>(), vec![(1, "Behind a flag.")]);
+	assert!(CanIUseDatabase.feature("transform3d").is_none());
+	
+	let feature_name = FeatureName::from("css-focus-ring");
+	let dynamic_feature = feature_name.feature(&can_i_use).unwrap();
+	for &(ref agent_name, version) in [(AgentName::MozillaFirefox, "57.0.1"), (AgentName::MozillaFirefox, "TP"), (AgentName::GoogleChrome, "1"), (AgentName::GoogleChrome, "4"), (AgentName::GoogleChrome, "11.0.2"), (AgentName::GoogleChrome, "200"), (AgentName::AppleSafariIOs, "14"), (AgentName::AppleSafariIOs, "15.1.1"), (AgentName::AppleSafariIOs, "15.2"), (AgentName::AppleSafariIOs, "15.2-15.3"), (AgentName::AppleSafariIOs, "15.4")].iter()
+	{
+		let static_support = feature.implementation(agent_name, version).map(|support| support.map(|support| (support.maturity(), support.requires_prefix())));
+		let support = dynamic_feature.implementation(agent_name, &Version::from(version)).map(|support| support.map(|support| (support.maturity(), support.requires_prefix())));
+		assert_eq!(static_support, support, "{:?} {}", agent_name, version);
+		
+		let static_usage = WorldWide.usage_of_version(agent_name, version);
+		let usage = regional_usage.usage_of_version(agent_name, &Version::from(version)).map(|usage| usage.cloned());
+		assert_eq!(static_usage, usage, "{:?} {}", agent_name, version);
+	}
+	assert!(feature.implementation(&AgentName::GoogleChrome, "1").unwrap().is_none());
+	assert_eq!(WorldWide.usage_of_version(&AgentName::AppleSafariIOs, "15.3"), Some(Some(Some(UsagePercentage::new(2.5)))));
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn can_i_use_validate()
+{
+	use validation::*;
+	
+	let can_i_use = CanIUse::default();
+	assert!(!can_i_use.validate().iter().any(Diagnostic::is_error));
+	
+	let mut feature_names: Vec<FeatureName> = can_i_use.known_feature_names().cloned().collect();
+	feature_names.sort();
+	let feature_name = feature_names[0].clone();
+	let chrome_current_version = AgentName::GoogleChrome.agent(&can_i_use).unwrap().current_version().to_string();
+	
+	let mut json = ::serde_json::to_value(&can_i_use).unwrap();
+	{
+		let feature = &mut json["data"][&feature_name.0];
+		feature["parent"] = "no-such-feature".into();
+		feature["notes_by_num"] = ::serde_json::Value::Object(::serde_json::Map::new());
+		feature["stats"]["chrome"][&chrome_current_version] = "y #1".into();
+	}
+	let invalid = CanIUse::from_str(&json.to_string()).unwrap();
+	
+	let errors: Vec<Diagnostic> = invalid.validate().into_iter().filter(Diagnostic::is_error).collect();
+	assert!(errors.contains(&Diagnostic::UnknownParentFeature { feature: feature_name.clone(), parent: FeatureName("no-such-feature".to_owned()) }));
+	assert!(errors.contains(&Diagnostic::MissingNote { feature: feature_name.clone(), agent: AgentName::GoogleChrome, version: Version::parse(&chrome_current_version), note_number: 1 }));
+	assert!(errors.iter().all(|diagnostic| diagnostic.severity() == DiagnosticSeverity::Error));
+	assert_eq!(errors.iter().find(|diagnostic| matches!(**diagnostic, Diagnostic::UnknownParentFeature { .. })).unwrap().to_string(), format!("error: feature '{}' has parent 'no-such-feature' which is not a feature", feature_name.0));
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn strict_loading_rejects_unrecognised_values_and_fields()
+{
+	let can_i_use = CanIUse::default();
+	let json = ::serde_json::to_value(&can_i_use).unwrap();
+	assert_eq!(CanIUse::from_str_with_options(&json.to_string(), &LoadOptions::Strict).unwrap(), can_i_use);
+	
+	let mut with_new_agent = json.clone();
+	with_new_agent["agents"]["kaios"] = with_new_agent["agents"]["firefox"].clone();
+	let with_new_agent = with_new_agent.to_string();
+	assert!(CanIUse::from_str_with_options(&with_new_agent, &LoadOptions::Lenient).is_ok());
+	match CanIUse::from_str_with_options(&with_new_agent, &LoadOptions::Strict)
+	{
+		Err(error @ LoadError::UnrecognisedValue { .. }) => assert_eq!(error.to_string(), "unrecognised value in caniuse.com data 'kaios' at '/agents/kaios'"),
+		result => panic!("Expected an unrecognised value, not {:?}", result),
+	}
+	
+	let mut with_new_field = json.clone();
+	with_new_field["data"]["css-focus-ring"]["new~field"] = true.into();
+	match CanIUse::from_slice_with_options(with_new_field.to_string().as_bytes(), &LoadOptions::Strict)
+	{
+		Err(LoadError::UnrecognisedField { path }) => assert_eq!(path, "/data/css-focus-ring/new~0field"),
+		result => panic!("Expected an unrecognised field, not {:?}", result),
+	}
+	
+	let mut with_new_category = json;
+	with_new_category["data"]["css-focus-ring"]["categories"] = vec!["CSS", "Quantum"].into();
+	match CanIUse::from_reader_with_options(with_new_category.to_string().as_bytes(), &LoadOptions::Strict)
+	{
+		Err(LoadError::UnrecognisedValue { path, value }) =>
+		{
+			assert_eq!(path, "/data/css-focus-ring/categories/1");
+			assert_eq!(value, "Quantum");
+		}
+		result => panic!("Expected an unrecognised value, not {:?}", result),
+	}
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn unrecognised_fields_are_preserved()
+{
+	let eras: ::serde_json::Value = ::serde_json::from_str(r#"{ "e0": "Current" }"#).unwrap();
+	let baseline: ::serde_json::Value = ::serde_json::from_str(r#"{ "status": "low" }"#).unwrap();
+	
+	let can_i_use = can_i_use_modified(|json|
+	{
+		json["eras"] = eras.clone();
+		json["agents"]["firefox"]["release_channel"] = "stable".into();
+		json["data"]["css-focus-ring"]["baseline"] = baseline.clone();
+	});
+	assert_eq!(can_i_use.extra_field("eras"), Some(&eras));
+	assert_eq!(can_i_use.extra_field("data"), None);
+	assert_eq!(AgentName::MozillaFirefox.agent(&can_i_use).unwrap().extra_field("release_channel"), Some(&"stable".into()));
+	let feature = FeatureName("css-focus-ring".to_owned());
+	assert_eq!(feature.feature(&can_i_use).unwrap().extra_field("baseline"), Some(&baseline));
+	assert_eq!(feature.feature(&can_i_use).unwrap().extra_field("title"), None);
+	
+	let rewritten = ::serde_json::to_value(&can_i_use).unwrap();
+	assert_eq!(rewritten["eras"], eras);
+	assert_eq!(rewritten["agents"]["firefox"]["release_channel"], "stable");
+	assert_eq!(rewritten["data"]["css-focus-ring"]["baseline"], baseline);
+	assert_eq!(CanIUse::from_str(&rewritten.to_string()).unwrap(), can_i_use);
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn features_are_searched_tolerant_of_typos()
+{
+	let can_i_use = CanIUse::default();
+	let feature_name = FeatureName("css-focus-ring".to_owned());
+	let title = feature_name.feature(&can_i_use).unwrap().title().to_owned();
+	
+	let results = can_i_use.search("focus ring");
+	assert_eq!(results[0].0, feature_name);
+	assert!(results.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+	
+	assert_eq!(can_i_use.search("focsu-rign")[0].0, feature_name);
+	assert_eq!(can_i_use.search("foc")[0].0, feature_name);
+	assert!(can_i_use.search(&title).iter().any(|(result, _)| *result == feature_name));
+	assert!(can_i_use.search("").is_empty());
+	assert!(can_i_use.search("zzzzqqqq").is_empty());
+	
+	let mut json = ::serde_json::to_value(&can_i_use).unwrap();
+	json["data"]["css-focus-ring"]["chrome_id"] = "CSSFocusRingWidget".into();
+	let can_i_use = CanIUse::from_str(&json.to_string()).unwrap();
+	let index = search::FeatureSearchIndex::new(&can_i_use);
+	let results = index.search("widgte");
+	assert_eq!(results[0].0, feature_name);
+	assert_eq!(results, can_i_use.search("widgte"));
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn first_supported_versions_and_support_removals()
+{
+	let can_i_use = can_i_use_with_stats(r#"{ "css-focus-ring": { "chrome": { "4": "n", "5": "a x", "10": "y x", "20": "n", "21": "u", "30": "y #1", "40": "n d", "TP": "y" }, "safari": { "11": "n", "TP": "y" } } }"#);
+	let feature_name: FeatureName = "css-focus-ring".into();
+	let feature = feature_name.feature(&can_i_use).unwrap();
+	
+	assert_eq!(feature.first_supported_versions(&SupportPolicy::Strict)[&AgentName::AppleSafari], None);
+	let unprefixed = SupportPolicy { accept_prefixed: false, .. SupportPolicy::Strict };
+	assert_eq!(feature.first_supported_versions(&SupportPolicy::Strict)[&AgentName::GoogleChrome], Some(Version::major(10)));
+	assert_eq!(feature.first_supported_versions(&SupportPolicy::Lenient)[&AgentName::GoogleChrome], Some(Version::major(5)));
+	assert_eq!(feature.first_supported_versions(&unprefixed)[&AgentName::GoogleChrome], Some(Version::major(30)));
+	
+	let removals = feature.support_removals(&SupportPolicy::Strict);
+	assert_eq!(removals[&AgentName::GoogleChrome], vec!
+	[
+		SupportRemoval { last_supported_version: Version::major(10), removed_in_version: Version::major(20), restored_in_version: Some(Version::major(30)) },
+		SupportRemoval { last_supported_version: Version::major(30), removed_in_version: Version::major(40), restored_in_version: None },
+	]);
+	let with_disabled_by_default = SupportPolicy { disabled_by_default: true, .. SupportPolicy::Strict };
+	assert_eq!(feature.support_removals(&with_disabled_by_default)[&AgentName::GoogleChrome].len(), 1);
+	assert_eq!(feature.support_removals(&unprefixed)[&AgentName::GoogleChrome].len(), 1);
+}
+
+#[test]
+fn agents_map_to_engines()
+{
+	use self::AgentName::*;
+	use self::Engine::*;
+	
+	assert_eq!(MicrosoftEdge.engine(&Version::major(18)), Some((EdgeHTML, Some(Version::major(18)))));
+	assert_eq!(MicrosoftEdge.engine(&Version::major(79)), Some((Blink, Some(Version::major(79)))));
+	assert_eq!(MicrosoftInternetExplorer.engine(&Version::major(11)), Some((Trident, Some(Version::major_minor(7, 0)))));
+	assert_eq!(Opera.engine(&Version::major_minor(12, 1)), Some((Presto, None)));
+	assert_eq!(Opera.engine(&Version::major(48)), Some((Blink, Some(Version::major(61)))));
+	assert_eq!(OperaMini.engine(&Version::opera_mini_all()), Some((Presto, None)));
+	assert_eq!(GoogleChrome.engine(&Version::major(27)), Some((WebKit, None)));
+	assert_eq!(GoogleAndroidBrowserAndWebComponent.engine(&"4.4.3-4.4.4".into()), Some((Blink, Some(Version::major(33)))));
+	assert_eq!(GoogleAndroidBrowserAndWebComponent.engine(&Version::major_minor(4, 3)), Some((WebKit, None)));
+	assert_eq!(SamsungBrowserAndroid.engine(&"6.2-6.4".into()), Some((Blink, Some(Version::major(56)))));
+	assert_eq!(MozillaFirefox.engine(&Version::major_minor(3, 6)), Some((Gecko, Some(Version::major_minor_revision(1, 9, 2)))));
+	assert_eq!(AppleSafariIOs.engine(&"11.0-11.1".into()), Some((WebKit, Some(Version::major_minor(11, 0)))));
+	assert_eq!(AppleSafari.engine(&Version::safari_technology_preview()), Some((WebKit, None)));
+	assert_eq!(OperaMobile.engine(&Version::major(37)), Some((Blink, Some(Version::major(50)))));
+	assert_eq!(OperaMobile.engine(&Version::major(12)), Some((Presto, None)));
+	assert_eq!(UcBrowserAndroid.engine(&Version::major_minor(11, 4)), Some((Blink, Some(Version::major(57)))));
+	assert_eq!(QqBrowserAndroid.engine(&Version::major_minor(1, 2)), Some((Blink, Some(Version::major(37)))));
+	assert_eq!(BaiduBrowserAndroid.engine(&Version::major_minor(7, 12)), Some((Blink, Some(Version::major(48)))));
+	assert_eq!(BaiduBrowserAndroid.engine(&Version::major(13)), Some((Blink, None)));
+	assert_eq!(Unknown("kaios".to_owned()).engine(&Version::major(2)), None);
+	
+	let agent_name_and_version_set = AgentNameAndVersionSet::new(hashset!
+	(
+		(GoogleChrome, Version::major(61)),
+		(SamsungBrowserAndroid, Version::major(5)),
+		(Opera, Version::major(48)),
+		(UcBrowserAndroid, Version::major_minor(11, 4)),
+		(AppleSafari, Version::major(11)),
+		(OperaMini, Version::opera_mini_all()),
+	));
+	assert_eq!(agent_name_and_version_set.minimum_engine_version(Blink), Some(Version::major(51)));
+	assert_eq!(agent_name_and_version_set.minimum_engine_version(Gecko), None);
+	let minimum_engine_versions = agent_name_and_version_set.minimum_engine_versions();
+	assert_eq!(minimum_engine_versions.len(), 3);
+	assert_eq!(minimum_engine_versions[&WebKit], Some(Version::major(11)));
+	assert_eq!(minimum_engine_versions[&Presto], None);
+	assert_eq!(Blink.to_string(), "Blink");
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn unknown_support_of_chromium_derived_agents_is_inferred()
+{
+	use self::AgentName::*;
+	
+	let can_i_use = can_i_use_with_stats(r#"{ "css-focus-ring": { "chrome": { "37": "n", "40": "n", "48": "a", "51": "y", "56": "y x" }, "samsung": { "4": "u", "5": "u", "6.2": "a" }, "and_uc": { "11.4": "u" }, "and_qq": { "1.2": "u" }, "baidu": { "7.12": "u" } } }"#);
+	let feature_name: FeatureName = "css-focus-ring".into();
+	let feature = feature_name.feature(&can_i_use).unwrap();
+	
+	let mut support_inference = SupportInference::default();
+	assert_eq!(support_inference.chromium_version(&SamsungBrowserAndroid, &Version::major(5)), Some(Version::major(51)));
+	assert_eq!(support_inference.chromium_version(&UcBrowserAndroid, &Version::major_minor(11, 4)), Some(Version::major(57)));
+	assert_eq!(support_inference.chromium_version(&UcBrowserAndroid, &Version::major(12)), None);
+	
+	assert_eq!(feature.implementation(&SamsungBrowserAndroid, &Version::major(5)).unwrap().unwrap().maturity(), SupportMaturity::SupportUnknown);
+	let inferred = feature.inferred_implementation(&SamsungBrowserAndroid, &Version::major(5), &support_inference).unwrap().unwrap();
+	assert_eq!(inferred.maturity(), SupportMaturity::SupportedByDefault);
+	assert!(inferred.is_inferred());
+	assert_eq!(inferred.inferred_from_google_chrome_version(), Some(&Version::major(51)));
+	
+	let inferred = feature.inferred_implementation(&SamsungBrowserAndroid, &Version::major(4), &support_inference).unwrap().unwrap();
+	assert_eq!(inferred.maturity(), SupportMaturity::NotSupportedOrDisabledByDefault);
+	assert_eq!(inferred.inferred_from_google_chrome_version(), Some(&Version::major(40)));
+	
+	let known = feature.inferred_implementation(&SamsungBrowserAndroid, &Version::major_minor(6, 2), &support_inference).unwrap().unwrap();
+	assert_eq!(known.maturity(), SupportMaturity::AlmostSupported);
+	assert!(!known.is_inferred());
+	
+	let inferred = feature.inferred_implementation(&UcBrowserAndroid, &Version::major_minor(11, 4), &support_inference).unwrap().unwrap();
+	assert!(inferred.requires_prefix());
+	assert_eq!(inferred.inferred_from_google_chrome_version(), Some(&Version::major(56)));
+	
+	let inferred = feature.inferred_implementation(&QqBrowserAndroid, &Version::major_minor(1, 2), &support_inference).unwrap().unwrap();
+	assert_eq!(inferred.maturity(), SupportMaturity::NotSupportedOrDisabledByDefault);
+	assert_eq!(inferred.inferred_from_google_chrome_version(), Some(&Version::major(37)));
+	
+	let inferred = feature.inferred_implementation(&BaiduBrowserAndroid, &Version::major_minor(7, 12), &support_inference).unwrap().unwrap();
+	assert_eq!(inferred.maturity(), SupportMaturity::AlmostSupported);
+	assert_eq!(inferred.inferred_from_google_chrome_version(), Some(&Version::major(48)));
+	
+	support_inference.add_chromium_version(UcBrowserAndroid, Version::major_minor(11, 4), Version::major(51));
+	let inferred = feature.inferred_implementation(&UcBrowserAndroid, &Version::major_minor(11, 4), &support_inference).unwrap().unwrap();
+	assert_eq!(inferred.maturity(), SupportMaturity::SupportedByDefault);
+	assert_eq!(inferred.inferred_from_google_chrome_version(), Some(&Version::major(51)));
+	
+	let unknown = feature.inferred_implementation(&QqBrowserAndroid, &Version::major(2), &support_inference).unwrap().unwrap();
+	assert_eq!(unknown.maturity(), SupportMaturity::SupportUnknown);
+	assert!(!unknown.is_inferred());
+}
+
+#[test]
+fn minimum_versions_render_as_targets()
+{
+	use self::AgentName::*;
+	use ::targets::MinimumVersions;
+	
+	let agent_name_and_version_set = AgentNameAndVersionSet::new(hashset!
+	(
+		(GoogleChrome, Version::major(61)),
+		(GoogleChrome, Version::major(58)),
+		(GoogleChromeAndroid, Version::major(62)),
+		(MicrosoftInternetExplorer, Version::major(11)),
+		(MicrosoftEdge, Version::major(16)),
+		(AppleSafariIOs, "11.0-11.1".into()),
+		(AppleSafari, Version::safari_technology_preview()),
+		(AppleSafari, Version::major(11)),
+		(SamsungBrowserAndroid, Version::major(5)),
+		(OperaMini, Version::opera_mini_all()),
+	));
+	let minimum_versions = agent_name_and_version_set.minimum_versions();
+	assert_eq!(minimum_versions[&GoogleChrome], Version::major(58));
+	assert_eq!(minimum_versions[&AppleSafariIOs], Version::major_minor(11, 0));
+	assert_eq!(minimum_versions[&AppleSafari], Version::major(11));
+	
+	let babel_targets: ::serde_json::Value = ::serde_json::from_str(r#"{ "chrome": "58", "edge": "16", "ie": "11", "ios": "11.0", "safari": "11", "samsung": "5" }"#).unwrap();
+	assert_eq!(minimum_versions.babel_targets(), babel_targets);
+	let esbuild_target_error = minimum_versions.esbuild_target().unwrap_err();
+	assert_eq!(esbuild_target_error.target, "chrome51,edge16,ie11,safari11");
+	assert_eq!(esbuild_target_error.unexpressible_agents, btreemap!(OperaMini => Version::opera_mini_all()));
+	
+	let browserslist_query = minimum_versions.browserslist_query();
+	assert_eq!(browserslist_query, "ie >= 11, edge >= 16, chrome >= 58, safari >= 11, ios_saf >= 11.0, op_mini all, and_chr >= 62, samsung >= 5");
+	assert!(browserslist_query.parse::<BrowserslistQuery>().is_ok());
+	
+	assert_eq!(MinimumVersions::new(BTreeMap::new()).esbuild_target(), Ok("".to_owned()));
+	assert_eq!(MinimumVersions::new(btreemap!(MozillaFirefox => Version::major_minor(3, 6), GoogleChrome => Version::major(58))).esbuild_target(), Ok("chrome58,firefox3.6".to_owned()));
+	
+	let esbuild_target_error = MinimumVersions::new(btreemap!(MozillaFirefox => Version::major(57), GoogleChrome => Version::major(4), Blackberry => Version::major(10))).esbuild_target().unwrap_err();
+	assert_eq!(esbuild_target_error.target, "firefox57");
+	assert_eq!(esbuild_target_error.unexpressible_agents, btreemap!(GoogleChrome => Version::major(4), Blackberry => Version::major(10)));
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn prefix_requirements_of_css_features()
+{
+	use self::AgentName::*;
+	use ::prefixes::CssFeatureCoverage;
+	
+	let can_i_use = can_i_use_with_stats(r#"{ "css-focus-ring": { "firefox": { "40": "a x", "50": "y x" }, "chrome": { "61": "y" }, "safari": { "11": "n" } } }"#);
+	
+	let agent_name_and_version_set = AgentNameAndVersionSet::new(hashset!
+	(
+		(MozillaFirefox, Version::major(40)),
+		(MozillaFirefox, Version::major(50)),
+		(GoogleChrome, Version::major(61)),
+		(AppleSafari, Version::major(11)),
+	));
+	let feature_name: FeatureName = "css-focus-ring".into();
+	let prefix_requirements = agent_name_and_version_set.prefix_requirements(&can_i_use, &[feature_name.clone(), "no-such-feature".into()]);
+	assert_eq!(prefix_requirements.len(), 1);
+	
+	let prefix_requirement = &prefix_requirements[&feature_name];
+	assert!(prefix_requirement.needs(&Prefix::moz));
+	assert!(!prefix_requirement.needs(&Prefix::webkit));
+	assert_eq!(prefix_requirement.prefixes[&Prefix::moz].len(), 2);
+	assert_eq!(prefix_requirement.css_prefixes(), vec!["-moz-".to_owned()]);
+	assert!(prefix_requirement.unprefixed.contains(&(GoogleChrome, Version::major(61))));
+	assert_eq!(prefix_requirement.unprefixed.len(), 1);
+	
+	assert_eq!(CssFeatureCoverage::of(&feature_name).unwrap().selectors, &[":focus-ring"]);
+	assert_eq!(CssFeatureCoverage::of(&"no-such-feature".into()), None);
+	assert_eq!(CssFeatureCoverage::feature_names_covering_property("transform"), vec![FeatureName::from("transforms2d")]);
+	assert_eq!(CssFeatureCoverage::feature_names_covering_value("display", "flex"), vec![FeatureName::from("flexbox")]);
+	assert!(CssFeatureCoverage::feature_names_covering_value("position", "flex").is_empty());
+	assert_eq!(CssFeatureCoverage::feature_names_covering_value("width", "calc"), vec![FeatureName::from("calc")]);
+	assert_eq!(CssFeatureCoverage::feature_names_covering_at_rule("keyframes"), vec![FeatureName::from("css-animation")]);
+	assert_eq!(CssFeatureCoverage::feature_names_covering_selector("::placeholder"), vec![FeatureName::from("css-placeholder")]);
+}
+
+#[cfg(feature = "embedded-database")]
+#[test]
+fn stylesheets_are_checked_for_unsupported_css()
+{
+	use self::AgentName::*;
+	use ::css_compatibility::*;
+	
+	let css = "/* display: grid; :focus-ring */\n.a:focus-visible,\n.b { display: grid !important; content: \"display: flex\"; background: url(data:image/png;base64,AAAA) #fff }\n@supports (display: grid) {\n\t.c { display: FLEX; margin: -1px; --gap: calc(1px) }\n}\n";
+	let syntax = CssSyntax::scan(css);
+	let location = |line, column| CssLocation { line, column };
+	let value = |property: &str, value: &str| CssSyntax::Value { property: property.to_owned(), value: value.to_owned() };
+	assert_eq!(syntax, vec!
+	[
+		(location(2, 3), CssSyntax::Selector(":focus-visible".to_owned())),
+		(location(3, 6), CssSyntax::Property("display".to_owned())),
+		(location(3, 15), value("display", "grid")),
+		(location(3, 32), CssSyntax::Property("content".to_owned())),
+		(location(3, 58), CssSyntax::Property("background".to_owned())),
+		(location(3, 70), value("background", "url")),
+		(location(4, 1), CssSyntax::AtRule("supports".to_owned())),
+		(location(5, 7), CssSyntax::Property("display".to_owned())),
+		(location(5, 16), value("display", "flex")),
+		(location(5, 22), CssSyntax::Property("margin".to_owned())),
+		(location(5, 36), CssSyntax::Property("--gap".to_owned())),
+	]);
+	assert_eq!(value("display", "grid").feature_names(), vec![FeatureName::from("css-grid")]);
+	assert_eq!(CssSyntax::Selector(":focus-visible".to_owned()).feature_names(), vec![FeatureName::from("css-focus-visible")]);
+	assert_eq!(CssSyntax::AtRule("supports".to_owned()).feature_names(), vec![FeatureName::from("css-featurequeries")]);
+	assert_eq!(CssSyntax::Property("--gap".to_owned()).feature_names(), vec![FeatureName::from("css-variables")]);
+	
+	let can_i_use = can_i_use_with_stats(r#"{ "css-grid": { "chrome": { "50": "n", "57": "y" } }, "css-focus-visible": { "chrome": { "50": "n", "57": "n d" } }, "flexbox": { "chrome": { "50": "y x", "57": "y" } } }"#);
+	let targets = AgentNameAndVersionSet::new(hashset!((GoogleChrome, Version::major(50)), (GoogleChrome, Version::major(57))));
+	
+	let issues = CssCompatibilityLinter::new(&can_i_use, &targets, SupportPolicy::Strict).lint(css);
+	assert_eq!(issues.len(), 3);
+	assert_eq!(issues[0].feature_name, FeatureName::from("css-focus-visible"));
+	assert_eq!(issues[0].unsupported.len(), 2);
+	assert_eq!(issues[1].to_string(), "3:15: value 'grid' of 'display' (css-grid) is not supported by chrome 50");
+	assert_eq!(issues[2].to_string(), "5:16: value 'flex' of 'display' (flexbox) is not supported by chrome 50");
+	assert_eq!(CssCompatibilityLinter::new(&can_i_use, &targets, SupportPolicy::Lenient).lint(css).len(), 3);
+	
+	let with_disabled_by_default = SupportPolicy { disabled_by_default: true, .. SupportPolicy::Strict };
+	let issues = CssCompatibilityLinter::new(&can_i_use, &targets, with_disabled_by_default).lint(css);
+	assert_eq!(issues[0].unsupported.iter().collect::<Vec<_>>(), vec![&(GoogleChrome, Version::major(50))]);
+}
@@ -0,0 +1,2 @@
+pub static CanIUseDatabase: ::caniuse_serde::static_data::StaticCanIUse = ::caniuse_serde::static_data::StaticCanIUse { agents: &[::caniuse_serde::static_data::StaticAgentDetail { agent_name: "chrome", name: "Chrome", abbreviated_name: "Chr.", prefix: "webkit", agent_type: ::caniuse_serde::AgentType::desktop, usage_global: &[("4", 0.01), ("10", 0.02), ("57", 20.5), ], version_list: &[::caniuse_serde::static_data::StaticVersionDetail { version: "4", global_usage: 0.01, release_date: Some(1256515200), era: -2, prefix_override: Some("webkit") }, ::caniuse_serde::static_data::StaticVersionDetail { version: "10", global_usage: 0.02, release_date: Some(1296518400), era: -1, prefix_override: Some("webkit") }, ::caniuse_serde::static_data::StaticVersionDetail { version: "57", global_usage: 20.5, release_date: Some(1500940800), era: 0, prefix_override: Some("webkit") }, ], current_version: "57", prefix_exceptions: &[] }, ::caniuse_serde::static_data::StaticAgentDetail { agent_name: "firefox", name: "Firefox", abbreviated_name: "FF", prefix: "moz", agent_type: ::caniuse_serde::AgentType::desktop, usage_global: &[("57", 5.5), ], version_list: &[::caniuse_serde::static_data::StaticVersionDetail { version: "57", global_usage: 5.5, release_date: Some(1510617600), era: 0, prefix_override: Some("moz") }, ], current_version: "57", prefix_exceptions: &[] }, ::caniuse_serde::static_data::StaticAgentDetail { agent_name: "ios_saf", name: "Safari on iOS", abbreviated_name: "iOS Saf.", prefix: "webkit", agent_type: ::caniuse_serde::AgentType::mobile, usage_global: &[("15.0-15.1", 1.5), ("15.2-15.3", 2.5), ], version_list: &[::caniuse_serde::static_data::StaticVersionDetail { version: "15.0-15.1", global_usage: 1.5, release_date: Some(1631577600), era: -1, prefix_override: Some("webkit") }, ::caniuse_serde::static_data::StaticVersionDetail { version: "15.2-15.3", global_usage: 2.5, release_date: Some(1639353600), era: 0, prefix_override: Some("webkit") }, ], current_version: "15.2-15.3", prefix_exceptions: &[] }, ], statuses: &[("rec", "W3C Recommendation"), ("wd", "W3C Working Draft"), ], child_categories: &[("CSS", &["CSS3", ]), ], updated: 1510617600, features: &[::caniuse_serde::static_data::StaticFeatureDetail { feature_name: "css-focus-ring", title: "focus-ring", description: "", specification_url: "https://drafts.csswg.org/selectors-4/", status: "wd", links: &[], bugs: &[], categories: &["CSS3", ], implementations_by_agents: &[("chrome", &[("4", ::caniuse_serde::static_data::StaticSupportDetail { maturity: ::caniuse_serde::SupportMaturity::NotSupportedOrDisabledByDefault, requires_prefix: false, disabled_by_default: false, notes_by_one_based_number: &[] }), ("10", ::caniuse_serde::static_data::StaticSupportDetail { maturity: ::caniuse_serde::SupportMaturity::SupportedByDefault, requires_prefix: true, disabled_by_default: false, notes_by_one_based_number: &[] }), ("57", ::caniuse_serde::static_data::StaticSupportDetail { maturity: ::caniuse_serde::SupportMaturity::SupportedByDefault, requires_prefix: false, disabled_by_default: false, notes_by_one_based_number: &[] }), ]), ("firefox", &[("57", ::caniuse_serde::static_data::StaticSupportDetail { maturity: ::caniuse_serde::SupportMaturity::SupportedByDefault, requires_prefix: true, disabled_by_default: false, notes_by_one_based_number: &[1] }), ]), ("ios_saf", &[("15.0-15.1", ::caniuse_serde::static_data::StaticSupportDetail { maturity: ::caniuse_serde::SupportMaturity::NotSupportedOrDisabledByDefault, requires_prefix: false, disabled_by_default: false, notes_by_one_based_number: &[] }), ("15.2-15.3", ::caniuse_serde::static_data::StaticSupportDetail { maturity: ::caniuse_serde::SupportMaturity::SupportedByDefault, requires_prefix: false, disabled_by_default: false, notes_by_one_based_number: &[] }), ]), ], notes: "", notes_by_one_based_number: &[(1, "Behind a flag."), ], parent: None, supported_by_default_usage: 23.0, almost_supported_usage: 0.0, upper_case_prefix: false, keywords: &["focus", "ring"], internet_explorer_feature_identifiers: &[], blink_feature_identifiers: &[], firefox_feature_identifiers: &[], webkit_feature_identifiers: &[], shown: true }, ] };
+pub static WorldWide: ::caniuse_serde::static_data::StaticRegionalUsage = ::caniuse_serde::static_data::StaticRegionalUsage { id: "alt-ww", name: "Global", year: 2017, one_based_month: 10, access_date: "2017-11-01", data: &[("chrome", &[("4", Some(0.01)), ("10", None), ("57", Some(20.5)), ]), ("ios_saf", &[("15.0-15.1", Some(1.5)), ("15.2-15.3", Some(2.5)), ]), ], total: 24.51 };
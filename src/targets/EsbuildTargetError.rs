@@ -0,0 +1,28 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Some agents of a `MinimumVersions` can not be expressed in an esbuild `--target`, because esbuild does not know their engine (Presto) or the version of their engine is not known, eg Chrome before 28 or Blackberry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EsbuildTargetError
+{
+	/// The esbuild `--target` of the agents which can be expressed, eg `chrome58,ie11`.
+	pub target: String,
+	
+	/// The agents, and their minimum versions, which can not be expressed.
+	pub unexpressible_agents: BTreeMap<AgentName, Version>,
+}
+
+impl Error for EsbuildTargetError
+{
+}
+
+impl Display for EsbuildTargetError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		let unexpressible_agents: Vec<String> = self.unexpressible_agents.iter().map(|(agent_name, version)| format!("{} {}", agent_name.can_i_use_identifier(), version)).collect();
+		write!(f, "agents which can not be expressed in an esbuild target: {}", unexpressible_agents.join(", "))
+	}
+}
@@ -0,0 +1,182 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+impl AgentNameAndVersionSet
+{
+	/// The minimum version of each agent in this set, for rendering as the targets of tools such as Babel, SWC, esbuild and browserslist.
+	#[inline(always)]
+	pub fn minimum_versions(&self) -> MinimumVersions
+	{
+		MinimumVersions::from(self)
+	}
+}
+
+/// The minimum (oldest) version of each agent in an `AgentNameAndVersionSet`, for rendering as the targets of tools such as Babel, SWC, esbuild and browserslist.
+/// A range of versions, eg iOS Safari's "11.0-11.1", is reduced to its lower bound.
+/// Versions without a major number, such as Safari's TP, are only used for agents without any other version.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MinimumVersions(BTreeMap<AgentName, Version>);
+
+impl Deref for MinimumVersions
+{
+	type Target = BTreeMap<AgentName, Version>;
+	
+	#[inline(always)]
+	fn deref(&self) -> &Self::Target
+	{
+		&self.0
+	}
+}
+
+impl<'a> From<&'a AgentNameAndVersionSet> for MinimumVersions
+{
+	#[inline(always)]
+	fn from(agent_name_and_version_set: &'a AgentNameAndVersionSet) -> Self
+	{
+		let mut minimum_versions = BTreeMap::new();
+		
+		for (agent_name, version) in agent_name_and_version_set.iter()
+		{
+			let version = version.lower_bound();
+			let is_older = match minimum_versions.get(agent_name)
+			{
+				None => true,
+				Some(minimum_version) => Self::is_older(&version, minimum_version),
+			};
+			if is_older
+			{
+				minimum_versions.insert(agent_name.clone(), version);
+			}
+		}
+		
+		MinimumVersions(minimum_versions)
+	}
+}
+
+impl MinimumVersions
+{
+	/// Constructor to use if `AgentNameAndVersionSet::minimum_versions()` isn't suitable.
+	#[inline(always)]
+	pub fn new(minimum_versions: BTreeMap<AgentName, Version>) -> Self
+	{
+		MinimumVersions(minimum_versions)
+	}
+	
+	/// A Babel `@babel/preset-env` `targets` object, which is also that of SWC's `env.targets`, eg `{"chrome": "58", "ie": "11"}`.
+	/// Agents are mapped with `AgentName::babel_identifier()`; agents which share an identifier, eg Chrome and Chrome for Android, take the minimum of their versions.
+	/// Agents without an identifier and versions without a major number are omitted.
+	#[inline(always)]
+	pub fn babel_targets(&self) -> Value
+	{
+		let mut targets = BTreeMap::new();
+		for (agent_name, version) in self.0.iter().filter(|&(_, version)| version.major_number().is_some())
+		{
+			if let Some(identifier) = agent_name.babel_identifier()
+			{
+				Self::insert_if_older(&mut targets, identifier, version.clone());
+			}
+		}
+		
+		Value::Object(targets.into_iter().map(|(identifier, version)| (identifier.to_owned(), Value::String(version.to_string()))).collect())
+	}
+	
+	/// An esbuild `--target` string, which is engine based, eg `chrome58,edge16,firefox57,safari11`.
+	/// Agents are mapped to engines and engine versions with `AgentName::engine()`, and engines with `Engine::esbuild_identifier()`; each engine takes the minimum of its versions.
+	/// Internet Explorer and Firefox (Gecko) agents use their own versions, as esbuild's `ie` and `firefox` do, eg `firefox3.6` rather than `firefox1.9.2`.
+	/// Returns an error, which also contains the target of the other agents, if any agents have no known engine version or an engine without an identifier, ie Presto.
+	#[inline(always)]
+	pub fn esbuild_target(&self) -> Result<String, EsbuildTargetError>
+	{
+		use self::Engine::*;
+		
+		let mut targets = BTreeMap::new();
+		let mut unexpressible_agents = BTreeMap::new();
+		for (agent_name, version) in self.0.iter()
+		{
+			let target = match agent_name.engine(version)
+			{
+				None => None,
+				Some((engine, engine_version)) =>
+				{
+					let target_version = match engine
+					{
+						Trident | Gecko => Some(version.clone()),
+						_ => engine_version,
+					};
+					
+					match (engine.esbuild_identifier(), target_version)
+					{
+						(Some(identifier), Some(target_version)) => Some((identifier, target_version)),
+						_ => None,
+					}
+				}
+			};
+			
+			match target
+			{
+				None =>
+				{
+					unexpressible_agents.insert(agent_name.clone(), version.clone());
+				}
+				Some((identifier, target_version)) => Self::insert_if_older(&mut targets, identifier, target_version),
+			}
+		}
+		
+		let targets: Vec<String> = targets.iter().map(|(identifier, version)| format!("{}{}", identifier, version)).collect();
+		let target = targets.join(",");
+		if unexpressible_agents.is_empty()
+		{
+			Ok(target)
+		}
+		else
+		{
+			Err(EsbuildTargetError
+			{
+				target,
+				unexpressible_agents,
+			})
+		}
+	}
+	
+	/// A browserslist query selecting the minimum version of each agent and every later version, eg `ie >= 11, chrome >= 58, op_mini all`.
+	/// This can be evaluated with `AgentNameAndVersionSet::browserslist()`.
+	#[inline(always)]
+	pub fn browserslist_query(&self) -> String
+	{
+		let selectors: Vec<String> = self.0.iter().map(|(agent_name, version)| if version.major_number().is_some()
+		{
+			format!("{} >= {}", agent_name.can_i_use_identifier(), version)
+		}
+		else
+		{
+			format!("{} {}", agent_name.can_i_use_identifier(), version)
+		}).collect();
+		selectors.join(", ")
+	}
+	
+	#[inline(always)]
+	fn insert_if_older(targets: &mut BTreeMap<&'static str, Version>, identifier: &'static str, version: Version)
+	{
+		let is_older = match targets.get(identifier)
+		{
+			None => true,
+			Some(minimum_version) => Self::is_older(&version, minimum_version),
+		};
+		if is_older
+		{
+			targets.insert(identifier, version);
+		}
+	}
+	
+	#[inline(always)]
+	fn is_older(version: &Version, minimum_version: &Version) -> bool
+	{
+		match (version.major_number().is_some(), minimum_version.major_number().is_some())
+		{
+			(true, false) => true,
+			(false, true) => false,
+			_ => version < minimum_version,
+		}
+	}
+}
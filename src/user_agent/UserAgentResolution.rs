@@ -0,0 +1,85 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// The result of resolving a `User-Agent` string against a caniuse.com database.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum UserAgentResolution
+{
+	/// The agent was recognised and the version it reported has been matched to a version known to the caniuse.com database (see `Agent::nearest_known_version()`).
+	/// The `version` can be used with `Feature::implementation()`.
+	Known
+	{
+		/// Agent.
+		agent_name: AgentName,
+		
+		/// Version known to the caniuse.com database.
+		version: Version,
+		
+		/// Version as reported in the `User-Agent` string.
+		reported_version: Version,
+	},
+	
+	/// The agent was recognised but the caniuse.com database does not know of it, any of its versions or any version as old as that reported.
+	UnknownVersion
+	{
+		/// Agent.
+		agent_name: AgentName,
+		
+		/// Version as reported in the `User-Agent` string.
+		reported_version: Version,
+	},
+	
+	/// The agent was not recognised; no guess is made.
+	Unknown,
+}
+
+impl UserAgentResolution
+{
+	/// Resolves a `User-Agent` string against a caniuse.com database.
+	#[inline(always)]
+	pub fn resolve(can_i_use: &CanIUse, user_agent: &str) -> Self
+	{
+		use self::UserAgentResolution::*;
+		
+		match parse_user_agent(user_agent)
+		{
+			None => Unknown,
+			Some((agent_name, reported_version)) =>
+			{
+				let version = match agent_name.agent(can_i_use)
+				{
+					None => None,
+					Some(agent) => agent.nearest_known_version(&reported_version).cloned(),
+				};
+				
+				match version
+				{
+					None => UnknownVersion
+					{
+						agent_name,
+						reported_version,
+					},
+					
+					Some(version) => Known
+					{
+						agent_name,
+						version,
+						reported_version,
+					},
+				}
+			}
+		}
+	}
+	
+	/// Agent name and version known to the caniuse.com database, if any.
+	#[inline(always)]
+	pub fn agent_name_and_version(&self) -> Option<(&AgentName, &Version)>
+	{
+		match *self
+		{
+			UserAgentResolution::Known { ref agent_name, ref version, .. } => Some((agent_name, version)),
+			_ => None,
+		}
+	}
+}
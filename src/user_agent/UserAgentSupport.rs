@@ -0,0 +1,166 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Whether the agent of a HTTP `User-Agent` header supports a feature; suitable for choosing, say, between modern and legacy bundles at request time.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum UserAgentSupport<'a>
+{
+	/// Supported without a prefix.
+	Supported
+	{
+		/// Agent.
+		agent_name: AgentName,
+		
+		/// Version known to the caniuse.com database.
+		version: Version,
+		
+		/// Pairs of one-based note numbers and note text.
+		notes: Vec<(u8, &'a str)>,
+	},
+	
+	/// Supported, but only with a prefix.
+	SupportedWithPrefix
+	{
+		/// Agent.
+		agent_name: AgentName,
+		
+		/// Version known to the caniuse.com database.
+		version: Version,
+		
+		/// Prefix to use, from `Agent::prefix()`.
+		prefix: &'a Prefix,
+		
+		/// Pairs of one-based note numbers and note text.
+		notes: Vec<(u8, &'a str)>,
+	},
+	
+	/// Not supported according to the policy, or unknown because the agent, version or feature are not known.
+	Unsupported
+	{
+		/// How the `User-Agent` header was resolved.
+		resolution: UserAgentResolution,
+		
+		/// Maturity of support; None if support is unknown.
+		maturity: Option<SupportMaturity>,
+		
+		/// Pairs of one-based note numbers and note text.
+		notes: Vec<(u8, &'a str)>,
+	},
+}
+
+impl<'a> UserAgentSupport<'a>
+{
+	/// Determines whether the agent of a HTTP `User-Agent` header supports a feature.
+	#[inline(always)]
+	pub fn evaluate(can_i_use: &'a CanIUse, user_agent: &str, feature_name: &FeatureName, policy: &SupportPolicy) -> Self
+	{
+		use self::UserAgentSupport::*;
+		
+		let resolution = UserAgentResolution::resolve(can_i_use, user_agent);
+		
+		let (agent_name, version, agent_detail, feature_detail, support_detail) =
+		{
+			let (agent_name, version) = match resolution.agent_name_and_version()
+			{
+				None => return Self::unknown(resolution),
+				Some(agent_name_and_version) => agent_name_and_version,
+			};
+			
+			let agent_detail = match can_i_use.agents.get(agent_name)
+			{
+				None => return Self::unknown(resolution),
+				Some(agent_detail) => agent_detail,
+			};
+			
+			let feature_detail = match can_i_use.features.get(feature_name)
+			{
+				None => return Self::unknown(resolution),
+				Some(feature_detail) => feature_detail,
+			};
+			
+			let support_detail = match feature_detail.implementations_by_agents.get(agent_name).and_then(|implementations| version.resolve(implementations))
+			{
+				None => return Self::unknown(resolution),
+				Some((_, support_detail)) => support_detail,
+			};
+			
+			(agent_name.clone(), version.clone(), agent_detail, feature_detail, support_detail)
+		};
+		
+		let notes = support_detail.notes(feature_detail);
+		
+		if !policy.supports_detail(support_detail)
+		{
+			return Unsupported
+			{
+				resolution,
+				maturity: Some(support_detail.maturity()),
+				notes,
+			};
+		}
+		
+		if support_detail.requires_prefix()
+		{
+			SupportedWithPrefix
+			{
+				prefix: agent_detail.prefix(&version),
+				agent_name,
+				version,
+				notes,
+			}
+		}
+		else
+		{
+			Supported
+			{
+				agent_name,
+				version,
+				notes,
+			}
+		}
+	}
+	
+	/// Is this `Supported` or `SupportedWithPrefix`?
+	#[inline(always)]
+	pub fn is_supported(&self) -> bool
+	{
+		!matches!(*self, UserAgentSupport::Unsupported { .. })
+	}
+	
+	/// Prefix to use, if supported with a prefix.
+	#[inline(always)]
+	pub fn prefix(&self) -> Option<&'a Prefix>
+	{
+		match *self
+		{
+			UserAgentSupport::SupportedWithPrefix { prefix, .. } => Some(prefix),
+			_ => None,
+		}
+	}
+	
+	/// Pairs of one-based note numbers and note text.
+	#[inline(always)]
+	pub fn notes(&self) -> &[(u8, &'a str)]
+	{
+		use self::UserAgentSupport::*;
+		
+		match *self
+		{
+			Supported { ref notes, .. } => notes,
+			SupportedWithPrefix { ref notes, .. } => notes,
+			Unsupported { ref notes, .. } => notes,
+		}
+	}
+	
+	#[inline(always)]
+	fn unknown(resolution: UserAgentResolution) -> Self
+	{
+		UserAgentSupport::Unsupported
+		{
+			resolution,
+			maturity: None,
+			notes: Vec::new(),
+		}
+	}
+}
@@ -0,0 +1,205 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// Parses a HTTP `User-Agent` header into an agent and the version it reports, eg "62.0.3202.94" for Chrome.
+/// The version is not snapped to one known to the caniuse.com database; use `UserAgentResolution::resolve()` or `CanIUse::resolve_user_agent()` for that.
+/// Returns None if the agent is not recognised; no guess is made.
+///
+/// Order of detection matters, as most agents claim to be several others:-
+///
+/// * Opera Mini is detected before anything else, and always has the version `Version::opera_mini_all()`;
+/// * Every browser on iOS is treated as iOS Safari (they all must use WebKit), with the version of iOS;
+/// * Edge is detected before Chrome, as it claims to be Chrome (and Safari);
+/// * Samsung Internet, QQ Browser, UC Browser and Baidu Browser are detected before Chrome, as they are Chromium-derived and claim to be Chrome;
+/// * Chrome's Android WebView is treated as the Android Browser, which caniuse.com uses for it from Android 5 onwards.
+pub fn parse_user_agent(user_agent: &str) -> Option<(AgentName, Version)>
+{
+	use self::AgentName::*;
+	
+	#[inline(always)]
+	fn with(agent_name: AgentName, version: Option<Version>) -> Option<(AgentName, Version)>
+	{
+		version.map(|version| (agent_name, version))
+	}
+	
+	let is_android = user_agent.contains("Android");
+	
+	if user_agent.contains("Opera Mini")
+	{
+		return Some((OperaMini, Version::opera_mini_all()));
+	}
+	
+	if user_agent.contains("iPhone") || user_agent.contains("iPad") || user_agent.contains("iPod")
+	{
+		let version = version_after(user_agent, " OS ").or_else(|| version_after(user_agent, "Version/"));
+		return with(AppleSafariIOs, version);
+	}
+	
+	if user_agent.contains("IEMobile/")
+	{
+		return with(MicrosoftInternetExplorerMobile, version_after(user_agent, "IEMobile/"));
+	}
+	
+	// EdgeHTML-based Edge
+	if user_agent.contains("Edge/")
+	{
+		return with(MicrosoftEdge, version_after(user_agent, "Edge/"));
+	}
+	
+	// Chromium-based Edge; the Android version has no entry of its own in the caniuse.com database
+	if user_agent.contains("EdgA/")
+	{
+		return with(GoogleChromeAndroid, version_after(user_agent, "Chrome/"));
+	}
+	if user_agent.contains("Edg/")
+	{
+		return with(MicrosoftEdge, version_after(user_agent, "Edg/"));
+	}
+	
+	if user_agent.contains("MSIE ")
+	{
+		return with(MicrosoftInternetExplorer, version_after(user_agent, "MSIE "));
+	}
+	if user_agent.contains("Trident/")
+	{
+		return with(MicrosoftInternetExplorer, version_after(user_agent, "rv:"));
+	}
+	
+	// Presto-based Opera
+	if user_agent.contains("Opera")
+	{
+		let agent_name = if user_agent.contains("Opera Mobi") || user_agent.contains("Opera Tablet")
+		{
+			OperaMobile
+		}
+		else
+		{
+			Opera
+		};
+		let version = version_after(user_agent, "Version/").or_else(|| version_after(user_agent, "Opera/")).or_else(|| version_after(user_agent, "Opera "));
+		return with(agent_name, version);
+	}
+	
+	// Blink-based Opera
+	if user_agent.contains("OPR/")
+	{
+		let agent_name = if is_android
+		{
+			OperaMobile
+		}
+		else
+		{
+			Opera
+		};
+		return with(agent_name, version_after(user_agent, "OPR/"));
+	}
+	
+	if user_agent.contains("SamsungBrowser/")
+	{
+		return with(SamsungBrowserAndroid, version_after(user_agent, "SamsungBrowser/"));
+	}
+	
+	if user_agent.contains("UCBrowser/")
+	{
+		return with(UcBrowserAndroid, version_after(user_agent, "UCBrowser/"));
+	}
+	
+	if user_agent.contains("MQQBrowser/")
+	{
+		return with(QqBrowserAndroid, version_after(user_agent, "MQQBrowser/"));
+	}
+	
+	if is_android
+	{
+		for token in ["baidubrowser/", "bdbrowser/", "BIDUBrowser/"].iter()
+		{
+			if user_agent.contains(*token)
+			{
+				return with(BaiduBrowserAndroid, version_after(user_agent, token));
+			}
+		}
+	}
+	
+	if user_agent.contains("BB10")
+	{
+		return Some((Blackberry, Version::major(10)));
+	}
+	if user_agent.contains("BlackBerry")
+	{
+		return with(Blackberry, version_after(user_agent, "Version/"));
+	}
+	
+	if user_agent.contains("Firefox/")
+	{
+		let agent_name = if is_android
+		{
+			MozillaFirefoxAndroid
+		}
+		else
+		{
+			MozillaFirefox
+		};
+		return with(agent_name, version_after(user_agent, "Firefox/"));
+	}
+	
+	if user_agent.contains("Chrome/")
+	{
+		let version = version_after(user_agent, "Chrome/");
+		
+		if is_android
+		{
+			let is_web_view = user_agent.contains("; wv)") || user_agent.contains("Version/");
+			let agent_name = if is_web_view
+			{
+				GoogleAndroidBrowserAndWebComponent
+			}
+			else
+			{
+				GoogleChromeAndroid
+			};
+			return with(agent_name, version);
+		}
+		
+		return with(GoogleChrome, version);
+	}
+	
+	// Pre-Chromium Android Browser
+	if is_android
+	{
+		return with(GoogleAndroidBrowserAndWebComponent, version_after(user_agent, "Android "));
+	}
+	
+	if user_agent.contains("Safari/")
+	{
+		return with(AppleSafari, version_after(user_agent, "Version/"));
+	}
+	
+	None
+}
+
+/// Finds the version immediately after `token`, eg "11.2.1" for " OS " in "CPU iPhone OS 11_2_1 like Mac OS X".
+/// Underscore-delimited versions (as used by iOS) are normalized to period-delimited ones.
+#[inline(always)]
+fn version_after(user_agent: &str, token: &str) -> Option<Version>
+{
+	let start = match user_agent.find(token)
+	{
+		None => return None,
+		Some(index) => index + token.len(),
+	};
+	
+	let remainder = &user_agent[start..];
+	let end = remainder.find(|character: char| !(character.is_ascii_digit() || character == '.' || character == '_')).unwrap_or(remainder.len());
+	let version = remainder[..end].replace('_', ".");
+	let version = version.trim_matches('.');
+	
+	if version.is_empty()
+	{
+		None
+	}
+	else
+	{
+		Some(Version::parse(version))
+	}
+}
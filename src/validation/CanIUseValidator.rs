@@ -0,0 +1,209 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+impl CanIUse
+{
+	/// Checks this database for questionable or inconsistent data, eg a feature's `stats` referencing a note number absent from its `notes_by_num`.
+	/// Diagnostics of the database's statuses and categories come first, then those of each agent and then of each feature, all in sorted order; use `Diagnostic::is_error()` to, say, gate data updates in CI.
+	#[inline(always)]
+	pub fn validate(&self) -> Vec<Diagnostic>
+	{
+		let mut validator = CanIUseValidator
+		{
+			can_i_use: self,
+			diagnostics: Vec::new(),
+		};
+		validator.validate();
+		validator.diagnostics
+	}
+}
+
+#[derive(Debug)]
+struct CanIUseValidator<'a>
+{
+	can_i_use: &'a CanIUse,
+	diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> CanIUseValidator<'a>
+{
+	#[inline(always)]
+	fn validate(&mut self)
+	{
+		use self::Diagnostic::*;
+		
+		let mut statuses: Vec<&Status> = self.can_i_use.statuses.keys().collect();
+		statuses.sort();
+		for status in statuses
+		{
+			if let Status::Unknown(_) = *status
+			{
+				self.diagnostics.push(UnknownStatus { feature: None, status: status.clone() });
+			}
+		}
+		
+		let mut child_categories: Vec<(&ParentCategory, &Vec<Category>)> = self.can_i_use.child_categories.iter().collect();
+		child_categories.sort_by_key(|&(parent_category, _)| parent_category);
+		for (parent_category, categories) in child_categories
+		{
+			if let ParentCategory::Unknown(_) = *parent_category
+			{
+				self.diagnostics.push(UnknownParentCategory { parent_category: parent_category.clone() });
+			}
+			for category in categories.iter()
+			{
+				self.category(None, category);
+			}
+		}
+		
+		let mut agents: Vec<(&AgentName, &AgentDetail)> = self.can_i_use.agents.iter().collect();
+		agents.sort_by_key(|&(agent_name, _)| agent_name);
+		for (agent_name, agent_detail) in agents
+		{
+			self.agent(agent_name, agent_detail);
+		}
+		
+		let mut features: Vec<(&FeatureName, &FeatureDetail)> = self.can_i_use.features.iter().collect();
+		features.sort_by_key(|&(feature_name, _)| feature_name);
+		for (feature_name, feature_detail) in features
+		{
+			self.feature(feature_name, feature_detail);
+		}
+	}
+	
+	#[inline(always)]
+	fn agent(&mut self, agent_name: &AgentName, agent_detail: &AgentDetail)
+	{
+		use self::Diagnostic::*;
+		
+		if let AgentName::Unknown(_) = *agent_name
+		{
+			self.diagnostics.push(UnknownAgent { feature: None, agent: agent_name.clone() });
+		}
+		
+		self.prefix(agent_name, None, &agent_detail.prefix);
+		for (version, prefix) in agent_detail.prefix_exceptions.iter()
+		{
+			self.prefix(agent_name, Some(version), prefix);
+		}
+		
+		match agent_detail.version_list.get(&agent_detail.current_version)
+		{
+			None => self.diagnostics.push(CurrentVersionNotInVersionList { agent: agent_name.clone(), current_version: agent_detail.current_version.clone() }),
+			Some(version_detail) => if version_detail.era != 0
+			{
+				self.diagnostics.push(CurrentVersionEraIsNotZero { agent: agent_name.clone(), current_version: agent_detail.current_version.clone(), era: version_detail.era });
+			},
+		}
+		
+		let mut versions_by_era: Vec<(&Version, &VersionDetail)> = agent_detail.version_list.iter().collect();
+		versions_by_era.sort_by_key(|&(_, version_detail)| version_detail.era);
+		let mut latest_released: Option<(&Version, DateTime<Utc>)> = None;
+		for (version, version_detail) in versions_by_era
+		{
+			if let Some(ref prefix) = version_detail.prefix_override
+			{
+				self.prefix(agent_name, Some(version), prefix);
+			}
+			
+			match version_detail.release_date
+			{
+				None => if version_detail.era <= 0
+				{
+					self.diagnostics.push(UnreleasedVersionIsNotInTheFuture { agent: agent_name.clone(), version: version.clone(), era: version_detail.era });
+				},
+				
+				Some(release_date) =>
+				{
+					if let Some((earlier_era_version, earlier_era_release_date)) = latest_released
+					{
+						if release_date < earlier_era_release_date
+						{
+							self.diagnostics.push(EraIsInconsistentWithReleaseDate { agent: agent_name.clone(), version: version.clone(), release_date, earlier_era_version: earlier_era_version.clone(), earlier_era_release_date });
+							continue;
+						}
+					}
+					latest_released = Some((version, release_date));
+				}
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn feature(&mut self, feature_name: &FeatureName, feature_detail: &FeatureDetail)
+	{
+		use self::Diagnostic::*;
+		
+		if let Status::Unknown(_) = feature_detail.status
+		{
+			self.diagnostics.push(UnknownStatus { feature: Some(feature_name.clone()), status: feature_detail.status.clone() });
+		}
+		
+		for category in feature_detail.categories.iter()
+		{
+			self.category(Some(feature_name), category);
+		}
+		
+		if let Some(ref parent) = feature_detail.parent
+		{
+			if !self.can_i_use.features.contains_key(parent)
+			{
+				self.diagnostics.push(UnknownParentFeature { feature: feature_name.clone(), parent: parent.clone() });
+			}
+		}
+		
+		let mut implementations_by_agents: Vec<(&AgentName, &BTreeMap<Version, SupportDetail>)> = feature_detail.implementations_by_agents.iter().collect();
+		implementations_by_agents.sort_by_key(|&(agent_name, _)| agent_name);
+		for (agent_name, implementations) in implementations_by_agents
+		{
+			if let AgentName::Unknown(_) = *agent_name
+			{
+				self.diagnostics.push(UnknownAgent { feature: Some(feature_name.clone()), agent: agent_name.clone() });
+			}
+			
+			let agent_detail = self.can_i_use.agents.get(agent_name);
+			if agent_detail.is_none()
+			{
+				self.diagnostics.push(MissingAgent { feature: feature_name.clone(), agent: agent_name.clone() });
+			}
+			
+			for (version, support_detail) in implementations.iter()
+			{
+				if let Some(agent_detail) = agent_detail
+				{
+					if !agent_detail.version_list.contains_key(version)
+					{
+						self.diagnostics.push(UnknownVersion { feature: feature_name.clone(), agent: agent_name.clone(), version: version.clone() });
+					}
+				}
+				
+				for note_number in support_detail.notes_by_one_based_number.iter()
+				{
+					if !feature_detail.notes_by_one_based_number.contains_key(note_number)
+					{
+						self.diagnostics.push(MissingNote { feature: feature_name.clone(), agent: agent_name.clone(), version: version.clone(), note_number: *note_number });
+					}
+				}
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn category(&mut self, feature_name: Option<&FeatureName>, category: &Category)
+	{
+		if let Category::Unknown(_) = *category
+		{
+			self.diagnostics.push(Diagnostic::UnknownCategory { feature: feature_name.cloned(), category: category.clone() });
+		}
+	}
+	
+	#[inline(always)]
+	fn prefix(&mut self, agent_name: &AgentName, version: Option<&Version>, prefix: &Prefix)
+	{
+		if let Prefix::Unknown(_) = *prefix
+		{
+			self.diagnostics.push(Diagnostic::UnknownPrefix { agent: agent_name.clone(), version: version.cloned(), prefix: prefix.clone() });
+		}
+	}
+}
@@ -0,0 +1,234 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// A problem found by `CanIUse::validate()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic
+{
+	/// An agent identifier not known to this crate, either in `agents` or in a feature's `stats`.
+	UnknownAgent
+	{
+		/// Feature whose `stats` contain the agent; None if in `agents`.
+		feature: Option<FeatureName>,
+		
+		/// Agent.
+		agent: AgentName,
+	},
+	
+	/// A status identifier not known to this crate, either in `statuses` or of a feature.
+	UnknownStatus
+	{
+		/// Feature with the status; None if in `statuses`.
+		feature: Option<FeatureName>,
+		
+		/// Status.
+		status: Status,
+	},
+	
+	/// A category identifier not known to this crate, either in `cats` or of a feature.
+	UnknownCategory
+	{
+		/// Feature with the category; None if in `cats`.
+		feature: Option<FeatureName>,
+		
+		/// Category.
+		category: Category,
+	},
+	
+	/// A parent category identifier in `cats` not known to this crate.
+	UnknownParentCategory
+	{
+		/// Parent category.
+		parent_category: ParentCategory,
+	},
+	
+	/// A prefix not known to this crate.
+	UnknownPrefix
+	{
+		/// Agent.
+		agent: AgentName,
+		
+		/// Version with a prefix exception or override; None if the agent's prefix.
+		version: Option<Version>,
+		
+		/// Prefix.
+		prefix: Prefix,
+	},
+	
+	/// A feature's `stats` contain an agent that is not in `agents`.
+	MissingAgent
+	{
+		/// Feature.
+		feature: FeatureName,
+		
+		/// Agent.
+		agent: AgentName,
+	},
+	
+	/// A feature's `stats` contain a version that is not in the agent's `version_list`.
+	UnknownVersion
+	{
+		/// Feature.
+		feature: FeatureName,
+		
+		/// Agent.
+		agent: AgentName,
+		
+		/// Version.
+		version: Version,
+	},
+	
+	/// A feature's `stats` reference a note number absent from its `notes_by_num`.
+	MissingNote
+	{
+		/// Feature.
+		feature: FeatureName,
+		
+		/// Agent.
+		agent: AgentName,
+		
+		/// Version.
+		version: Version,
+		
+		/// One-based note number.
+		note_number: u8,
+	},
+	
+	/// A feature's `parent` is not a feature in the database.
+	UnknownParentFeature
+	{
+		/// Feature.
+		feature: FeatureName,
+		
+		/// Parent.
+		parent: FeatureName,
+	},
+	
+	/// An agent's `current_version` is not in its `version_list`.
+	CurrentVersionNotInVersionList
+	{
+		/// Agent.
+		agent: AgentName,
+		
+		/// Current version.
+		current_version: Version,
+	},
+	
+	/// An agent's `current_version` does not have an `era` of zero.
+	CurrentVersionEraIsNotZero
+	{
+		/// Agent.
+		agent: AgentName,
+		
+		/// Current version.
+		current_version: Version,
+		
+		/// Era.
+		era: i64,
+	},
+	
+	/// A version without a `release_date` (ie not yet released) has an `era` of zero or less (ie current or past).
+	UnreleasedVersionIsNotInTheFuture
+	{
+		/// Agent.
+		agent: AgentName,
+		
+		/// Version.
+		version: Version,
+		
+		/// Era.
+		era: i64,
+	},
+	
+	/// A version was released before a version with an earlier `era`.
+	EraIsInconsistentWithReleaseDate
+	{
+		/// Agent.
+		agent: AgentName,
+		
+		/// Version.
+		version: Version,
+		
+		/// Release date of version.
+		release_date: DateTime<Utc>,
+		
+		/// Version with an earlier era but a later release date.
+		earlier_era_version: Version,
+		
+		/// Release date of `earlier_era_version`.
+		earlier_era_release_date: DateTime<Utc>,
+	},
+}
+
+impl Diagnostic
+{
+	/// How serious is this diagnostic?
+	#[inline(always)]
+	pub fn severity(&self) -> DiagnosticSeverity
+	{
+		use self::Diagnostic::*;
+		use self::DiagnosticSeverity::*;
+		
+		match *self
+		{
+			UnknownAgent { .. } | UnknownStatus { .. } | UnknownCategory { .. } | UnknownParentCategory { .. } | UnknownPrefix { .. } => Warning,
+			MissingAgent { .. } | UnknownVersion { .. } => Warning,
+			MissingNote { .. } | UnknownParentFeature { .. } => Error,
+			CurrentVersionNotInVersionList { .. } => Error,
+			CurrentVersionEraIsNotZero { .. } | UnreleasedVersionIsNotInTheFuture { .. } | EraIsInconsistentWithReleaseDate { .. } => Warning,
+		}
+	}
+	
+	/// Is this diagnostic an error?
+	#[inline(always)]
+	pub fn is_error(&self) -> bool
+	{
+		self.severity() == DiagnosticSeverity::Error
+	}
+}
+
+impl Display for Diagnostic
+{
+	/// Displays as, say, `error: feature 'css-grid' agent 'ie' version '11' references missing note 3`.
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::Diagnostic::*;
+		
+		write!(f, "{}: ", self.severity())?;
+		
+		match *self
+		{
+			UnknownAgent { feature: None, ref agent } => write!(f, "unknown agent '{}'", agent.can_i_use_identifier()),
+			UnknownAgent { feature: Some(ref feature), ref agent } => write!(f, "feature '{}' has unknown agent '{}'", feature.0, agent.can_i_use_identifier()),
+			
+			UnknownStatus { feature: None, ref status } => write!(f, "unknown status '{}'", status.can_i_use_identifier()),
+			UnknownStatus { feature: Some(ref feature), ref status } => write!(f, "feature '{}' has unknown status '{}'", feature.0, status.can_i_use_identifier()),
+			
+			UnknownCategory { feature: None, ref category } => write!(f, "unknown category '{}'", category.can_i_use_identifier()),
+			UnknownCategory { feature: Some(ref feature), ref category } => write!(f, "feature '{}' has unknown category '{}'", feature.0, category.can_i_use_identifier()),
+			
+			UnknownParentCategory { ref parent_category } => write!(f, "unknown parent category '{}'", parent_category.can_i_use_identifier()),
+			
+			UnknownPrefix { ref agent, version: None, ref prefix } => write!(f, "agent '{}' has unknown prefix '{}'", agent.can_i_use_identifier(), prefix.can_i_use_identifier()),
+			UnknownPrefix { ref agent, version: Some(ref version), ref prefix } => write!(f, "agent '{}' version '{}' has unknown prefix '{}'", agent.can_i_use_identifier(), version, prefix.can_i_use_identifier()),
+			
+			MissingAgent { ref feature, ref agent } => write!(f, "feature '{}' has agent '{}' which is not in agents", feature.0, agent.can_i_use_identifier()),
+			
+			UnknownVersion { ref feature, ref agent, ref version } => write!(f, "feature '{}' agent '{}' has version '{}' which is not in the agent's version_list", feature.0, agent.can_i_use_identifier(), version),
+			
+			MissingNote { ref feature, ref agent, ref version, note_number } => write!(f, "feature '{}' agent '{}' version '{}' references missing note {}", feature.0, agent.can_i_use_identifier(), version, note_number),
+			
+			UnknownParentFeature { ref feature, ref parent } => write!(f, "feature '{}' has parent '{}' which is not a feature", feature.0, parent.0),
+			
+			CurrentVersionNotInVersionList { ref agent, ref current_version } => write!(f, "agent '{}' has current_version '{}' which is not in its version_list", agent.can_i_use_identifier(), current_version),
+			
+			CurrentVersionEraIsNotZero { ref agent, ref current_version, era } => write!(f, "agent '{}' has current_version '{}' with era {} rather than 0", agent.can_i_use_identifier(), current_version, era),
+			
+			UnreleasedVersionIsNotInTheFuture { ref agent, ref version, era } => write!(f, "agent '{}' version '{}' has no release_date but has era {}", agent.can_i_use_identifier(), version, era),
+			
+			EraIsInconsistentWithReleaseDate { ref agent, ref version, ref release_date, ref earlier_era_version, ref earlier_era_release_date } => write!(f, "agent '{}' version '{}' was released on {} before version '{}' with an earlier era was released on {}", agent.can_i_use_identifier(), version, release_date.format("%Y-%m-%d"), earlier_era_version, earlier_era_release_date.format("%Y-%m-%d")),
+		}
+	}
+}
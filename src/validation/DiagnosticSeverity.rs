@@ -0,0 +1,29 @@
+// This file is part of caniuse-serde. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT. No part of caniuse-serde, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of caniuse-serde. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/caniuse-serde/master/COPYRIGHT.
+
+
+/// How serious a `Diagnostic` is.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum DiagnosticSeverity
+{
+	/// Questionable data that this crate copes with, eg an identifier added to the caniuse.com database after this crate was written.
+	Warning,
+	
+	/// Data that is inconsistent, and which may cause this crate to panic or give wrong answers, eg a note number with no note.
+	Error,
+}
+
+impl Display for DiagnosticSeverity
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::DiagnosticSeverity::*;
+		
+		match *self
+		{
+			Warning => write!(f, "warning"),
+			Error => write!(f, "error"),
+		}
+	}
+}